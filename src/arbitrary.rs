@@ -0,0 +1,134 @@
+//! Implementations of `proptest::arbitrary::Arbitrary` for the types of this library, such that
+//! downstream crates may property-test code that consumes them. These are only available when the
+//! `proptest` feature is enabled.
+//!
+//! Calendar dates are always generated from a valid universal `Date`, such that only dates that
+//! actually exist in their respective calendar are produced.
+
+use proptest::{
+    arbitrary::{Arbitrary, any, any_with},
+    strategy::{BoxedStrategy, Map, Strategy},
+};
+
+use crate::{
+    CopticDate, Date, Days, Duration, EthiopicDate, GregorianDate, HebrewDate, HistoricDate,
+    JulianDate, Month, TimePoint, WeekDay,
+};
+
+impl<Representation, Period> Arbitrary for Duration<Representation, Period>
+where
+    Representation: Arbitrary,
+    Period: core::fmt::Debug + 'static,
+{
+    type Parameters = Representation::Parameters;
+    type Strategy = Map<Representation::Strategy, fn(Representation) -> Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        any_with::<Representation>(args).prop_map(Duration::new)
+    }
+}
+
+impl<Scale, Representation, Period> Arbitrary for TimePoint<Scale, Representation, Period>
+where
+    Representation: Arbitrary,
+    Scale: core::fmt::Debug + 'static,
+    Period: core::fmt::Debug + 'static,
+{
+    type Parameters = Representation::Parameters;
+    type Strategy = Map<
+        <Duration<Representation, Period> as Arbitrary>::Strategy,
+        fn(Duration<Representation, Period>) -> Self,
+    >;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        any_with::<Duration<Representation, Period>>(args)
+            .prop_map(TimePoint::from_time_since_epoch)
+    }
+}
+
+impl<Representation> Arbitrary for Date<Representation>
+where
+    Representation: Arbitrary,
+{
+    type Parameters = Representation::Parameters;
+    type Strategy =
+        Map<<Days<Representation> as Arbitrary>::Strategy, fn(Days<Representation>) -> Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        any_with::<Days<Representation>>(args).prop_map(Date::from_time_since_epoch)
+    }
+}
+
+/// Implements `Arbitrary` for a calendar type, based on its conversion from `Date<i32>`.
+macro_rules! arbitrary_calendar {
+    ($calendar:ty) => {
+        impl Arbitrary for $calendar {
+            type Parameters = ();
+            type Strategy = Map<<Date<i32> as Arbitrary>::Strategy, fn(Date<i32>) -> Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                any::<Date<i32>>().prop_map(<$calendar>::from_date)
+            }
+        }
+    };
+}
+
+arbitrary_calendar!(CopticDate);
+arbitrary_calendar!(EthiopicDate);
+arbitrary_calendar!(GregorianDate);
+arbitrary_calendar!(HebrewDate);
+arbitrary_calendar!(HistoricDate);
+arbitrary_calendar!(JulianDate);
+
+impl Arbitrary for Month {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (1u8..=12)
+            .prop_map(|month| Month::try_from(month).unwrap_or_else(|_| unreachable!()))
+            .boxed()
+    }
+}
+
+impl Arbitrary for WeekDay {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (0u8..7)
+            .prop_map(|week_day| WeekDay::try_from(week_day).unwrap_or_else(|_| unreachable!()))
+            .boxed()
+    }
+}
+
+proptest::proptest! {
+    /// Verifies that arbitrary calendar dates round-trip through the universal `Date`.
+    #[test]
+    fn calendar_roundtrip(
+        gregorian in any::<GregorianDate>(),
+        julian in any::<JulianDate>(),
+        historic in any::<HistoricDate>(),
+        coptic in any::<CopticDate>(),
+        ethiopic in any::<EthiopicDate>(),
+        hebrew in any::<HebrewDate>(),
+    ) {
+        use crate::Calendar;
+        proptest::prop_assert_eq!(GregorianDate::from_date(gregorian.to_date()), gregorian);
+        proptest::prop_assert_eq!(JulianDate::from_date(julian.to_date()), julian);
+        proptest::prop_assert_eq!(HistoricDate::from_date(historic.to_date()), historic);
+        proptest::prop_assert_eq!(CopticDate::from_date(coptic.to_date()), coptic);
+        proptest::prop_assert_eq!(EthiopicDate::from_date(ethiopic.to_date()), ethiopic);
+        proptest::prop_assert_eq!(HebrewDate::from_date(hebrew.to_date()), hebrew);
+    }
+
+    /// Verifies that arbitrary time points and durations are consistent with one another.
+    #[test]
+    fn time_point_offset(
+        time_point in any::<crate::TaiTime<i32>>(),
+        duration in any::<Duration<i32>>(),
+    ) {
+        let (time_point, duration) = (time_point.cast::<i64>(), duration.cast::<i64>());
+        proptest::prop_assert_eq!((time_point + duration) - time_point, duration);
+    }
+}
@@ -0,0 +1,57 @@
+//! Absolute difference of two numbers, expressed in a type that can hold it for all inputs.
+
+use num_traits::Float;
+
+/// Computes the magnitude of the difference between two numbers without overflowing. For signed
+/// integers, this is expressed in their unsigned counterpart, like the inherent `i64::abs_diff`:
+/// the difference between `i64::MIN` and zero does not fit in an `i64`, but does fit in a `u64`.
+pub trait AbsDiff {
+    /// The type in which the absolute difference is expressed.
+    type Magnitude;
+
+    /// Returns the magnitude of the difference between `self` and `other`.
+    fn abs_diff(self, other: Self) -> Self::Magnitude;
+}
+
+macro_rules! impl_abs_diff_for_integer {
+    ($($repr:ty => $output:ty),+ $(,)?) => {
+        $(
+            impl AbsDiff for $repr {
+                type Magnitude = $output;
+
+                fn abs_diff(self, other: Self) -> Self::Magnitude {
+                    <$repr>::abs_diff(self, other)
+                }
+            }
+        )+
+    };
+}
+
+impl_abs_diff_for_integer!(
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    u128 => u128,
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+);
+
+macro_rules! impl_abs_diff_for_float {
+    ($($repr:ty),+ $(,)?) => {
+        $(
+            impl AbsDiff for $repr {
+                type Magnitude = $repr;
+
+                fn abs_diff(self, other: Self) -> Self::Magnitude {
+                    Float::abs(self - other)
+                }
+            }
+        )+
+    };
+}
+
+impl_abs_diff_for_float!(f32, f64);
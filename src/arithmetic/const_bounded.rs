@@ -0,0 +1,27 @@
+//! Compile-time counterpart of the `num_traits::Bounded` trait.
+
+use num_traits::Bounded;
+
+/// Representations of which the minimum and maximum values are available as constants. This
+/// permits `const` evaluation of bounds, which is not possible using `Bounded`, whose functions
+/// are not `const`. Both shall agree with the values returned by `Bounded`.
+pub trait ConstBounded: Bounded {
+    /// The value nearest to negative infinity.
+    const MIN: Self;
+
+    /// The value nearest to positive infinity.
+    const MAX: Self;
+}
+
+macro_rules! impl_const_bounded {
+    ($($repr:ty),+ $(,)?) => {
+        $(
+            impl ConstBounded for $repr {
+                const MIN: Self = <$repr>::MIN;
+                const MAX: Self = <$repr>::MAX;
+            }
+        )+
+    };
+}
+
+impl_const_bounded!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64,);
@@ -0,0 +1,321 @@
+//! Implementation of a double-double floating point number: an unevaluated sum of two `f64`s. This
+//! is a software substitute for quadruple precision floats, which are not available on stable Rust.
+
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+use num_traits::Zero;
+
+use crate::{ConvertUnit, UnitRatio};
+
+/// Factor used to split an `f64` into two halves of 26 significant bits each (Dekker's method),
+/// such that their products are exact.
+const SPLITTER: f64 = 134_217_729.0;
+
+/// A floating point number that is stored as the unevaluated sum of a high and a low `f64` part,
+/// giving roughly 31 significant decimal digits instead of the 15 of a single `f64`. This makes it
+/// suitable as representation for time points that must combine a wide range with a fine
+/// resolution: for example, `f64` seconds a millennium from J2000 only resolve a few microseconds,
+/// while a `DoubleDouble` still resolves well below a nanosecond.
+///
+/// The number is kept normalized: the low part never exceeds half a unit in the last place of the
+/// high part. The exponent range is that of `f64`, and non-finite values are not treated
+/// specially.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+impl DoubleDouble {
+    /// Constructs a double-double from the sum of two `f64`s, which need not be normalized.
+    pub fn new(hi: f64, lo: f64) -> Self {
+        two_sum(hi, lo)
+    }
+
+    /// Returns the high part of this number: its nearest `f64` approximation.
+    pub const fn hi(&self) -> f64 {
+        self.hi
+    }
+
+    /// Returns the low part of this number: the error of its high part.
+    pub const fn lo(&self) -> f64 {
+        self.lo
+    }
+
+    /// Rounds this number to the nearest `f64`.
+    pub fn to_f64(self) -> f64 {
+        self.hi + self.lo
+    }
+}
+
+/// Computes the exact sum of two `f64`s as a normalized double-double, without any requirements
+/// on their relative magnitudes (Knuth's two-sum).
+fn two_sum(a: f64, b: f64) -> DoubleDouble {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let error = (a - (sum - b_virtual)) + (b - b_virtual);
+    DoubleDouble { hi: sum, lo: error }
+}
+
+/// Computes the exact sum of two `f64`s as a normalized double-double, assuming that the magnitude
+/// of `a` is at least that of `b`.
+fn quick_two_sum(a: f64, b: f64) -> DoubleDouble {
+    let sum = a + b;
+    DoubleDouble {
+        hi: sum,
+        lo: b - (sum - a),
+    }
+}
+
+/// Splits an `f64` into a high and a low part of at most 26 significant bits each.
+fn split(a: f64) -> (f64, f64) {
+    let t = SPLITTER * a;
+    let hi = t - (t - a);
+    (hi, a - hi)
+}
+
+/// Computes the exact product of two `f64`s as a double-double (Dekker's two-product). A fused
+/// multiply-add would be faster, but is not available without `std`.
+fn two_prod(a: f64, b: f64) -> DoubleDouble {
+    let product = a * b;
+    let (a_hi, a_lo) = split(a);
+    let (b_hi, b_lo) = split(b);
+    let error = ((a_hi * b_hi - product) + a_hi * b_lo + a_lo * b_hi) + a_lo * b_lo;
+    DoubleDouble {
+        hi: product,
+        lo: error,
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(value: f64) -> Self {
+        Self { hi: value, lo: 0.0 }
+    }
+}
+
+impl From<f32> for DoubleDouble {
+    fn from(value: f32) -> Self {
+        Self::from(f64::from(value))
+    }
+}
+
+impl From<i32> for DoubleDouble {
+    fn from(value: i32) -> Self {
+        Self::from(f64::from(value))
+    }
+}
+
+/// Any `i64` is represented exactly: the part that does not fit in the high `f64` is stored in the
+/// low part.
+impl From<i64> for DoubleDouble {
+    fn from(value: i64) -> Self {
+        let hi = value as f64;
+        let lo = (i128::from(value) - hi as i128) as f64;
+        quick_two_sum(hi, lo)
+    }
+}
+
+/// Any `u64` is represented exactly, as for `i64`.
+impl From<u64> for DoubleDouble {
+    fn from(value: u64) -> Self {
+        let hi = value as f64;
+        let lo = (i128::from(value) - hi as i128) as f64;
+        quick_two_sum(hi, lo)
+    }
+}
+
+/// A `u128` is split into its high and low 64 bits, each of which is represented exactly. Only
+/// values wider than the 106-bit double-double mantissa are rounded, when both halves are summed.
+impl From<u128> for DoubleDouble {
+    fn from(value: u128) -> Self {
+        const TWO_POW_64: f64 = 18_446_744_073_709_551_616.0;
+        let high = Self::from((value >> 64) as u64);
+        let high = Self {
+            hi: high.hi * TWO_POW_64,
+            lo: high.lo * TWO_POW_64,
+        };
+        high + Self::from(value as u64)
+    }
+}
+
+impl PartialOrd for DoubleDouble {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.hi.partial_cmp(&other.hi)? {
+            Ordering::Equal => self.lo.partial_cmp(&other.lo),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let high = two_sum(self.hi, rhs.hi);
+        let low = two_sum(self.lo, rhs.lo);
+        let sum = quick_two_sum(high.hi, high.lo + low.hi);
+        quick_two_sum(sum.hi, sum.lo + low.lo)
+    }
+}
+
+impl AddAssign for DoubleDouble {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl SubAssign for DoubleDouble {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let product = two_prod(self.hi, rhs.hi);
+        quick_two_sum(
+            product.hi,
+            product.lo + (self.hi * rhs.lo + self.lo * rhs.hi),
+        )
+    }
+}
+
+/// Division is computed by long division, refining the quotient with a correction term twice.
+impl Div for DoubleDouble {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let q1 = self.hi / rhs.hi;
+        let remainder = self - rhs * Self::from(q1);
+        let q2 = remainder.hi / rhs.hi;
+        let remainder = remainder - rhs * Self::from(q2);
+        let q3 = remainder.hi / rhs.hi;
+        quick_two_sum(q1, q2) + Self::from(q3)
+    }
+}
+
+impl Zero for DoubleDouble {
+    fn zero() -> Self {
+        Self { hi: 0.0, lo: 0.0 }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.hi == 0.0
+    }
+}
+
+/// Unit conversions are applied in double-double arithmetic, such that no more precision is lost
+/// than for the other operations.
+impl<From, Into> ConvertUnit<From, Into> for DoubleDouble
+where
+    From: UnitRatio + ?Sized,
+    Into: UnitRatio + ?Sized,
+{
+    fn convert(self) -> Self {
+        let combined_ratio = From::FRACTION.divide_by(&Into::FRACTION);
+        let numerator = DoubleDouble::from(combined_ratio.numerator());
+        let denominator = DoubleDouble::from(combined_ratio.denominator());
+        self * numerator / denominator
+    }
+}
+
+/// Verifies that double-double arithmetic retains the precision that is lost in a single `f64`.
+#[test]
+fn extended_precision() {
+    let one = DoubleDouble::from(1.0);
+    let tiny = DoubleDouble::from(1e-20);
+    assert_eq!((one + tiny) - one, tiny);
+    assert_eq!((1.0 + 1e-20) - 1.0, 0.0);
+
+    let third = one / DoubleDouble::from(3.0);
+    let error = (third * DoubleDouble::from(3.0) - one).to_f64().abs();
+    assert!(error < 1e-30);
+
+    let large = i64::MAX - 1;
+    let exact = DoubleDouble::from(large);
+    assert_eq!(exact.hi() as i128 + exact.lo() as i128, i128::from(large));
+    assert!(DoubleDouble::from(2.0) > DoubleDouble::new(2.0, -1e-20));
+}
+
+/// Verifies that time points a millennium after J2000 retain nanosecond resolution when expressed
+/// in double-double seconds, while `f64` seconds cannot resolve a single nanosecond there.
+#[test]
+fn millennium_time_point() {
+    use crate::{MilliSeconds, Nano, Second, Seconds, TcgTime};
+    // One thousand Julian years after J2000, relative to the TCG epoch (in 1977).
+    let seconds = 23 * 31_557_600 + 1_000 * 31_557_600i64;
+    let nanosecond = 1e-9;
+
+    let float = TcgTime::from_time_since_epoch(Seconds::new(seconds as f64));
+    let later = float + Seconds::new(nanosecond);
+    assert_eq!((later - float).count(), 0.0);
+
+    let time = TcgTime::from_time_since_epoch(Seconds::new(DoubleDouble::from(seconds)));
+    let later = time + Seconds::new(DoubleDouble::from(nanosecond));
+    assert_eq!((later - time).count(), DoubleDouble::from(nanosecond));
+
+    let nanoseconds = (later - time).into_unit::<Nano>().count().to_f64();
+    assert!((nanoseconds - 1.0).abs() < 1e-12);
+    let milliseconds: MilliSeconds<DoubleDouble> =
+        Seconds::new(DoubleDouble::from(1.5)).into_unit();
+    assert_eq!(milliseconds.count(), DoubleDouble::from(1_500.0));
+    assert_eq!(
+        milliseconds.into_unit::<Second>().count(),
+        DoubleDouble::from(1.5)
+    );
+}
+
+/// Verifies that unit conversions with ratios beyond the range of `i64` are not truncated.
+#[test]
+fn extreme_unit_ratios() {
+    use crate::{
+        AttoSeconds, Years,
+        units::{Atto, Quecto, SecondsPerYear},
+    };
+    let attoseconds = Years::new(DoubleDouble::from(1.0))
+        .into_unit::<Atto>()
+        .count();
+    assert_eq!(attoseconds.to_f64(), 3.1556952e25);
+    assert_eq!(
+        AttoSeconds::new(attoseconds)
+            .into_unit::<SecondsPerYear>()
+            .count(),
+        DoubleDouble::from(1.0)
+    );
+    let quectoseconds = Years::new(DoubleDouble::from(1.0))
+        .into_unit::<Quecto>()
+        .count();
+    assert_eq!(quectoseconds.to_f64(), 3.1556952e37);
+
+    let large = u128::MAX - 1;
+    let exact = DoubleDouble::from(large);
+    assert_eq!(exact.to_f64(), large as f64);
+    assert_eq!(
+        DoubleDouble::from(1u128 << 100) + DoubleDouble::from(1.0),
+        DoubleDouble::from((1u128 << 100) + 1)
+    );
+}
@@ -27,6 +27,21 @@ impl Fraction {
         }
     }
 
+    /// Creates a new `Fraction`, like `new`, but returns `None` instead of panicking if the
+    /// `denominator` is zero. Useful when constructing ratios from user-provided values at runtime.
+    pub const fn try_new(numerator: u128, denominator: u128) -> Option<Self> {
+        if denominator == 0 {
+            None
+        } else if numerator == 0 {
+            Some(Self {
+                numerator: 0,
+                denominator: 1,
+            })
+        } else {
+            Some(Self::new(numerator, denominator))
+        }
+    }
+
     /// Returns the value of this fraction's numerator.
     pub const fn numerator(&self) -> u128 {
         self.numerator
@@ -66,6 +81,44 @@ impl Fraction {
             denominator: denominator / gcd3,
         }
     }
+
+    /// Divides this fraction by `other`, like `divide_by`, but returns `None` instead of panicking
+    /// if `other` is zero or if the result cannot be expressed without overflow.
+    pub const fn checked_divide_by(&self, other: &Self) -> Option<Self> {
+        if other.numerator == 0 {
+            return None;
+        }
+        let reciprocal = Self {
+            numerator: other.denominator,
+            denominator: other.numerator,
+        };
+        self.checked_mul(&reciprocal)
+    }
+
+    /// Multiplies this fraction by `other`. Returns `None` if the result cannot be expressed
+    /// without overflow, even after normalization.
+    pub const fn checked_mul(&self, other: &Self) -> Option<Self> {
+        if self.numerator == 0 || other.numerator == 0 {
+            return Some(Self {
+                numerator: 0,
+                denominator: 1,
+            });
+        }
+        let gcd1 = binary_gcd(self.numerator, other.denominator);
+        let gcd2 = binary_gcd(other.numerator, self.denominator);
+        let numerator = match (self.numerator / gcd1).checked_mul(other.numerator / gcd2) {
+            Some(numerator) => numerator,
+            None => return None,
+        };
+        let denominator = match (self.denominator / gcd2).checked_mul(other.denominator / gcd1) {
+            Some(denominator) => denominator,
+            None => return None,
+        };
+        Some(Self {
+            numerator,
+            denominator,
+        })
+    }
 }
 
 #[cfg(kani)]
@@ -351,6 +404,133 @@ fn rounding_multiplication() {
     assert_eq!(2.mul_round(Fraction::new(1, 3)), 1);
 }
 
+/// Trait representing multiplication that rounds to nearest (upwards on tie) if the result cannot
+/// be represented exactly by the output type, but that fails if the result overflows it. Unlike
+/// `MulRound`, floats are never rounded to integers, since they represent any product up to
+/// floating point error: for floats, only overflow towards infinity results in failure.
+pub trait CheckedMulRound<T> {
+    type Output;
+
+    /// Multiplies `self` by `rhs`, rounding to nearest if the output is not representable. Returns
+    /// `None` if the output, or any intermediate result, overflows.
+    fn checked_mul_round(self, rhs: T) -> Option<Self::Output>;
+}
+
+macro_rules! checked_mul_round_unsigned_integer {
+    ($repr:ty) => {
+        impl CheckedMulRound<$repr> for Fraction {
+            type Output = $repr;
+
+            fn checked_mul_round(self, rhs: $repr) -> Option<Self::Output> {
+                let numerator = (rhs as u128).checked_mul(self.numerator())?;
+                let denominator = self.denominator();
+                let div = numerator / denominator;
+                let rem = numerator % denominator;
+                let half = denominator >> 1;
+                let result = if rem > half { div + 1 } else { div };
+                result.try_into().ok()
+            }
+        }
+
+        impl CheckedMulRound<Fraction> for $repr {
+            type Output = $repr;
+
+            fn checked_mul_round(self, rhs: Fraction) -> Option<Self::Output> {
+                rhs.checked_mul_round(self)
+            }
+        }
+    };
+}
+
+macro_rules! checked_mul_round_signed_integer {
+    ($repr:ty) => {
+        impl CheckedMulRound<$repr> for Fraction {
+            type Output = $repr;
+
+            fn checked_mul_round(self, rhs: $repr) -> Option<Self::Output> {
+                use num_traits::ConstZero;
+                let numerator = i128::try_from(self.numerator()).ok()?;
+                let numerator = (rhs as i128).checked_mul(numerator)?;
+                let denominator = i128::try_from(self.denominator()).ok()?;
+                let div = numerator / denominator;
+                let rem = numerator % denominator;
+                let half = denominator >> 1;
+                let result = if rhs >= <$repr>::ZERO {
+                    if rem > half { div + 1 } else { div }
+                } else {
+                    if rem < (-half) { div - 1 } else { div }
+                };
+                result.try_into().ok()
+            }
+        }
+
+        impl CheckedMulRound<Fraction> for $repr {
+            type Output = $repr;
+
+            fn checked_mul_round(self, rhs: Fraction) -> Option<Self::Output> {
+                rhs.checked_mul_round(self)
+            }
+        }
+    };
+}
+
+macro_rules! checked_mul_round_float {
+    ($repr:ty) => {
+        impl CheckedMulRound<$repr> for Fraction {
+            type Output = $repr;
+
+            fn checked_mul_round(self, rhs: $repr) -> Option<Self::Output> {
+                let result = self * rhs;
+                (result.is_finite() || !rhs.is_finite()).then_some(result)
+            }
+        }
+
+        impl CheckedMulRound<Fraction> for $repr {
+            type Output = $repr;
+
+            fn checked_mul_round(self, rhs: Fraction) -> Option<Self::Output> {
+                rhs.checked_mul_round(self)
+            }
+        }
+    };
+}
+
+checked_mul_round_unsigned_integer!(u8);
+checked_mul_round_unsigned_integer!(u16);
+checked_mul_round_unsigned_integer!(u32);
+checked_mul_round_unsigned_integer!(u64);
+checked_mul_round_unsigned_integer!(u128);
+checked_mul_round_signed_integer!(i8);
+checked_mul_round_signed_integer!(i16);
+checked_mul_round_signed_integer!(i32);
+checked_mul_round_signed_integer!(i64);
+checked_mul_round_signed_integer!(i128);
+checked_mul_round_float!(f32);
+checked_mul_round_float!(f64);
+
+/// Verifies that checked rounding multiplication is exact where possible, rounds otherwise, and
+/// only fails on overflow.
+#[test]
+fn checked_rounding_multiplication() {
+    assert_eq!(10.checked_mul_round(Fraction::new(3, 2)), Some(15));
+    assert_eq!(2.checked_mul_round(Fraction::new(1, 3)), Some(1));
+    assert_eq!((-10).checked_mul_round(Fraction::new(2, 3)), Some(-7));
+    assert_eq!(100u8.checked_mul_round(Fraction::new(3, 1)), None);
+    assert_eq!(i64::MAX.checked_mul_round(Fraction::new(2, 1)), None);
+    assert_eq!(
+        i64::MIN.checked_mul_round(Fraction::new(1, 2)),
+        Some(i64::MIN / 2)
+    );
+    assert_eq!(u128::MAX.checked_mul_round(Fraction::new(3, 2)), None);
+    assert_eq!(1.0f64.checked_mul_round(Fraction::new(1, 4)), Some(0.25));
+    assert_eq!(f64::MAX.checked_mul_round(Fraction::new(2, 1)), None);
+    assert!(
+        f64::INFINITY
+            .checked_mul_round(Fraction::new(2, 1))
+            .is_some()
+    );
+}
+
 /// Trait representing multiplication that always succeeds, but that will round towards negative
 /// infinity if the output is not an integer.
 pub trait MulFloor<T> {
@@ -500,3 +680,26 @@ impl MulCeil<f32> for Fraction {
         (self * rhs).ceil()
     }
 }
+
+/// Verifies that the non-panicking constructor and arithmetic report failure instead of panicking.
+#[test]
+fn checked_arithmetic() {
+    assert_eq!(Fraction::try_new(1, 0), None);
+    let zero = Fraction::try_new(0, 7).unwrap();
+    assert_eq!((zero.numerator(), zero.denominator()), (0, 1));
+    assert_eq!(Fraction::try_new(6, 4), Some(Fraction::new(3, 2)));
+
+    let half = Fraction::new(1, 2);
+    let third = Fraction::new(1, 3);
+    assert_eq!(half.checked_divide_by(&third), Some(Fraction::new(3, 2)));
+    assert_eq!(half.checked_divide_by(&third), Some(half.divide_by(&third)));
+    assert_eq!(half.checked_mul(&third), Some(Fraction::new(1, 6)));
+    assert_eq!(half.checked_divide_by(&zero), None);
+
+    let huge = Fraction::new(u128::MAX, 1);
+    let tiny = Fraction::new(1, u128::MAX - 1);
+    assert_eq!(huge.checked_divide_by(&tiny), None);
+    assert_eq!(huge.checked_mul(&Fraction::new(2, 1)), None);
+    assert_eq!(huge.checked_divide_by(&huge), Some(Fraction::new(1, 1)));
+    assert_eq!(zero.checked_divide_by(&tiny), Some(zero));
+}
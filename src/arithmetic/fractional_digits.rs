@@ -18,6 +18,17 @@ pub trait FractionalDigits {
         precision: Option<usize>,
         base: u8,
     ) -> Self::Iterator;
+
+    /// Returns the number of decimal fractional digits that are significant when this number is
+    /// interpreted as a count of units of `unit_ratio` seconds, or `None` if all digits are exact.
+    /// Only for floats is this limited, and then also by the magnitude of the number itself.
+    fn significant_fractional_digits(self, unit_ratio: Fraction) -> Option<usize>
+    where
+        Self: Sized,
+    {
+        let _ = unit_ratio;
+        None
+    }
 }
 
 // Back-up limit that is used to prevent infinite loops while printing. The value of this constant
@@ -71,6 +82,21 @@ macro_rules! impl_fractional_digits_for_float {
             ) -> Self::Iterator {
                 FractionalDigitsIterator::from_float(self, unit_ratio, precision, base)
             }
+
+            fn significant_fractional_digits(self, unit_ratio: Fraction) -> Option<usize> {
+                // Non-finite values have no fractional digits at all, significant or not.
+                if !self.is_finite() {
+                    return Some(0);
+                }
+                let mut magnitude = (self as f64).abs() * (unit_ratio.numerator() as f64)
+                    / (unit_ratio.denominator() as f64);
+                let mut integer_digits = 0;
+                while magnitude >= 1.0 {
+                    magnitude /= 10.0;
+                    integer_digits += 1;
+                }
+                Some((<$repr>::DIGITS as usize).saturating_sub(integer_digits))
+            }
         }
     };
 }
@@ -147,16 +173,33 @@ impl FractionalDigitsIterator {
         T: Mul<Fraction, Output = T> + Float,
     {
         let (mantissa, exponent, _) = count.integer_decode();
-        let mut numerator = (mantissa as u128) * fraction.numerator();
+        let mut mantissa = mantissa as u128;
         let mut denominator = fraction.denominator();
-        if exponent > 0 {
-            numerator *= 2u128.pow(exponent as u32)
-        } else if exponent < 0 {
-            denominator *= 2u128.pow(-exponent as u32)
-        };
+        if exponent < 0 {
+            // The denominator is scaled by the binary exponent for as far as it stays small enough
+            // to be multiplied by the base; any bits shifted out of the mantissa beyond that only
+            // affect digits far past the precision of the float itself.
+            let shift = -exponent as u32;
+            let max_shift = denominator.leading_zeros().saturating_sub(8);
+            if shift > max_shift {
+                mantissa = mantissa.checked_shr(shift - max_shift).unwrap_or(0);
+            }
+            denominator <<= shift.min(max_shift);
+        }
+
+        // Only the remainder modulo the denominator matters, so it is computed with modular
+        // arithmetic throughout: large magnitudes would otherwise overflow the numerator.
+        let mut remainder = mul_mod(
+            mantissa % denominator,
+            fraction.numerator() % denominator,
+            denominator,
+        );
+        for _ in 0..exponent.max(0) {
+            remainder = add_mod(remainder, remainder, denominator);
+        }
 
         Self {
-            remainder: numerator % denominator,
+            remainder,
             denominator,
             base,
             precision,
@@ -165,6 +208,27 @@ impl FractionalDigitsIterator {
     }
 }
 
+/// Computes `(lhs + rhs) % modulus` for `lhs, rhs < modulus` without intermediate overflow.
+fn add_mod(lhs: u128, rhs: u128, modulus: u128) -> u128 {
+    if lhs >= modulus - rhs {
+        lhs - (modulus - rhs)
+    } else {
+        lhs + rhs
+    }
+}
+
+/// Computes `(lhs * rhs) % modulus` for `lhs, rhs < modulus` without intermediate overflow.
+fn mul_mod(lhs: u128, rhs: u128, modulus: u128) -> u128 {
+    let mut result = 0;
+    for bit in (0..128 - rhs.leading_zeros()).rev() {
+        result = add_mod(result, result, modulus);
+        if (rhs >> bit) & 1 == 1 {
+            result = add_mod(result, lhs, modulus);
+        }
+    }
+    result
+}
+
 impl Iterator for FractionalDigitsIterator {
     type Item = u8;
 
@@ -215,4 +279,22 @@ fn float_fractions() {
         .fractional_digits(Fraction::new(1, 1_000_000_000_000), Some(9), 10)
         .collect();
     assert_eq!(fraction, vec![2, 3, 4, 5, 6, 7, 8, 9, 0]);
+
+    // Extreme magnitudes must not overflow the intermediate numerator or denominator.
+    let fraction: Vec<_> = 1e300f64
+        .fractional_digits(Fraction::new(1, 1_000), Some(3), 10)
+        .collect();
+    assert_eq!(fraction, vec![1, 6, 0]);
+    let fraction: Vec<_> = 1e-300f64
+        .fractional_digits(Fraction::new(1, 1), Some(3), 10)
+        .collect();
+    assert_eq!(fraction, vec![0, 0, 0]);
+    let fraction: Vec<_> = 0.375f64
+        .fractional_digits(
+            Fraction::new(1_000_000_000_000_000_000_000_000_000, 1),
+            None,
+            10,
+        )
+        .collect();
+    assert_eq!(fraction, Vec::<u8>::new());
 }
@@ -1,8 +1,16 @@
 //! Supporting code for common arithmetic operations: casting, converting, fractions, etc.
 
+mod abs_diff;
+pub use abs_diff::AbsDiff;
+mod const_bounded;
+pub use const_bounded::ConstBounded;
+mod double_double;
+pub use double_double::DoubleDouble;
 mod fraction;
-pub use fraction::{Fraction, MulCeil, MulFloor, MulRound, TryMul};
+pub use fraction::{CheckedMulRound, Fraction, MulCeil, MulFloor, MulRound, TryMul};
 mod fractional_digits;
 pub use fractional_digits::FractionalDigits;
+mod rounding_mode;
+pub use rounding_mode::RoundingMode;
 mod try_exact;
 pub use try_exact::{TryFromExact, TryIntoExact};
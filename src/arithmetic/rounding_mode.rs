@@ -0,0 +1,17 @@
+//! Selection of the rounding behaviour applied when a value cannot be represented exactly.
+
+/// Direction in which inexact results are rounded, for example when converting a duration or time
+/// point into a coarser unit. This permits selecting the rounding behaviour at runtime, where the
+/// `round`, `ceil`, and `floor` functions fix it at compile time.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds to the nearest representable value.
+    #[default]
+    Nearest,
+    /// Rounds towards positive infinity.
+    Ceil,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards zero, discarding the fractional part.
+    TowardZero,
+}
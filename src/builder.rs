@@ -0,0 +1,212 @@
+//! Implementation of a builder that may be used to construct `TimePoint`s from their date-time
+//! components in a more readable manner than by passing all components as function arguments.
+
+use num_traits::Zero;
+
+use crate::{
+    Date, Duration, FromFineDateTime, Month, TimePoint, errors::DateTimeBuilderError, units::Second,
+};
+
+/// Builder for `TimePoint`s based on a calendar date, time-of-day, and subsecond part. A date must
+/// always be provided; the time-of-day defaults to midnight, and the subsecond part to zero.
+///
+/// Calendar dates are validated as soon as they are provided, but any error is only reported when
+/// `build` is called, such that the builder may be chained fluently.
+pub struct DateTimeBuilder<Scale: ?Sized, Representation = i64, Period: ?Sized = Second> {
+    date: Result<Date<i32>, DateTimeBuilderError<core::convert::Infallible>>,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    subseconds: Duration<Representation, Period>,
+    time_scale: core::marker::PhantomData<Scale>,
+}
+
+impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representation, Period> {
+    /// Returns a builder that may be used to construct a time point from its date-time components.
+    pub fn builder() -> DateTimeBuilder<Scale, Representation, Period>
+    where
+        Representation: Zero,
+    {
+        DateTimeBuilder::new()
+    }
+}
+
+impl<Scale: ?Sized, Representation, Period: ?Sized> DateTimeBuilder<Scale, Representation, Period> {
+    /// Creates a new builder, without a date and with a time-of-day of midnight.
+    pub fn new() -> Self
+    where
+        Representation: Zero,
+    {
+        Self {
+            date: Err(DateTimeBuilderError::MissingDate),
+            hour: 0,
+            minute: 0,
+            second: 0,
+            subseconds: Duration::zero(),
+            time_scale: core::marker::PhantomData,
+        }
+    }
+
+    /// Sets the date of the time point to be constructed.
+    pub fn date(mut self, date: Date<i32>) -> Self {
+        self.date = Ok(date);
+        self
+    }
+
+    /// Sets the date of the time point to be constructed, based on a date in the historic
+    /// calendar.
+    pub fn historic(mut self, year: i32, month: Month, day: u8) -> Self {
+        self.date = Date::from_historic_date(year, month, day).map_err(Into::into);
+        self
+    }
+
+    /// Sets the date of the time point to be constructed, based on a date in the proleptic
+    /// Gregorian calendar.
+    pub fn gregorian(mut self, year: i32, month: Month, day: u8) -> Self {
+        self.date = Date::from_gregorian_date(year, month, day).map_err(Into::into);
+        self
+    }
+
+    /// Sets the date of the time point to be constructed, based on a date in the proleptic Julian
+    /// calendar.
+    pub fn julian(mut self, year: i32, month: Month, day: u8) -> Self {
+        self.date = Date::from_julian_date(year, month, day).map_err(Into::into);
+        self
+    }
+
+    /// Sets the time-of-day of the time point to be constructed. Its validity is checked by the
+    /// time scale when the time point is built, since only the time scale knows whether a given
+    /// time-of-day exists (for example, for leap seconds).
+    pub fn time(mut self, hour: u8, minute: u8, second: u8) -> Self {
+        self.hour = hour;
+        self.minute = minute;
+        self.second = second;
+        self
+    }
+
+    /// Sets the subsecond part of the time point to be constructed.
+    pub fn subseconds(mut self, subseconds: Duration<Representation, Period>) -> Self {
+        self.subseconds = subseconds;
+        self
+    }
+
+    /// Constructs the time point described by this builder. Returns an error if no (valid) date
+    /// was provided, or if the date-time does not exist in the time scale.
+    #[allow(clippy::type_complexity)]
+    pub fn build(
+        self,
+    ) -> Result<
+        TimePoint<Scale, Representation, Period>,
+        DateTimeBuilderError<
+            <TimePoint<Scale, Representation, Period> as FromFineDateTime<
+                Representation,
+                Period,
+            >>::Error,
+        >,
+    >
+    where
+        TimePoint<Scale, Representation, Period>: FromFineDateTime<Representation, Period>,
+    {
+        let date = match self.date {
+            Ok(date) => date,
+            Err(DateTimeBuilderError::InvalidHistoricDate(error)) => Err(error)?,
+            Err(DateTimeBuilderError::InvalidGregorianDate(error)) => Err(error)?,
+            Err(DateTimeBuilderError::InvalidJulianDate(error)) => Err(error)?,
+            Err(DateTimeBuilderError::MissingDate) => Err(DateTimeBuilderError::MissingDate)?,
+            Err(DateTimeBuilderError::InvalidDateTime(infallible)) => match infallible {},
+        };
+        TimePoint::from_fine_datetime(date, self.hour, self.minute, self.second, self.subseconds)
+            .map_err(DateTimeBuilderError::InvalidDateTime)
+    }
+}
+
+impl<Scale: ?Sized, Representation, Period: ?Sized> Default
+    for DateTimeBuilder<Scale, Representation, Period>
+where
+    Representation: Zero,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Verifies that time points may be built with and without the optional time-of-day and
+/// subsecond components.
+#[test]
+fn build_time_points() {
+    use crate::{MilliSeconds, TaiTime, UtcTime, units::Milli};
+
+    let midnight = TaiTime::builder()
+        .gregorian(2024, Month::March, 1)
+        .build()
+        .unwrap();
+    assert_eq!(
+        midnight,
+        TaiTime::from_gregorian_datetime(2024, Month::March, 1, 0, 0, 0).unwrap()
+    );
+
+    let time_point = TaiTime::builder()
+        .gregorian(2024, Month::March, 1)
+        .time(12, 34, 56)
+        .build()
+        .unwrap();
+    assert_eq!(
+        time_point,
+        TaiTime::from_gregorian_datetime(2024, Month::March, 1, 12, 34, 56).unwrap()
+    );
+
+    let fine_time_point = TaiTime::<i64, Milli>::builder()
+        .time(12, 34, 56)
+        .subseconds(MilliSeconds::new(789))
+        .gregorian(2024, Month::March, 1)
+        .build()
+        .unwrap();
+    assert_eq!(
+        fine_time_point,
+        TaiTime::from_fine_gregorian_datetime(
+            2024,
+            Month::March,
+            1,
+            12,
+            34,
+            56,
+            MilliSeconds::new(789)
+        )
+        .unwrap()
+    );
+
+    let leap_second = UtcTime::<i64>::builder()
+        .historic(2016, Month::December, 31)
+        .time(23, 59, 60)
+        .build();
+    assert!(leap_second.is_ok());
+}
+
+/// Verifies that invalid or missing input is reported when building.
+#[test]
+fn invalid_input() {
+    use crate::{TaiTime, errors::InvalidGregorianDate};
+    assert!(matches!(
+        TaiTime::<i64>::builder().time(1, 2, 3).build(),
+        Err(DateTimeBuilderError::MissingDate)
+    ));
+    assert!(matches!(
+        TaiTime::<i64>::builder()
+            .gregorian(2023, Month::February, 29)
+            .build(),
+        Err(DateTimeBuilderError::InvalidGregorianDate(
+            InvalidGregorianDate {
+                year: 2023,
+                month: Month::February,
+                day: 29
+            }
+        ))
+    ));
+    assert!(matches!(
+        TaiTime::<i64>::builder()
+            .gregorian(2023, Month::February, 28)
+            .time(24, 0, 0)
+            .build(),
+        Err(DateTimeBuilderError::InvalidDateTime(_))
+    ));
+}
@@ -0,0 +1,209 @@
+//! Implementation of the Coptic calendar. The Ethiopic calendar has an identical structure and
+//! only differs in its epoch, so the arithmetic shared by both calendars is also defined here.
+
+use crate::{Calendar, Date, duration::Days, errors::InvalidCopticDate};
+
+/// Representation of a date in the Coptic (Alexandrian) calendar. Each year consists of twelve
+/// months of 30 days, followed by a thirteenth month of five days (six in leap years). Every
+/// fourth year is a leap year, without exception. Years are counted from the Era of the Martyrs,
+/// which started on 29 August 284 in the Julian calendar.
+///
+/// Months are represented by their number, 1 (Thout) through 13 (Pi Kogi Enavot).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CopticDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+/// Number of days from 1970-01-01 to the Coptic epoch, 1 Thout 1 (29 August 284 in the Julian
+/// calendar, or JD 1825029.5).
+const COPTIC_EPOCH: i64 = -615558;
+
+impl CopticDate {
+    /// Creates a new Coptic date, given its `year`, `month` (1 to 13), and `day`. If the date does
+    /// not exist in the Coptic calendar, or lies outside the range of a `Date<i32>`, returns an
+    /// `InvalidCopticDate`.
+    ///
+    /// This function will never panic.
+    pub const fn new(year: i32, month: u8, day: u8) -> Result<Self, InvalidCopticDate> {
+        if is_valid_date(year, month, day, COPTIC_EPOCH) {
+            Ok(Self { year, month, day })
+        } else {
+            Err(InvalidCopticDate { year, month, day })
+        }
+    }
+
+    /// Constructs a Coptic date from a given `Date<i32>` instance.
+    pub const fn from_date(date: Date<i32>) -> Self {
+        let (year, month, day) = ymd_from_date(date, COPTIC_EPOCH);
+        Self { year, month, day }
+    }
+
+    /// Constructs a `Date` from a given Coptic date.
+    pub const fn into_date(&self) -> Date<i32> {
+        date_from_ymd(self.year, self.month, self.day, COPTIC_EPOCH)
+    }
+
+    /// Returns the year stored inside this Coptic date, counted from the Era of the Martyrs.
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month number (1 to 13) stored inside this Coptic date.
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day-of-month stored inside this Coptic date.
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+/// Returns whether the given year is a leap year in the Coptic or Ethiopic calendar. Leap years
+/// are those directly preceding a year divisible by four.
+pub(crate) const fn is_leap_year(year: i32) -> bool {
+    year.rem_euclid(4) == 3
+}
+
+/// Returns whether the given combination of year, month, and day exists in a Coptic-style calendar
+/// with the given epoch, and whether it may be represented as a `Date<i32>`.
+pub(crate) const fn is_valid_date(year: i32, month: u8, day: u8, epoch: i64) -> bool {
+    let days_in_month = match month {
+        1..=12 => 30,
+        13 if is_leap_year(year) => 6,
+        13 => 5,
+        _ => return false,
+    };
+    if day == 0 || day > days_in_month {
+        return false;
+    }
+    let days = days_since_1970(year as i64, month as i64, day as i64, epoch);
+    days >= i32::MIN as i64 && days <= i32::MAX as i64
+}
+
+/// Computes the year, month, and day of some date in a Coptic-style calendar with the given
+/// epoch, expressed in days since 1970-01-01. Based on the algorithm given by Reingold and
+/// Dershowitz in "Calendrical Calculations".
+pub(crate) const fn ymd_from_date(date: Date<i32>, epoch: i64) -> (i32, u8, u8) {
+    let days = date.time_since_epoch().count() as i64;
+    let year = (4 * (days - epoch) + 1463).div_euclid(1461);
+    let start_of_year = days_since_1970(year, 1, 1, epoch);
+    let month = (days - start_of_year).div_euclid(30) + 1;
+    let day = days - days_since_1970(year, month, 1, epoch) + 1;
+    (year as i32, month as u8, day as u8)
+}
+
+/// Computes the `Date` of some year, month, and day in a Coptic-style calendar with the given
+/// epoch, expressed in days since 1970-01-01. The date must have been validated using
+/// `is_valid_date`, such that the number of days fits in an `i32`.
+pub(crate) const fn date_from_ymd(year: i32, month: u8, day: u8, epoch: i64) -> Date<i32> {
+    let days = days_since_1970(year as i64, month as i64, day as i64, epoch);
+    Date::from_time_since_epoch(Days::new(days as i32))
+}
+
+/// Number of days since 1970-01-01 of some year, month, and day in a Coptic-style calendar.
+const fn days_since_1970(year: i64, month: i64, day: i64, epoch: i64) -> i64 {
+    epoch - 1 + 365 * (year - 1) + year.div_euclid(4) + 30 * (month - 1) + day
+}
+
+impl From<CopticDate> for Date<i32> {
+    fn from(value: CopticDate) -> Self {
+        value.into_date()
+    }
+}
+
+impl From<Date<i32>> for CopticDate {
+    fn from(value: Date<i32>) -> Self {
+        Self::from_date(value)
+    }
+}
+
+impl Calendar for CopticDate {
+    type Month = u8;
+
+    const NAME: &'static str = "Coptic";
+
+    fn from_date(date: Date<i32>) -> Self {
+        Self::from_date(date)
+    }
+
+    fn to_date(&self) -> Date<i32> {
+        self.into_date()
+    }
+
+    fn year(&self) -> i32 {
+        self.year()
+    }
+
+    fn month(&self) -> u8 {
+        self.month()
+    }
+
+    fn day(&self) -> u8 {
+        self.day()
+    }
+}
+
+/// Verifies conversion of some known Coptic dates.
+#[test]
+fn known_dates() {
+    use crate::Month;
+    // Coptic new year 1740 (Nayrouz) fell on 12 September 2023.
+    let date = Date::from_gregorian_date(2023, Month::September, 12).unwrap();
+    assert_eq!(
+        CopticDate::from_date(date),
+        CopticDate::new(1740, 1, 1).unwrap()
+    );
+    assert_eq!(CopticDate::new(1740, 1, 1).unwrap().into_date(), date);
+
+    // The epoch itself, 29 August 284 in the Julian calendar.
+    let date = Date::from_julian_date(284, Month::August, 29).unwrap();
+    assert_eq!(
+        CopticDate::from_date(date),
+        CopticDate::new(1, 1, 1).unwrap()
+    );
+
+    // Coptic year 1739 was a leap year, so its thirteenth month had six days.
+    let date = Date::from_gregorian_date(2023, Month::September, 11).unwrap();
+    assert_eq!(
+        CopticDate::from_date(date),
+        CopticDate::new(1739, 13, 6).unwrap()
+    );
+    assert!(CopticDate::new(1740, 13, 6).is_err());
+    assert!(CopticDate::new(1740, 14, 1).is_err());
+}
+
+/// Verifies that conversion into and from dates roundtrips for a range of days.
+#[test]
+fn roundtrip() {
+    for days in -1_000_000..1_000_000 {
+        let date = Date::from_time_since_epoch(Days::new(days));
+        let coptic_date = CopticDate::from_date(date);
+        assert!(is_valid_date(
+            coptic_date.year(),
+            coptic_date.month(),
+            coptic_date.day(),
+            COPTIC_EPOCH
+        ));
+        assert_eq!(coptic_date.into_date(), date);
+    }
+}
+
+/// Verifies that Coptic dates are only accepted for as far as they fit in a `Date<i32>`.
+#[test]
+fn representable_range() {
+    let last = CopticDate::from_date(Date::from_time_since_epoch(Days::new(i32::MAX)));
+    let last = CopticDate::new(last.year(), last.month(), last.day()).unwrap();
+    assert_eq!(last.into_date().time_since_epoch().count(), i32::MAX);
+    assert!(CopticDate::new(last.year() + 1, 1, 1).is_err());
+
+    let first = CopticDate::from_date(Date::from_time_since_epoch(Days::new(i32::MIN)));
+    let first = CopticDate::new(first.year(), first.month(), first.day()).unwrap();
+    assert_eq!(first.into_date().time_since_epoch().count(), i32::MIN);
+    assert!(CopticDate::new(first.year() - 1, 13, 5).is_err());
+
+    assert!(CopticDate::new(i32::MAX, 1, 1).is_err());
+    assert!(CopticDate::new(i32::MIN, 1, 1).is_err());
+}
@@ -6,7 +6,9 @@ use core::ops::{Add, AddAssign, Sub, SubAssign};
 
 use crate::{
     Days, GregorianDate, HistoricDate, JulianDate, Month, TryIntoExact, WeekDay,
-    errors::{InvalidGregorianDate, InvalidHistoricDate, InvalidJulianDate},
+    errors::{
+        InvalidGregorianDate, InvalidHistoricDate, InvalidJulianDate, OrdinalDateParsingError,
+    },
 };
 
 /// Generic representation of date. Identifies an exact individual date within the calendar, in
@@ -128,6 +130,123 @@ impl Date<i32> {
             _ => unreachable!(),
         }
     }
+
+    /// Returns whether this date falls on a Saturday or Sunday.
+    pub const fn is_weekend(&self) -> bool {
+        matches!(self.week_day(), WeekDay::Saturday | WeekDay::Sunday)
+    }
+
+    /// Returns whether this date falls on a weekday: Monday through Friday.
+    pub const fn is_weekday(&self) -> bool {
+        !self.is_weekend()
+    }
+
+    /// Returns the first weekday (Monday through Friday) strictly after this date.
+    pub fn next_weekday(&self) -> Self {
+        self.add_business_days(1)
+    }
+
+    /// Moves the given number of business days (Monday through Friday) forward in time, or
+    /// backwards if `n` is negative, skipping over weekends. When starting from a weekend day, the
+    /// first step moves onto the nearest weekday in the direction of travel: for example, both
+    /// Friday and Saturday plus one business day result in Monday. Adding zero business days
+    /// returns this date itself, even if it falls on a weekend.
+    ///
+    /// # Panics
+    /// Panics if the resulting date is not representable as `Date<i32>`. Use
+    /// `checked_add_business_days` to handle this case instead.
+    pub fn add_business_days(&self, n: i32) -> Self {
+        self.checked_add_business_days(n)
+            .expect("business day arithmetic overflows `Date<i32>`")
+    }
+
+    /// Moves the given number of business days forward in time, or backwards if `n` is negative,
+    /// like `add_business_days`. Returns `None` if the resulting date is not representable as
+    /// `Date<i32>`.
+    pub fn checked_add_business_days(&self, n: i32) -> Option<Self> {
+        if n == 0 {
+            return Some(*self);
+        }
+        // Index of the week day, with Monday as 0 and Sunday as 6.
+        let week_day = (self.week_day() as i64 + 6) % 7;
+        let steps = i64::from(n).abs();
+        let (weeks, remainder) = (steps / 5, steps % 5);
+        let days = if n > 0 {
+            // Weekend days are treated as if they were the preceding Friday.
+            let (week_day, offset) = if week_day > 4 {
+                (4, 4 - week_day)
+            } else {
+                (week_day, 0)
+            };
+            let weekend = if week_day + remainder > 4 { 2 } else { 0 };
+            offset + weeks * 7 + remainder + weekend
+        } else {
+            // Weekend days are treated as if they were the next Monday.
+            let (week_day, offset) = if week_day > 4 {
+                (0, 7 - week_day)
+            } else {
+                (week_day, 0)
+            };
+            let weekend = if week_day - remainder < 0 { 2 } else { 0 };
+            offset - weeks * 7 - remainder - weekend
+        };
+        let days = i32::try_from(days).ok()?;
+        self.checked_add_days(Days::new(days))
+    }
+
+    /// Adds the given number of days to this date. Returns `None` if the resulting date is not
+    /// representable as `Date<i32>`: useful when stepping through dates in an open-ended fashion.
+    pub const fn checked_add_days(self, days: Days<i32>) -> Option<Self> {
+        match self.time_since_epoch().count().checked_add(days.count()) {
+            Some(count) => Some(Self::from_time_since_epoch(Days::new(count))),
+            None => None,
+        }
+    }
+
+    /// Returns the day directly following this date.
+    ///
+    /// Panics if the resulting date is not representable as `Date<i32>`.
+    pub const fn succ(self) -> Self {
+        match self.checked_add_days(Days::new(1)) {
+            Some(date) => date,
+            None => panic!("successor of date is not representable as `Date<i32>`"),
+        }
+    }
+
+    /// Returns the day directly preceding this date.
+    ///
+    /// Panics if the resulting date is not representable as `Date<i32>`.
+    pub const fn pred(self) -> Self {
+        match self.checked_add_days(Days::new(-1)) {
+            Some(date) => date,
+            None => panic!("predecessor of date is not representable as `Date<i32>`"),
+        }
+    }
+
+    /// Returns the signed number of calendar days elapsed since the passed date. The result is
+    /// widened to `i64`, such that it is always representable, even for dates at opposite ends of
+    /// the `i32` range. As with `elapsed_calendar_days_since`, leap seconds are not considered.
+    pub const fn signed_days_since(&self, other: Self) -> i64 {
+        self.time_since_epoch().count() as i64 - other.time_since_epoch().count() as i64
+    }
+
+    /// Returns the year, month, and day-of-month of this date in the proleptic Gregorian
+    /// calendar.
+    pub const fn year_month_day(&self) -> (i32, Month, u8) {
+        GregorianDate::from_date(*self).year_month_day()
+    }
+
+    /// Formats this date as an ISO 8601 ordinal date in the proleptic Gregorian calendar, like
+    /// "2024-015": the year, followed by the three-digit day-of-year.
+    #[cfg(feature = "std")]
+    pub fn to_ordinal_string(&self) -> String {
+        GregorianDate::from_date(*self).to_ordinal_string()
+    }
+
+    /// Parses an ISO 8601 ordinal date in the proleptic Gregorian calendar, like "2024-015".
+    pub fn from_ordinal_str(string: &str) -> Result<Self, OrdinalDateParsingError> {
+        Ok(GregorianDate::from_ordinal_str(string)?.into_date())
+    }
 }
 
 impl<Representation> Add<Days<Representation>> for Date<Representation>
@@ -185,6 +304,41 @@ fn epoch_at_1970_01_01() {
     assert_eq!(historic_date, historic_date2);
 }
 
+/// Verifies that day arithmetic near the edges of the `i32` range is detected, rather than silently
+/// overflowing.
+#[test]
+fn checked_day_arithmetic() {
+    let far_future = Date::from_time_since_epoch(Days::new(i32::MAX - 1));
+    assert_eq!(
+        far_future.checked_add_days(Days::new(1)),
+        Some(Date::from_time_since_epoch(Days::new(i32::MAX)))
+    );
+    assert_eq!(far_future.checked_add_days(Days::new(2)), None);
+
+    let far_past = Date::from_time_since_epoch(Days::new(i32::MIN));
+    assert_eq!(far_past.checked_add_days(Days::new(-1)), None);
+    assert_eq!(
+        far_future.succ(),
+        Date::from_time_since_epoch(Days::new(i32::MAX))
+    );
+    assert_eq!(far_future.succ().pred(), far_future);
+
+    let date = Date::from_historic_date(2000, Month::February, 28).unwrap();
+    assert_eq!(
+        date.checked_add_days(Days::new(2)),
+        Some(Date::from_historic_date(2000, Month::March, 1).unwrap())
+    );
+
+    assert_eq!(
+        far_future.signed_days_since(far_past),
+        i32::MAX as i64 - 1 - i32::MIN as i64
+    );
+    assert_eq!(
+        far_past.signed_days_since(far_future),
+        i32::MIN as i64 - i32::MAX as i64 + 1
+    );
+}
+
 /// Testing function that simply verifies whether a given historic date corresponds with a provided
 /// week day. If not, panics.
 #[cfg(test)]
@@ -261,3 +415,49 @@ mod infallibility {
         assert_eq!(date, date2);
     }
 }
+
+/// Verifies that weekends are recognized and skipped in business day arithmetic.
+#[test]
+fn business_days() {
+    let date = |day| Date::from_historic_date(2024, Month::March, day).unwrap();
+    // 1 March 2024 is a Friday.
+    let friday = date(1);
+    let saturday = date(2);
+    let sunday = date(3);
+    let monday = date(4);
+    assert!(saturday.is_weekend() && sunday.is_weekend());
+    assert!(friday.is_weekday() && monday.is_weekday());
+    assert!(!friday.is_weekend() && !saturday.is_weekday());
+
+    assert_eq!(friday.add_business_days(1), monday);
+    assert_eq!(saturday.add_business_days(1), monday);
+    assert_eq!(sunday.add_business_days(1), monday);
+    assert_eq!(monday.add_business_days(-1), friday);
+    assert_eq!(saturday.add_business_days(-1), friday);
+    assert_eq!(sunday.add_business_days(-1), friday);
+    assert_eq!(saturday.add_business_days(0), saturday);
+    assert_eq!(friday.next_weekday(), monday);
+    assert_eq!(monday.next_weekday(), date(5));
+    assert_eq!(monday.add_business_days(5), date(11));
+    assert_eq!(date(6).add_business_days(7), date(15));
+    assert_eq!(date(15).add_business_days(-7), date(6));
+
+    // Compare against naive day-by-day stepping.
+    for start in 1..=14 {
+        for n in -12..=12i32 {
+            let mut expected = date(start);
+            for _ in 0..n.abs() {
+                expected += Days::new(n.signum());
+                while expected.is_weekend() {
+                    expected += Days::new(n.signum());
+                }
+            }
+            assert_eq!(date(start).add_business_days(n), expected);
+        }
+    }
+
+    // Results beyond the range of `Date<i32>` are reported rather than wrapped.
+    assert_eq!(friday.checked_add_business_days(1), Some(monday));
+    assert_eq!(friday.checked_add_business_days(i32::MAX), None);
+    assert_eq!(friday.checked_add_business_days(i32::MIN), None);
+}
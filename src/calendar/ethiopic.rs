@@ -0,0 +1,145 @@
+//! Implementation of the Ethiopic calendar, which shares its structure with the Coptic calendar.
+
+use crate::{
+    Calendar, Date,
+    calendar::coptic::{date_from_ymd, is_valid_date, ymd_from_date},
+    errors::InvalidEthiopicDate,
+};
+
+/// Representation of a date in the Ethiopic calendar. Like the Coptic calendar, each year consists
+/// of twelve months of 30 days, followed by a thirteenth month of five days (six in leap years).
+/// Years are counted according to the Amete Mihret ("Year of Mercy") era, which started on 29
+/// August 8 in the Julian calendar.
+///
+/// Months are represented by their number, 1 (Meskerem) through 13 (Pagumen).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EthiopicDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+/// Number of days from 1970-01-01 to the Ethiopic epoch, 1 Meskerem 1 (29 August 8 in the Julian
+/// calendar, or JD 1724220.5).
+const ETHIOPIC_EPOCH: i64 = -716367;
+
+impl EthiopicDate {
+    /// Creates a new Ethiopic date, given its `year`, `month` (1 to 13), and `day`. If the date
+    /// does not exist in the Ethiopic calendar, or lies outside the range of a `Date<i32>`, returns
+    /// an `InvalidEthiopicDate`.
+    ///
+    /// This function will never panic.
+    pub const fn new(year: i32, month: u8, day: u8) -> Result<Self, InvalidEthiopicDate> {
+        if is_valid_date(year, month, day, ETHIOPIC_EPOCH) {
+            Ok(Self { year, month, day })
+        } else {
+            Err(InvalidEthiopicDate { year, month, day })
+        }
+    }
+
+    /// Constructs an Ethiopic date from a given `Date<i32>` instance.
+    pub const fn from_date(date: Date<i32>) -> Self {
+        let (year, month, day) = ymd_from_date(date, ETHIOPIC_EPOCH);
+        Self { year, month, day }
+    }
+
+    /// Constructs a `Date` from a given Ethiopic date.
+    pub const fn into_date(&self) -> Date<i32> {
+        date_from_ymd(self.year, self.month, self.day, ETHIOPIC_EPOCH)
+    }
+
+    /// Returns the year stored inside this Ethiopic date, counted from the Amete Mihret era.
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month number (1 to 13) stored inside this Ethiopic date.
+    pub const fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day-of-month stored inside this Ethiopic date.
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+}
+
+impl From<EthiopicDate> for Date<i32> {
+    fn from(value: EthiopicDate) -> Self {
+        value.into_date()
+    }
+}
+
+impl From<Date<i32>> for EthiopicDate {
+    fn from(value: Date<i32>) -> Self {
+        Self::from_date(value)
+    }
+}
+
+impl Calendar for EthiopicDate {
+    type Month = u8;
+
+    const NAME: &'static str = "Ethiopic";
+
+    fn from_date(date: Date<i32>) -> Self {
+        Self::from_date(date)
+    }
+
+    fn to_date(&self) -> Date<i32> {
+        self.into_date()
+    }
+
+    fn year(&self) -> i32 {
+        self.year()
+    }
+
+    fn month(&self) -> u8 {
+        self.month()
+    }
+
+    fn day(&self) -> u8 {
+        self.day()
+    }
+}
+
+/// Verifies conversion of some known Ethiopic dates.
+#[test]
+fn known_dates() {
+    use crate::{CopticDate, Month};
+    // Ethiopian New Year 2016 fell on 12 September 2023.
+    let date = Date::from_gregorian_date(2023, Month::September, 12).unwrap();
+    assert_eq!(
+        EthiopicDate::from_date(date),
+        EthiopicDate::new(2016, 1, 1).unwrap()
+    );
+    assert_eq!(EthiopicDate::new(2016, 1, 1).unwrap().into_date(), date);
+
+    // Ethiopian Christmas (Genna) 2016 fell on 7 January 2024. Because 2015 was a leap year, it
+    // was celebrated on 28 Tahsas rather than the usual 29 Tahsas.
+    let date = Date::from_gregorian_date(2024, Month::January, 7).unwrap();
+    assert_eq!(
+        EthiopicDate::from_date(date),
+        EthiopicDate::new(2016, 4, 28).unwrap()
+    );
+
+    let time_point =
+        crate::UtcTime::from_gregorian_datetime(2023, Month::September, 12, 6, 0, 0).unwrap();
+    assert_eq!(
+        time_point.into_ethiopic_datetime(),
+        (EthiopicDate::new(2016, 1, 1).unwrap(), 6, 0, 0)
+    );
+    assert_eq!(
+        time_point.into_coptic_datetime(),
+        (CopticDate::new(1740, 1, 1).unwrap(), 6, 0, 0)
+    );
+
+    // The Ethiopic calendar is exactly 276 years ahead of the Coptic one.
+    for days in -100_000..100_000 {
+        let date = Date::from_time_since_epoch(crate::Days::new(days));
+        let ethiopic = EthiopicDate::from_date(date);
+        let coptic = CopticDate::from_date(date);
+        assert_eq!(ethiopic.year(), coptic.year() + 276);
+        assert_eq!(ethiopic.month(), coptic.month());
+        assert_eq!(ethiopic.day(), coptic.day());
+    }
+}
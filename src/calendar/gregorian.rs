@@ -2,10 +2,10 @@
 //! calendar.
 
 use crate::{
-    Date, Month,
-    calendar::historic::month_day_from_ordinal_date,
+    Calendar, Date, Month, WeekDay, YearMonth,
+    calendar::{PaddedYear, historic::month_day_from_ordinal_date},
     duration::Days,
-    errors::{InvalidDayOfYear, InvalidGregorianDate},
+    errors::{InvalidDayOfYear, InvalidGregorianDate, InvalidPackedDate},
 };
 
 /// Representation of a proleptic Gregorian date. Only represents logic down to single-day
@@ -83,6 +83,9 @@ impl GregorianDate {
     /// Creates a new date given only the year and the day-of-year. Implementation is based on an
     /// algorithm found by A. Pouplier and reported by Jean Meeus in Astronomical Algorithms.
     ///
+    /// Uses the proleptic Gregorian leap year rule for all years: in contrast with the historic
+    /// calendar, no days are skipped around the 1582 calendar reform.
+    ///
     /// This function will never panic.
     pub const fn from_ordinal_date(year: i32, day_of_year: u16) -> Result<Self, InvalidDayOfYear> {
         let is_leap_year = Self::is_leap_year(year);
@@ -96,6 +99,49 @@ impl GregorianDate {
         }
     }
 
+    /// Decodes a date packed into a single integer as `yyyymmdd`: for example, 15 January 2024 is
+    /// stored as `20240115`. This is a common interchange format in legacy systems. Negative years
+    /// are encoded by negating the packed value as a whole, such that `-yyyymmdd` represents day
+    /// `dd` of month `mm` in year `-yyyy`: for example, `-440315` is 15 March of the year -44 (in
+    /// astronomical year numbering). Years 0 through 9999 are always encoded as non-negative
+    /// values.
+    ///
+    /// Returns an error if the month or day fields do not form a valid date.
+    pub const fn from_packed(packed: i32) -> Result<Self, InvalidPackedDate> {
+        let magnitude = packed.unsigned_abs();
+        let year = (magnitude / 10000) as i32;
+        let year = if packed < 0 { -year } else { year };
+        let month = match Month::try_from(((magnitude / 100) % 100) as u8) {
+            Ok(month) => month,
+            Err(error) => return Err(InvalidPackedDate::InvalidMonthNumber(error)),
+        };
+        let day = (magnitude % 100) as u8;
+        match Self::new(year, month, day) {
+            Ok(date) => Ok(date),
+            Err(error) => Err(InvalidPackedDate::InvalidGregorianDate(error)),
+        }
+    }
+
+    /// Encodes this date as a single integer `yyyymmdd`, using the sign convention described in
+    /// `GregorianDate::from_packed`.
+    ///
+    /// Only years in the range -214748 up to and including 214748 can be represented this way:
+    /// this function panics for dates outside that range.
+    pub const fn to_packed(&self) -> i32 {
+        let fields = self.month as i32 * 100 + self.day as i32;
+        match self.year.checked_mul(10000) {
+            Some(year) if year < 0 => match year.checked_sub(fields) {
+                Some(packed) => packed,
+                None => panic!("year cannot be represented in packed yyyymmdd format"),
+            },
+            Some(year) => match year.checked_add(fields) {
+                Some(packed) => packed,
+                None => panic!("year cannot be represented in packed yyyymmdd format"),
+            },
+            None => panic!("year cannot be represented in packed yyyymmdd format"),
+        }
+    }
+
     /// Returns the year stored inside this proleptic Gregorian date. Astronomical year
     /// numbering is used (as also done in NAIF SPICE): the year 1 BCE is represented as 0, 2 BCE as
     /// -1, etc. Hence, around the year 0, the numbering is ..., -2 (3 BCE), -1 (2 BCE), 0 (1 BCE),
@@ -114,8 +160,98 @@ impl GregorianDate {
         self.day
     }
 
+    /// Returns the year, month, and day-of-month of this date as a tuple.
+    pub const fn year_month_day(&self) -> (i32, Month, u8) {
+        (self.year, self.month, self.day)
+    }
+
+    /// Returns the year and month in which this date falls, without the day-of-month. Useful for
+    /// bucketing dates by month.
+    pub const fn year_month(&self) -> YearMonth {
+        YearMonth::new(self.year, self.month)
+    }
+
+    /// Returns the day-of-year of this specific date, within its calendar year. The day-of-year is
+    /// an integer value ranging from 1 on January 1 to 365 (or 366, in leap years) on December 31.
+    /// Uses the algorithm given by Meeus in Astronomical Algorithms.
+    pub const fn day_of_year(&self) -> u16 {
+        let k = if Self::is_leap_year(self.year) { 1 } else { 2 };
+        let m = self.month as u16;
+        let d = self.day as u16;
+        ((275 * m) / 9) - k * ((m + 9) / 12) + d - 30
+    }
+
+    /// Formats this date as an ISO 8601 ordinal date, like "2024-015": the year, followed by the
+    /// three-digit day-of-year. Years are padded to at least four digits.
+    #[cfg(feature = "std")]
+    pub fn to_ordinal_string(&self) -> String {
+        format!("{}-{:03}", PaddedYear(self.year), self.day_of_year())
+    }
+
+    /// Returns the day directly following this date.
+    ///
+    /// Panics if the resulting date is not representable.
+    pub const fn succ(self) -> Self {
+        Self::from_date(self.into_date().succ())
+    }
+
+    /// Returns the day directly preceding this date.
+    ///
+    /// Panics if the resulting date is not representable.
+    pub const fn pred(self) -> Self {
+        Self::from_date(self.into_date().pred())
+    }
+
+    /// Returns the first day of the month in which this date falls.
+    pub const fn first_of_month(&self) -> Self {
+        Self {
+            year: self.year,
+            month: self.month,
+            day: 1,
+        }
+    }
+
+    /// Returns the last day of the month in which this date falls.
+    pub const fn last_of_month(&self) -> Self {
+        Self {
+            year: self.year,
+            month: self.month,
+            day: Self::days_in_month(self.year, self.month),
+        }
+    }
+
+    /// Returns the `n`th occurrence of `week_day` within the given month, counting from 1: for
+    /// example, the third Monday of January 2025 is obtained with `n = 3`. Returns `None` if that
+    /// occurrence does not exist, which is always the case for `n = 0` or `n > 5`.
+    pub const fn nth_weekday_of_month(
+        year: i32,
+        month: Month,
+        week_day: WeekDay,
+        n: u8,
+    ) -> Option<Self> {
+        if n == 0 || n > 5 {
+            return None;
+        }
+        let first = Self {
+            year,
+            month,
+            day: 1,
+        }
+        .into_date();
+        let offset = (week_day as i32 - first.week_day() as i32).rem_euclid(7) + 7 * (n as i32 - 1);
+        let date = match first.checked_add_days(Days::new(offset)) {
+            Some(date) => Self::from_date(date),
+            None => return None,
+        };
+        if date.year == year && date.month as u8 == month as u8 {
+            Some(date)
+        } else {
+            None
+        }
+    }
+
     /// Returns the number of days in a given month of a year.
-    const fn days_in_month(year: i32, month: Month) -> u8 {
+    pub(crate) const fn days_in_month(year: i32, month: Month) -> u8 {
         use crate::Month::*;
         match month {
             January | March | May | July | August | October | December => 31,
@@ -154,6 +290,277 @@ impl From<Date<i32>> for GregorianDate {
     }
 }
 
+/// Formats this date as ISO 8601 calendar date, like "2024-03-15". Years are padded to at least
+/// four digits, and negative years (in astronomical year numbering) are preceded by a minus sign:
+/// for example, "-0044-03-15".
+impl core::fmt::Display for GregorianDate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}-{:02}-{:02}",
+            PaddedYear(self.year),
+            self.month as u8,
+            self.day
+        )
+    }
+}
+
+impl Calendar for GregorianDate {
+    type Month = Month;
+
+    const NAME: &'static str = "Proleptic Gregorian";
+
+    fn from_date(date: Date<i32>) -> Self {
+        Self::from_date(date)
+    }
+
+    fn to_date(&self) -> Date<i32> {
+        self.into_date()
+    }
+
+    fn year(&self) -> i32 {
+        self.year()
+    }
+
+    fn month(&self) -> Month {
+        self.month()
+    }
+
+    fn day(&self) -> u8 {
+        self.day()
+    }
+}
+
+/// Verifies the month navigation helpers against some known dates.
+#[test]
+fn month_navigation() {
+    let date = GregorianDate::new(2024, Month::February, 13).unwrap();
+    assert_eq!(
+        date.first_of_month(),
+        GregorianDate::new(2024, Month::February, 1).unwrap()
+    );
+    assert_eq!(
+        date.last_of_month(),
+        GregorianDate::new(2024, Month::February, 29).unwrap()
+    );
+    let date = GregorianDate::new(2023, Month::February, 13).unwrap();
+    assert_eq!(date.last_of_month().day(), 28);
+
+    assert_eq!(
+        GregorianDate::nth_weekday_of_month(2025, Month::January, WeekDay::Monday, 3),
+        Some(GregorianDate::new(2025, Month::January, 20).unwrap())
+    );
+    assert_eq!(
+        GregorianDate::nth_weekday_of_month(2025, Month::January, WeekDay::Wednesday, 1),
+        Some(GregorianDate::new(2025, Month::January, 1).unwrap())
+    );
+    assert_eq!(
+        GregorianDate::nth_weekday_of_month(2025, Month::January, WeekDay::Friday, 5),
+        Some(GregorianDate::new(2025, Month::January, 31).unwrap())
+    );
+    assert_eq!(
+        GregorianDate::nth_weekday_of_month(2025, Month::February, WeekDay::Monday, 5),
+        None
+    );
+    assert_eq!(
+        GregorianDate::nth_weekday_of_month(2025, Month::January, WeekDay::Monday, 0),
+        None
+    );
+}
+
+/// Verifies that dates packed as `yyyymmdd` integers are decoded and validated correctly.
+#[test]
+fn packed() {
+    let date = GregorianDate::from_packed(20240229).unwrap();
+    assert_eq!(date, GregorianDate::new(2024, Month::February, 29).unwrap());
+    assert_eq!(date.to_packed(), 20240229);
+    assert_eq!(
+        GregorianDate::from_packed(20240230),
+        Err(InvalidPackedDate::InvalidGregorianDate(
+            InvalidGregorianDate {
+                year: 2024,
+                month: Month::February,
+                day: 30
+            }
+        ))
+    );
+    assert!(matches!(
+        GregorianDate::from_packed(20241301),
+        Err(InvalidPackedDate::InvalidMonthNumber(_))
+    ));
+    assert!(GregorianDate::from_packed(20240100).is_err());
+
+    let date = GregorianDate::from_packed(-440315).unwrap();
+    assert_eq!(date, GregorianDate::new(-44, Month::March, 15).unwrap());
+    assert_eq!(date.to_packed(), -440315);
+    let date = GregorianDate::new(0, Month::January, 1).unwrap();
+    assert_eq!(date.to_packed(), 101);
+    assert_eq!(GregorianDate::from_packed(101), Ok(date));
+
+    let date = GregorianDate::new(214_748, Month::December, 31).unwrap();
+    assert_eq!(date.to_packed(), 2_147_481_231);
+    let date = GregorianDate::new(-214_748, Month::December, 31).unwrap();
+    assert_eq!(date.to_packed(), -2_147_481_231);
+}
+
+/// Verifies that ordinal dates are resolved using the proleptic Gregorian leap year rule, which
+/// differs from the Julian one for century years such as 1900.
+#[test]
+fn from_ordinal_date() {
+    use crate::{JulianDate, errors::InvalidDayOfYearCount};
+    assert_eq!(
+        GregorianDate::from_ordinal_date(2000, 366),
+        Ok(GregorianDate::new(2000, Month::December, 31).unwrap())
+    );
+    assert_eq!(
+        GregorianDate::from_ordinal_date(1900, 366),
+        Err(InvalidDayOfYear::InvalidDayOfYearCount(
+            InvalidDayOfYearCount {
+                day_of_year: 366,
+                year: 1900
+            }
+        ))
+    );
+    assert_eq!(
+        JulianDate::from_ordinal_date(1900, 366),
+        Ok(JulianDate::new(1900, Month::December, 31).unwrap())
+    );
+    assert_eq!(
+        GregorianDate::from_ordinal_date(1900, 60),
+        Ok(GregorianDate::new(1900, Month::March, 1).unwrap())
+    );
+    assert_eq!(
+        JulianDate::from_ordinal_date(1900, 60),
+        Ok(JulianDate::new(1900, Month::February, 29).unwrap())
+    );
+    // Unlike historic dates, proleptic dates do not skip the October 1582 calendar reform.
+    assert_eq!(
+        GregorianDate::from_ordinal_date(1582, 278),
+        Ok(GregorianDate::new(1582, Month::October, 5).unwrap())
+    );
+}
+
+/// Verifies that ordinal dates round-trip through their string representation, including the last
+/// day of a leap year, and that out-of-range days are rejected.
+#[cfg(feature = "std")]
+#[test]
+fn ordinal_string() {
+    use crate::errors::{InvalidDayOfYearCount, OrdinalDateParsingError};
+    let date = GregorianDate::new(2024, Month::January, 15).unwrap();
+    assert_eq!(date.to_ordinal_string(), "2024-015");
+    assert_eq!(GregorianDate::from_ordinal_str("2024-015"), Ok(date));
+
+    let date = GregorianDate::new(2024, Month::December, 31).unwrap();
+    assert_eq!(date.day_of_year(), 366);
+    assert_eq!(date.to_ordinal_string(), "2024-366");
+    assert_eq!(GregorianDate::from_ordinal_str("2024-366"), Ok(date));
+    assert_eq!(
+        GregorianDate::from_ordinal_str("2023-366"),
+        Err(OrdinalDateParsingError::InvalidDayOfYear(
+            InvalidDayOfYear::InvalidDayOfYearCount(InvalidDayOfYearCount {
+                day_of_year: 366,
+                year: 2023
+            })
+        ))
+    );
+    assert_eq!(
+        GregorianDate::from_ordinal_str("2024-15"),
+        Err(OrdinalDateParsingError::DayOfYearRepresentationNotThreeDigits)
+    );
+    assert!(matches!(
+        GregorianDate::from_ordinal_str("2024-000"),
+        Err(OrdinalDateParsingError::InvalidDayOfYear(_))
+    ));
+    assert_eq!(
+        GregorianDate::from_ordinal_str("2024-015T"),
+        Err(OrdinalDateParsingError::UnexpectedRemainder)
+    );
+
+    let date = GregorianDate::new(-44, Month::March, 15).unwrap();
+    assert_eq!(date.to_ordinal_string(), "-0044-075");
+    assert_eq!(
+        GregorianDate::from_ordinal_str(&date.to_ordinal_string()),
+        Ok(date)
+    );
+    let date = Date::from_gregorian_date(2000, Month::February, 29).unwrap();
+    assert_eq!(date.to_ordinal_string(), "2000-060");
+    assert_eq!(Date::from_ordinal_str("2000-060"), Ok(date));
+}
+
+/// Verifies that calendar dates are displayed in ISO 8601 format, with years padded to four digits
+/// and negative years preceded by a minus sign, and that they round-trip through `FromStr`.
+#[cfg(feature = "std")]
+#[test]
+fn display_roundtrip() {
+    use core::str::FromStr;
+    let cases = [
+        (
+            GregorianDate::new(-44, Month::March, 15).unwrap(),
+            "-0044-03-15",
+        ),
+        (
+            GregorianDate::new(0, Month::January, 1).unwrap(),
+            "0000-01-01",
+        ),
+        (
+            GregorianDate::new(-1, Month::December, 31).unwrap(),
+            "-0001-12-31",
+        ),
+        (
+            GregorianDate::new(837, Month::April, 10).unwrap(),
+            "0837-04-10",
+        ),
+        (
+            GregorianDate::new(2024, Month::February, 29).unwrap(),
+            "2024-02-29",
+        ),
+        (
+            GregorianDate::new(-12345, Month::June, 1).unwrap(),
+            "-12345-06-01",
+        ),
+        (
+            GregorianDate::new(12345, Month::June, 1).unwrap(),
+            "12345-06-01",
+        ),
+    ];
+    for (date, string) in cases {
+        assert_eq!(date.to_string(), string);
+        assert_eq!(GregorianDate::from_str(string), Ok(date));
+    }
+
+    let date = crate::HistoricDate::new(-44, Month::March, 15).unwrap();
+    assert_eq!(date.to_string(), "-0044-03-15");
+    assert_eq!(crate::HistoricDate::from_str("-0044-03-15"), Ok(date));
+    assert_eq!(
+        crate::HistoricDate::new(5, Month::May, 5)
+            .unwrap()
+            .to_string(),
+        "0005-05-05"
+    );
+}
+
+/// Verifies that single-day navigation rolls over across month and year boundaries.
+#[test]
+fn single_day_navigation() {
+    let date = GregorianDate::new(2024, Month::February, 28).unwrap();
+    assert_eq!(
+        date.succ(),
+        GregorianDate::new(2024, Month::February, 29).unwrap()
+    );
+    assert_eq!(
+        date.succ().succ(),
+        GregorianDate::new(2024, Month::March, 1).unwrap()
+    );
+    assert_eq!(
+        GregorianDate::new(2025, Month::January, 1).unwrap().pred(),
+        GregorianDate::new(2024, Month::December, 31).unwrap()
+    );
+    assert_eq!(
+        GregorianDate::new(1582, Month::October, 4).unwrap().succ(),
+        GregorianDate::new(1582, Month::October, 5).unwrap()
+    );
+}
+
 /// Verifies that roundtrip conversion for some random dates conserves the date.
 #[test]
 fn roundtrip() {
@@ -0,0 +1,400 @@
+//! Implementation of the arithmetic Hebrew calendar, as described by Reingold and Dershowitz in
+//! "Calendrical Calculations".
+
+use crate::{Calendar, Date, duration::Days, errors::InvalidHebrewDate};
+
+/// Representation of a date in the arithmetic Hebrew calendar. This is a lunisolar calendar: months
+/// follow the mean lunation, while a 19-year Metonic cycle of 7 leap years, each of which inserts
+/// an additional month (Adar I), keeps the years aligned with the seasons. The start of each year
+/// is determined by the molad (mean conjunction) of Tishrei, subject to a set of postponement
+/// rules. As a result, common years are 353 to 355 days long and leap years 383 to 385 days.
+///
+/// Years are counted from the epoch of the world (Anno Mundi), 7 October 3761 BCE in the Julian
+/// calendar. Note that the Hebrew day formally starts at sunset: as elsewhere in this library,
+/// dates are instead identified with the civil day on which their daylight part falls.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HebrewDate {
+    year: i32,
+    month: HebrewMonth,
+    day: u8,
+}
+
+/// Representation of a month in the Hebrew calendar. In leap years, the month Adar is replaced by
+/// the two months Adar I and Adar II: `Adar` may only be used in common years, while `AdarI` and
+/// `AdarII` may only be used in leap years.
+///
+/// Months are numbered from Nisan, following the biblical convention, even though the year number
+/// increments at the start of Tishrei.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+#[cfg_attr(kani, derive(kani::Arbitrary))]
+pub enum HebrewMonth {
+    Nisan,
+    Iyar,
+    Sivan,
+    Tammuz,
+    Av,
+    Elul,
+    Tishrei,
+    Cheshvan,
+    Kislev,
+    Tevet,
+    Shevat,
+    Adar,
+    AdarI,
+    AdarII,
+}
+
+/// Number of days from 1970-01-01 to the Hebrew epoch, 1 Tishrei 1 AM (7 October 3761 BCE in the
+/// proleptic Julian calendar, or R.D. -1373427).
+const HEBREW_EPOCH: i64 = -2092590;
+
+impl HebrewDate {
+    /// Creates a new Hebrew date, given its `year`, `month`, and `day`. If the date does not exist
+    /// in the Hebrew calendar, returns an `InvalidHebrewDate`. This includes the case where
+    /// `Adar` is used in a leap year, or `AdarI` or `AdarII` in a common year, as well as dates that
+    /// lie outside the range of a `Date<i32>`.
+    ///
+    /// This function will never panic.
+    pub const fn new(year: i32, month: HebrewMonth, day: u8) -> Result<Self, InvalidHebrewDate> {
+        if Self::is_valid_date(year, month, day) {
+            Ok(Self { year, month, day })
+        } else {
+            Err(InvalidHebrewDate { year, month, day })
+        }
+    }
+
+    /// Constructs a Hebrew date from a given `Date<i32>` instance.
+    pub const fn from_date(date: Date<i32>) -> Self {
+        let days = date.time_since_epoch().count() as i64;
+        // Based on the mean year length, we find a lower bound on the year, after which we search
+        // for the last year that started before the given date.
+        let mut year = (98496 * (days - HEBREW_EPOCH)).div_euclid(35975351);
+        while new_year(year + 1) <= days {
+            year += 1;
+        }
+
+        // Months are numbered from Nisan, but the year starts at Tishrei.
+        let mut month = if days < days_since_1970(year, 1, 1) {
+            7
+        } else {
+            1
+        };
+        while days > days_since_1970(year, month, last_day_of_month(year, month) as i64) {
+            month += 1;
+        }
+        let day = days - days_since_1970(year, month, 1) + 1;
+        Self {
+            year: year as i32,
+            month: HebrewMonth::from_number(month as u8, is_leap_year(year)),
+            day: day as u8,
+        }
+    }
+
+    /// Constructs a `Date` from a given Hebrew date. Since the date was validated on construction,
+    /// the number of days is guaranteed to fit in an `i32`.
+    pub const fn into_date(&self) -> Date<i32> {
+        let days = days_since_1970(
+            self.year as i64,
+            self.month.number() as i64,
+            self.day as i64,
+        );
+        Date::from_time_since_epoch(Days::new(days as i32))
+    }
+
+    /// Returns the year stored inside this Hebrew date, counted from the epoch of the world.
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month stored inside this Hebrew date.
+    pub const fn month(&self) -> HebrewMonth {
+        self.month
+    }
+
+    /// Returns the day-of-month stored inside this Hebrew date.
+    pub const fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns whether the given year is a leap year, containing the additional month Adar I.
+    /// Leap years are years 3, 6, 8, 11, 14, 17, and 19 of each 19-year Metonic cycle.
+    pub const fn is_leap_year(year: i32) -> bool {
+        is_leap_year(year as i64)
+    }
+
+    /// Returns the number of days in the given year, which ranges from 353 to 355 for common
+    /// years and from 383 to 385 for leap years.
+    pub const fn days_in_year(year: i32) -> u16 {
+        days_in_year(year as i64) as u16
+    }
+
+    /// Returns the number of days in a given month of a year. Returns zero if the month does not
+    /// occur in that year.
+    pub const fn days_in_month(year: i32, month: HebrewMonth) -> u8 {
+        let is_leap_year = Self::is_leap_year(year);
+        match month {
+            HebrewMonth::Adar if is_leap_year => 0,
+            HebrewMonth::AdarI | HebrewMonth::AdarII if !is_leap_year => 0,
+            _ => last_day_of_month(year as i64, month.number() as i64),
+        }
+    }
+
+    /// Returns whether the given calendar date is a valid Hebrew calendar date that may be
+    /// represented as a `Date<i32>`.
+    const fn is_valid_date(year: i32, month: HebrewMonth, day: u8) -> bool {
+        if day == 0 || day > Self::days_in_month(year, month) {
+            return false;
+        }
+        let days = days_since_1970(year as i64, month.number() as i64, day as i64);
+        days >= i32::MIN as i64 && days <= i32::MAX as i64
+    }
+}
+
+impl HebrewMonth {
+    /// Returns the number of this month, counting from Nisan (1). Adar and Adar I are both month
+    /// 12; Adar II is month 13.
+    pub(crate) const fn number(&self) -> u8 {
+        match self {
+            Self::Nisan => 1,
+            Self::Iyar => 2,
+            Self::Sivan => 3,
+            Self::Tammuz => 4,
+            Self::Av => 5,
+            Self::Elul => 6,
+            Self::Tishrei => 7,
+            Self::Cheshvan => 8,
+            Self::Kislev => 9,
+            Self::Tevet => 10,
+            Self::Shevat => 11,
+            Self::Adar | Self::AdarI => 12,
+            Self::AdarII => 13,
+        }
+    }
+
+    /// Returns the month with the given number, counting from Nisan (1). Whether month 12 is Adar
+    /// or Adar I depends on whether the year is a leap year.
+    const fn from_number(month: u8, is_leap_year: bool) -> Self {
+        match month {
+            1 => Self::Nisan,
+            2 => Self::Iyar,
+            3 => Self::Sivan,
+            4 => Self::Tammuz,
+            5 => Self::Av,
+            6 => Self::Elul,
+            7 => Self::Tishrei,
+            8 => Self::Cheshvan,
+            9 => Self::Kislev,
+            10 => Self::Tevet,
+            11 => Self::Shevat,
+            12 if is_leap_year => Self::AdarI,
+            12 => Self::Adar,
+            13 => Self::AdarII,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Returns whether the given year is a Hebrew leap year.
+const fn is_leap_year(year: i64) -> bool {
+    (7 * year + 1).rem_euclid(19) < 7
+}
+
+/// Returns the number of days elapsed from the epoch until the molad of Tishrei of the given year,
+/// taking into account the postponement rule that prevents Rosh Hashanah from falling on a Sunday,
+/// Wednesday, or Friday.
+const fn elapsed_days(year: i64) -> i64 {
+    let months_elapsed = (235 * year - 234).div_euclid(19);
+    let parts_elapsed = 12084 + 13753 * months_elapsed;
+    let days = 29 * months_elapsed + parts_elapsed.div_euclid(25920);
+    if (3 * (days + 1)).rem_euclid(7) < 3 {
+        days + 1
+    } else {
+        days
+    }
+}
+
+/// Returns the additional postponement of the new year needed to keep year lengths within their
+/// permitted ranges.
+const fn year_length_correction(year: i64) -> i64 {
+    let previous = elapsed_days(year - 1);
+    let current = elapsed_days(year);
+    let next = elapsed_days(year + 1);
+    if next - current == 356 {
+        2
+    } else if current - previous == 382 {
+        1
+    } else {
+        0
+    }
+}
+
+/// Returns the number of days since 1970-01-01 of Rosh Hashanah (1 Tishrei) of the given year.
+const fn new_year(year: i64) -> i64 {
+    HEBREW_EPOCH + elapsed_days(year) + year_length_correction(year)
+}
+
+/// Returns the number of days in the given year.
+const fn days_in_year(year: i64) -> i64 {
+    new_year(year + 1) - new_year(year)
+}
+
+/// Returns the number of days in the given month, numbered from Nisan (1). Cheshvan and Kislev
+/// vary in length to absorb the differences in year length.
+const fn last_day_of_month(year: i64, month: i64) -> u8 {
+    let days_in_year = days_in_year(year);
+    match month {
+        2 | 4 | 6 | 10 | 13 => 29,
+        12 if !is_leap_year(year) => 29,
+        8 if days_in_year % 10 != 5 => 29,
+        9 if days_in_year % 10 == 3 => 29,
+        _ => 30,
+    }
+}
+
+/// Number of days since 1970-01-01 of some year, month (numbered from Nisan), and day in the
+/// Hebrew calendar.
+const fn days_since_1970(year: i64, month: i64, day: i64) -> i64 {
+    let last_month = if is_leap_year(year) { 13 } else { 12 };
+    let mut days = new_year(year) + day - 1;
+    if month < 7 {
+        let mut m = 7;
+        while m <= last_month {
+            days += last_day_of_month(year, m) as i64;
+            m += 1;
+        }
+        let mut m = 1;
+        while m < month {
+            days += last_day_of_month(year, m) as i64;
+            m += 1;
+        }
+    } else {
+        let mut m = 7;
+        while m < month {
+            days += last_day_of_month(year, m) as i64;
+            m += 1;
+        }
+    }
+    days
+}
+
+impl From<HebrewDate> for Date<i32> {
+    fn from(value: HebrewDate) -> Self {
+        value.into_date()
+    }
+}
+
+impl From<Date<i32>> for HebrewDate {
+    fn from(value: Date<i32>) -> Self {
+        Self::from_date(value)
+    }
+}
+
+impl Calendar for HebrewDate {
+    type Month = HebrewMonth;
+
+    const NAME: &'static str = "Hebrew";
+
+    fn from_date(date: Date<i32>) -> Self {
+        Self::from_date(date)
+    }
+
+    fn to_date(&self) -> Date<i32> {
+        self.into_date()
+    }
+
+    fn year(&self) -> i32 {
+        self.year()
+    }
+
+    fn month(&self) -> HebrewMonth {
+        self.month()
+    }
+
+    fn day(&self) -> u8 {
+        self.day()
+    }
+}
+
+/// Verifies conversion of some known Hebrew dates.
+#[test]
+fn known_dates() {
+    use crate::Month;
+    let check = |year, month, day, gregorian_year, gregorian_month, gregorian_day| {
+        let date =
+            Date::from_gregorian_date(gregorian_year, gregorian_month, gregorian_day).unwrap();
+        let hebrew_date = HebrewDate::new(year, month, day).unwrap();
+        assert_eq!(HebrewDate::from_date(date), hebrew_date);
+        assert_eq!(hebrew_date.into_date(), date);
+    };
+
+    // Rosh Hashanah 5784, 5785, and 5786.
+    check(5784, HebrewMonth::Tishrei, 1, 2023, Month::September, 16);
+    check(5785, HebrewMonth::Tishrei, 1, 2024, Month::October, 3);
+    check(5786, HebrewMonth::Tishrei, 1, 2025, Month::September, 23);
+
+    // 5784 is a leap year: Purim Katan falls in Adar I, Purim in Adar II.
+    assert!(HebrewDate::is_leap_year(5784));
+    assert_eq!(HebrewDate::days_in_year(5784), 383);
+    check(5784, HebrewMonth::AdarI, 14, 2024, Month::February, 23);
+    check(5784, HebrewMonth::AdarII, 14, 2024, Month::March, 24);
+    check(5784, HebrewMonth::Nisan, 15, 2024, Month::April, 23);
+    assert!(HebrewDate::new(5784, HebrewMonth::Adar, 14).is_err());
+
+    // 5785 is a common year with a single Adar.
+    assert!(!HebrewDate::is_leap_year(5785));
+    assert_eq!(HebrewDate::days_in_year(5785), 355);
+    check(5785, HebrewMonth::Adar, 14, 2025, Month::March, 14);
+    assert!(HebrewDate::new(5785, HebrewMonth::AdarII, 14).is_err());
+
+    // The epoch itself, 7 October 3761 BCE in the proleptic Julian calendar.
+    let date = Date::from_julian_date(-3760, Month::October, 7).unwrap();
+    assert_eq!(
+        HebrewDate::from_date(date),
+        HebrewDate::new(1, HebrewMonth::Tishrei, 1).unwrap()
+    );
+}
+
+/// Verifies that year lengths always fall within the permitted ranges.
+#[test]
+fn year_lengths() {
+    for year in 1..10_000 {
+        let days_in_year = HebrewDate::days_in_year(year);
+        if HebrewDate::is_leap_year(year) {
+            assert!((383..=385).contains(&days_in_year));
+        } else {
+            assert!((353..=355).contains(&days_in_year));
+        }
+    }
+}
+
+/// Verifies that conversion into and from dates roundtrips for a range of days.
+#[test]
+fn roundtrip() {
+    for days in (-1_000_000..1_000_000).step_by(7) {
+        let date = Date::from_time_since_epoch(Days::new(days));
+        let hebrew_date = HebrewDate::from_date(date);
+        assert!(HebrewDate::is_valid_date(
+            hebrew_date.year(),
+            hebrew_date.month(),
+            hebrew_date.day()
+        ));
+        assert_eq!(hebrew_date.into_date(), date);
+    }
+}
+
+/// Verifies that Hebrew dates are only accepted for as far as they fit in a `Date<i32>`.
+#[test]
+fn representable_range() {
+    let last = HebrewDate::from_date(Date::from_time_since_epoch(Days::new(i32::MAX)));
+    let last = HebrewDate::new(last.year(), last.month(), last.day()).unwrap();
+    assert_eq!(last.into_date().time_since_epoch().count(), i32::MAX);
+    assert!(HebrewDate::new(last.year() + 1, HebrewMonth::Tishrei, 1).is_err());
+
+    let first = HebrewDate::from_date(Date::from_time_since_epoch(Days::new(i32::MIN)));
+    let first = HebrewDate::new(first.year(), first.month(), first.day()).unwrap();
+    assert_eq!(first.into_date().time_since_epoch().count(), i32::MIN);
+    assert!(HebrewDate::new(first.year() - 1, HebrewMonth::Elul, 29).is_err());
+
+    assert!(HebrewDate::new(i32::MAX, HebrewMonth::Nisan, 1).is_err());
+    assert!(HebrewDate::new(i32::MIN, HebrewMonth::Nisan, 1).is_err());
+}
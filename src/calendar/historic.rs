@@ -4,7 +4,9 @@
 //! Gregoric calendar reform of 1582. When in doubt, use this calendar.
 
 use crate::{
-    Date, GregorianDate, JulianDate, Month,
+    Calendar, Date, GregorianDate, JulianDate, Month, WeekDay,
+    calendar::PaddedYear,
+    duration::Days,
     errors::{InvalidDayOfYear, InvalidDayOfYearCount, InvalidHistoricDate},
 };
 
@@ -119,6 +121,69 @@ impl HistoricDate {
         self.day
     }
 
+    /// Returns the day directly following this date. Across the Gregorian reform, this skips the
+    /// ten days that do not exist: 4 October 1582 is followed by 15 October 1582.
+    ///
+    /// Panics if the resulting date is not representable.
+    pub const fn succ(self) -> Self {
+        Self::from_date(self.into_date().succ())
+    }
+
+    /// Returns the day directly preceding this date.
+    ///
+    /// Panics if the resulting date is not representable.
+    pub const fn pred(self) -> Self {
+        Self::from_date(self.into_date().pred())
+    }
+
+    /// Returns the first day of the month in which this date falls.
+    pub const fn first_of_month(&self) -> Self {
+        Self {
+            year: self.year,
+            month: self.month,
+            day: 1,
+        }
+    }
+
+    /// Returns the last day of the month in which this date falls.
+    pub const fn last_of_month(&self) -> Self {
+        Self {
+            year: self.year,
+            month: self.month,
+            day: Self::days_in_month(self.year, self.month),
+        }
+    }
+
+    /// Returns the `n`th occurrence of `week_day` within the given month, counting from 1: for
+    /// example, the third Monday of January 2025 is obtained with `n = 3`. Returns `None` if that
+    /// occurrence does not exist, which is always the case for `n = 0` or `n > 5`.
+    pub const fn nth_weekday_of_month(
+        year: i32,
+        month: Month,
+        week_day: WeekDay,
+        n: u8,
+    ) -> Option<Self> {
+        if n == 0 || n > 5 {
+            return None;
+        }
+        let first = Self {
+            year,
+            month,
+            day: 1,
+        }
+        .into_date();
+        let offset = (week_day as i32 - first.week_day() as i32).rem_euclid(7) + 7 * (n as i32 - 1);
+        let date = match first.checked_add_days(Days::new(offset)) {
+            Some(date) => Self::from_date(date),
+            None => return None,
+        };
+        if date.year == year && date.month as u8 == month as u8 {
+            Some(date)
+        } else {
+            None
+        }
+    }
+
     /// Returns the day-of-year of this specific date, within its calendar year. The day-of-year is
     /// an integer value ranging from 1 on January 1 to 365 (or 365, in leap years) on December 31.
     /// Uses the algorithm given by Meeus in Astronomical Algorithms.
@@ -228,12 +293,86 @@ impl From<Date<i32>> for HistoricDate {
     }
 }
 
+impl Calendar for HistoricDate {
+    type Month = Month;
+
+    const NAME: &'static str = "Historic";
+
+    fn from_date(date: Date<i32>) -> Self {
+        Self::from_date(date)
+    }
+
+    fn to_date(&self) -> Date<i32> {
+        self.into_date()
+    }
+
+    fn year(&self) -> i32 {
+        self.year()
+    }
+
+    fn month(&self) -> Month {
+        self.month()
+    }
+
+    fn day(&self) -> u8 {
+        self.day()
+    }
+}
+
+/// Formats this date as ISO 8601 calendar date, like "2024-03-15". Years are padded to at least
+/// four digits, and years before 1 CE are expressed in astronomical year numbering, with a
+/// leading minus sign: for example, "-0044-03-15".
 impl core::fmt::Display for HistoricDate {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{}-{:02}-{:02}", self.year, self.month as u8, self.day)
+        write!(
+            f,
+            "{}-{:02}-{:02}",
+            PaddedYear(self.year),
+            self.month as u8,
+            self.day
+        )
     }
 }
 
+/// Verifies the month navigation helpers, including across the Gregorian calendar reform.
+#[test]
+fn month_navigation() {
+    let date = HistoricDate::new(2024, Month::February, 13).unwrap();
+    assert_eq!(date.last_of_month().day(), 29);
+    assert_eq!(date.first_of_month().day(), 1);
+    assert_eq!(
+        HistoricDate::nth_weekday_of_month(2025, Month::January, WeekDay::Monday, 3),
+        Some(HistoricDate::new(2025, Month::January, 20).unwrap())
+    );
+
+    // 1 October 1582 was a Monday; because of the reform, the next Monday was 18 October 1582.
+    assert_eq!(
+        HistoricDate::nth_weekday_of_month(1582, Month::October, WeekDay::Monday, 2),
+        Some(HistoricDate::new(1582, Month::October, 18).unwrap())
+    );
+    assert_eq!(
+        HistoricDate::nth_weekday_of_month(1582, Month::October, WeekDay::Monday, 3),
+        Some(HistoricDate::new(1582, Month::October, 25).unwrap())
+    );
+    assert_eq!(
+        HistoricDate::nth_weekday_of_month(1582, Month::October, WeekDay::Monday, 4),
+        None
+    );
+}
+
+/// Verifies that single-day navigation skips the days removed by the Gregorian reform.
+#[test]
+fn single_day_navigation() {
+    let before_reform = HistoricDate::new(1582, Month::October, 4).unwrap();
+    let after_reform = HistoricDate::new(1582, Month::October, 15).unwrap();
+    assert_eq!(before_reform.succ(), after_reform);
+    assert_eq!(after_reform.pred(), before_reform);
+    assert_eq!(
+        HistoricDate::new(1600, Month::February, 29).unwrap().succ(),
+        HistoricDate::new(1600, Month::March, 1).unwrap()
+    );
+}
+
 /// Tests the day-of-year function using some examples from Meeus.
 #[test]
 fn day_of_year() {
@@ -2,7 +2,7 @@
 //! calendar.
 
 use crate::{
-    Date, Month,
+    Calendar, Date, Month,
     calendar::historic::month_day_from_ordinal_date,
     duration::Days,
     errors::{InvalidDayOfYear, InvalidJulianDate},
@@ -81,6 +81,9 @@ impl JulianDate {
     /// Creates a new date given only the year and the day-of-year. Implementation is based on an
     /// algorithm found by A. Pouplier and reported by Jean Meeus in Astronomical Algorithms.
     ///
+    /// Uses the proleptic Julian leap year rule for all years: in contrast with the historic
+    /// calendar, no days are skipped around the 1582 calendar reform.
+    ///
     /// This function will never panic.
     pub const fn from_ordinal_date(year: i32, day_of_year: u16) -> Result<Self, InvalidDayOfYear> {
         let is_leap_year = Self::is_leap_year(year);
@@ -152,6 +155,32 @@ impl From<Date<i32>> for JulianDate {
     }
 }
 
+impl Calendar for JulianDate {
+    type Month = Month;
+
+    const NAME: &'static str = "Proleptic Julian";
+
+    fn from_date(date: Date<i32>) -> Self {
+        Self::from_date(date)
+    }
+
+    fn to_date(&self) -> Date<i32> {
+        self.into_date()
+    }
+
+    fn year(&self) -> i32 {
+        self.year()
+    }
+
+    fn month(&self) -> Month {
+        self.month()
+    }
+
+    fn day(&self) -> u8 {
+        self.day()
+    }
+}
+
 /// Verifies that roundtrip conversion for some random dates conserves the date.
 #[test]
 fn roundtrip() {
@@ -232,6 +261,25 @@ impl kani::Arbitrary for JulianDate {
     }
 }
 
+/// Verifies that calendar dates may be converted directly into one another, without routing
+/// through `Date` manually.
+#[test]
+fn cross_calendar_conversion() {
+    use crate::{GregorianDate, HistoricDate};
+    let gregorian = GregorianDate::new(1582, Month::October, 15).unwrap();
+    let julian = JulianDate::from(gregorian);
+    assert_eq!(julian, JulianDate::new(1582, Month::October, 5).unwrap());
+    assert_eq!(GregorianDate::from(julian), gregorian);
+    assert_eq!(
+        HistoricDate::from(julian),
+        HistoricDate::new(1582, Month::October, 15).unwrap()
+    );
+    assert_eq!(
+        JulianDate::from(HistoricDate::new(1582, Month::October, 4).unwrap()),
+        JulianDate::new(1582, Month::October, 4).unwrap()
+    );
+}
+
 #[cfg(kani)]
 mod proof_harness {
     use super::*;
@@ -1,10 +1,16 @@
 //! Representation of some calendrical time point as the elapsed number of (potentially fractional)
 //! days since the start of the Julian period.
 
-use core::ops::{Add, Sub};
+use core::{
+    fmt::Display,
+    ops::{Add, Sub},
+};
+
+use num_traits::Zero;
 
 use crate::{
-    ConvertUnit, Date, Days, Duration, HalfDays, Month, TryIntoExact,
+    ConvertUnit, Date, Days, Duration, Fraction, FractionalDigits, HalfDays, Month, MulCeil,
+    MulFloor, TryIntoExact, UnitRatio,
     errors::{InvalidGregorianDate, InvalidHistoricDate, InvalidJulianDate},
     units::{SecondsPerDay, SecondsPerHalfDay},
 };
@@ -52,6 +58,16 @@ impl<Representation, Period: ?Sized> JulianDay<Representation, Period> {
         self.time_since_epoch
     }
 
+    /// Returns the integer Julian day number: the number of whole days elapsed since the epoch,
+    /// rounded towards negative infinity.
+    pub fn day_number(&self) -> Representation
+    where
+        Representation: Copy + MulFloor<Fraction, Output = Representation>,
+        Period: UnitRatio,
+    {
+        self.time_since_epoch.floor::<SecondsPerDay>().count()
+    }
+
     /// Constructs a Julian day from some given calendar date.
     pub fn from_date(date: Date<Representation>) -> Self
     where
@@ -164,6 +180,112 @@ where
     }
 }
 
+/// A duration may be added to a Julian day, as long as it can be expressed exactly in the unit of
+/// that Julian day. For example, `Days` may be added to a Julian day expressed in `HalfDays`.
+impl<Representation, Period, DurationPeriod> Add<Duration<Representation, DurationPeriod>>
+    for JulianDay<Representation, Period>
+where
+    Representation: Add<Output = Representation> + ConvertUnit<DurationPeriod, Period>,
+    Period: ?Sized,
+    DurationPeriod: ?Sized,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Duration<Representation, DurationPeriod>) -> Self {
+        Self::from_time_since_epoch(self.time_since_epoch + rhs.into_unit())
+    }
+}
+
+impl<Representation, Period, DurationPeriod> Sub<Duration<Representation, DurationPeriod>>
+    for JulianDay<Representation, Period>
+where
+    Representation: Sub<Output = Representation> + ConvertUnit<DurationPeriod, Period>,
+    Period: ?Sized,
+    DurationPeriod: ?Sized,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Duration<Representation, DurationPeriod>) -> Self {
+        Self::from_time_since_epoch(self.time_since_epoch - rhs.into_unit())
+    }
+}
+
+/// The difference between two Julian days is the duration elapsed between them.
+impl<Representation, Period> Sub for JulianDay<Representation, Period>
+where
+    Representation: Sub<Output = Representation>,
+    Period: ?Sized,
+{
+    type Output = Duration<Representation, Period>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.time_since_epoch - rhs.time_since_epoch
+    }
+}
+
+impl<Representation, Period> Display for JulianDay<Representation, Period>
+where
+    Representation: Copy
+        + Display
+        + FractionalDigits
+        + MulFloor<Fraction, Output = Representation>
+        + MulCeil<Fraction, Output = Representation>
+        + PartialOrd
+        + Zero,
+    Period: UnitRatio,
+{
+    /// Prints the Julian day as a decimal number of days. The number of fractional digits printed
+    /// may be controlled using the precision argument of the formatter; by default, all non-zero
+    /// fractional digits are printed.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_decimal_days(f, self.time_since_epoch)
+    }
+}
+
+/// Writes some duration as a decimal number of days. Used to print both Julian days and modified
+/// Julian dates, which only differ in their epoch.
+pub(crate) fn write_decimal_days<Representation, Period>(
+    f: &mut core::fmt::Formatter<'_>,
+    duration: Duration<Representation, Period>,
+) -> core::fmt::Result
+where
+    Representation: Copy
+        + Display
+        + FractionalDigits
+        + MulFloor<Fraction, Output = Representation>
+        + MulCeil<Fraction, Output = Representation>
+        + PartialOrd
+        + Zero,
+    Period: UnitRatio,
+{
+    // The integer part is rounded towards zero, such that the fractional digits (which are always
+    // printed based on the magnitude of the duration) may be appended directly.
+    let is_negative = duration.count() < Representation::zero();
+    let days: Days<Representation> = if is_negative {
+        duration.ceil()
+    } else {
+        duration.floor()
+    };
+    if is_negative && days.count().is_zero() {
+        write!(f, "-0")?;
+    } else {
+        write!(f, "{}", days.count())?;
+    }
+
+    let unit_ratio = Period::FRACTION.divide_by(&SecondsPerDay::FRACTION);
+    let mut digits = duration
+        .count()
+        .fractional_digits(unit_ratio, f.precision(), 10)
+        .peekable();
+    if digits.peek().is_some() {
+        write!(f, ".")?;
+        for digit in digits {
+            write!(f, "{digit}")?;
+        }
+    }
+    Ok(())
+}
+
 /// Verifies this implementation by computing the `JulianDay` for some known (computed manually or
 /// obtained elsewhere) time stamp. If it doesn't match the given `time_since_epoch`, panics.
 #[cfg(test)]
@@ -200,6 +322,30 @@ fn historic_dates_from_meeus() {
     check_historic_julian_day(-4712, January, 1, -HalfDays::new(1));
 }
 
+/// Verifies that days may be added to Julian days, and that Julian days may be compared and
+/// subtracted.
+#[test]
+fn arithmetic_and_comparison() {
+    let jd = JulianDay::from_historic_date(2000, Month::January, 1).unwrap();
+    let next = jd + Days::new(1);
+    assert_eq!(next.time_since_epoch(), HalfDays::new(4903091));
+    assert_eq!(
+        next,
+        JulianDay::from_historic_date(2000, Month::January, 2).unwrap()
+    );
+    assert_eq!(next - Days::new(1), jd);
+    assert_eq!(next - jd, HalfDays::new(2));
+    assert!(jd < next);
+    assert_eq!(jd.max(next), next);
+
+    assert_eq!(jd.day_number(), 2451544);
+    assert_eq!(JulianDay::new(2451545).day_number(), 2451545);
+    assert_eq!(
+        JulianDay::from_time_since_epoch(-HalfDays::new(1)).day_number(),
+        -1
+    );
+}
+
 #[cfg(kani)]
 mod proof_harness {
     use super::*;
@@ -1,10 +1,16 @@
 //! Representation of specific calendrical types, used to represent individual dates according to a
 //! variety of historical calendars.
 
+mod coptic;
+pub use coptic::CopticDate;
 mod date;
 pub use date::Date;
+mod ethiopic;
+pub use ethiopic::EthiopicDate;
 mod gregorian;
 pub use gregorian::GregorianDate;
+mod hebrew;
+pub use hebrew::{HebrewDate, HebrewMonth};
 mod historic;
 pub use historic::HistoricDate;
 mod julian;
@@ -17,3 +23,140 @@ mod month;
 pub use month::Month;
 mod week_day;
 pub use week_day::WeekDay;
+mod year_month;
+pub use year_month::YearMonth;
+
+/// Formats a year in astronomical year numbering as used by ISO 8601: zero-padded to at least four
+/// digits, with negative years preceded by a `-` that does not count towards those four digits.
+/// For example, the year 44 BCE (astronomical year -43) is shown as "-0043".
+pub(crate) struct PaddedYear(pub i32);
+
+impl core::fmt::Display for PaddedYear {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.0 < 0 {
+            write!(f, "-{:04}", self.0.unsigned_abs())
+        } else {
+            write!(f, "{:04}", self.0)
+        }
+    }
+}
+
+/// Runtime selection of the calendar in which dates are rendered. Unlike the `Calendar` trait,
+/// which selects a calendar at compile time, this permits choosing the calendar based on user
+/// input: for example, historians may prefer Julian dates before the Gregorian calendar reform,
+/// while others may want proleptic Gregorian dates throughout.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CalendarKind {
+    /// The historic calendar, which is Julian before and Gregorian after the calendar reform of
+    /// 1582. This is the calendar used by the `Display` implementation of time points.
+    #[default]
+    Historic,
+    /// The proleptic Gregorian calendar.
+    Gregorian,
+    /// The proleptic Julian calendar.
+    Julian,
+    /// The Coptic calendar.
+    Coptic,
+    /// The Ethiopic calendar.
+    Ethiopic,
+    /// The arithmetic Hebrew calendar. Months are numbered from Nisan, as for `HebrewMonth`: Adar
+    /// and Adar I are both month 12, while Adar II is month 13.
+    Hebrew,
+}
+
+impl CalendarKind {
+    /// Expresses the given date in this calendar, as a year, month number, and day-of-month.
+    pub fn year_month_day(self, date: Date<i32>) -> (i32, u8, u8) {
+        match self {
+            Self::Historic => {
+                let date = HistoricDate::from_date(date);
+                (date.year(), date.month() as u8, date.day())
+            }
+            Self::Gregorian => {
+                let date = GregorianDate::from_date(date);
+                (date.year(), date.month() as u8, date.day())
+            }
+            Self::Julian => {
+                let date = JulianDate::from_date(date);
+                (date.year(), date.month() as u8, date.day())
+            }
+            Self::Coptic => {
+                let date = CopticDate::from_date(date);
+                (date.year(), date.month(), date.day())
+            }
+            Self::Ethiopic => {
+                let date = EthiopicDate::from_date(date);
+                (date.year(), date.month(), date.day())
+            }
+            Self::Hebrew => {
+                let date = HebrewDate::from_date(date);
+                (date.year(), date.month().number(), date.day())
+            }
+        }
+    }
+}
+
+/// A `Calendar` describes a scheme by which individual days are labelled with a year, month, and
+/// day. Any calendar that can be converted to and from the universal `Date<i32>` representation
+/// may implement this trait, after which it can be used with the generic calendar functionality
+/// of this library: for example, `TimePoint::into_calendar_datetime`.
+pub trait Calendar: Sized {
+    /// The type used to identify months within a year of this calendar.
+    type Month;
+
+    /// The full (English) name of this calendar.
+    const NAME: &'static str;
+
+    /// Constructs a calendar date from the given universal `Date`.
+    fn from_date(date: Date<i32>) -> Self;
+
+    /// Converts this calendar date into the equivalent universal `Date`.
+    fn to_date(&self) -> Date<i32>;
+
+    /// Returns the year of this calendar date.
+    fn year(&self) -> i32;
+
+    /// Returns the month of this calendar date.
+    fn month(&self) -> Self::Month;
+
+    /// Returns the day-of-the-month of this calendar date.
+    fn day(&self) -> u8;
+
+    /// Returns the full (English) name of the calendar of this date, as given by `NAME`.
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+}
+
+/// Implements `From` conversions from one calendar type into each of the given other calendar
+/// types, by routing through the universal `Date<i32>` representation.
+macro_rules! calendar_conversions {
+    ($from:ty => $($into:ty),+) => {
+        $(
+            impl From<$from> for $into {
+                fn from(date: $from) -> Self {
+                    <$into as Calendar>::from_date(date.to_date())
+                }
+            }
+        )+
+    };
+}
+
+calendar_conversions!(
+    CopticDate => EthiopicDate, GregorianDate, HebrewDate, HistoricDate, JulianDate
+);
+calendar_conversions!(
+    EthiopicDate => CopticDate, GregorianDate, HebrewDate, HistoricDate, JulianDate
+);
+calendar_conversions!(
+    GregorianDate => CopticDate, EthiopicDate, HebrewDate, HistoricDate, JulianDate
+);
+calendar_conversions!(
+    HebrewDate => CopticDate, EthiopicDate, GregorianDate, HistoricDate, JulianDate
+);
+calendar_conversions!(
+    HistoricDate => CopticDate, EthiopicDate, GregorianDate, HebrewDate, JulianDate
+);
+calendar_conversions!(
+    JulianDate => CopticDate, EthiopicDate, GregorianDate, HebrewDate, HistoricDate
+);
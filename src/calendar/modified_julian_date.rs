@@ -2,10 +2,17 @@
 //! at 0h UT. Effectively, this makes it a constant offset from the Julian Day (JD); however, the
 //! MJD is useful because it is not fractional for time points at midnight.
 
-use core::ops::{Add, Sub};
+use core::{
+    fmt::Display,
+    ops::{Add, Sub},
+};
+
+use num_traits::Zero;
 
 use crate::{
-    ConvertUnit, Date, Days, Duration, Month, TryIntoExact,
+    ConvertUnit, Date, Days, Duration, Fraction, FractionalDigits, Month, MulCeil, MulFloor,
+    TryIntoExact, UnitRatio,
+    calendar::julian_day::write_decimal_days,
     errors::{InvalidGregorianDate, InvalidHistoricDate, InvalidJulianDate},
     units::SecondsPerDay,
 };
@@ -40,6 +47,16 @@ impl<Representation, Period: ?Sized> ModifiedJulianDate<Representation, Period>
         self.time_since_epoch
     }
 
+    /// Returns the integer modified Julian day number: the number of whole days elapsed since the
+    /// epoch, rounded towards negative infinity.
+    pub fn day_number(&self) -> Representation
+    where
+        Representation: Copy + MulFloor<Fraction, Output = Representation>,
+        Period: UnitRatio,
+    {
+        self.time_since_epoch.floor::<SecondsPerDay>().count()
+    }
+
     /// Constructs a modified Julian date from some given calendar date.
     pub fn from_date(date: Date<Representation>) -> Self
     where
@@ -149,6 +166,69 @@ where
     }
 }
 
+/// A duration may be added to a modified Julian date, as long as it can be expressed exactly in the
+/// unit of that modified Julian date. For example, `Days` may be added to a modified Julian date
+/// expressed in `HalfDays`.
+impl<Representation, Period, DurationPeriod> Add<Duration<Representation, DurationPeriod>>
+    for ModifiedJulianDate<Representation, Period>
+where
+    Representation: Add<Output = Representation> + ConvertUnit<DurationPeriod, Period>,
+    Period: ?Sized,
+    DurationPeriod: ?Sized,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Duration<Representation, DurationPeriod>) -> Self {
+        Self::from_time_since_epoch(self.time_since_epoch + rhs.into_unit())
+    }
+}
+
+impl<Representation, Period, DurationPeriod> Sub<Duration<Representation, DurationPeriod>>
+    for ModifiedJulianDate<Representation, Period>
+where
+    Representation: Sub<Output = Representation> + ConvertUnit<DurationPeriod, Period>,
+    Period: ?Sized,
+    DurationPeriod: ?Sized,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Duration<Representation, DurationPeriod>) -> Self {
+        Self::from_time_since_epoch(self.time_since_epoch - rhs.into_unit())
+    }
+}
+
+/// The difference between two modified Julian dates is the duration elapsed between them.
+impl<Representation, Period> Sub for ModifiedJulianDate<Representation, Period>
+where
+    Representation: Sub<Output = Representation>,
+    Period: ?Sized,
+{
+    type Output = Duration<Representation, Period>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.time_since_epoch - rhs.time_since_epoch
+    }
+}
+
+impl<Representation, Period> Display for ModifiedJulianDate<Representation, Period>
+where
+    Representation: Copy
+        + Display
+        + FractionalDigits
+        + MulFloor<Fraction, Output = Representation>
+        + MulCeil<Fraction, Output = Representation>
+        + PartialOrd
+        + Zero,
+    Period: UnitRatio,
+{
+    /// Prints the modified Julian date as a decimal number of days. The number of fractional
+    /// digits printed may be controlled using the precision argument of the formatter; by default,
+    /// all non-zero fractional digits are printed.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_decimal_days(f, self.time_since_epoch)
+    }
+}
+
 /// Verifies this implementation by computing the `ModifiedJulianDate` for some known (computed
 /// manually or obtained elsewhere) time stamp. If it doesn't match the given `time_since_epoch`,
 /// panics.
@@ -204,6 +284,24 @@ fn float_mjd_from_date() {
     assert_eq!(time_since_epoch, Days::new(50558.0f64));
 }
 
+/// Verifies that days may be added to modified Julian dates, and that they may be compared and
+/// subtracted.
+#[test]
+fn arithmetic_and_comparison() {
+    let mjd = ModifiedJulianDate::new(51544);
+    let later = mjd + Days::new(31);
+    assert_eq!(
+        later,
+        ModifiedJulianDate::from_historic_date(2000, Month::February, 1).unwrap()
+    );
+    assert_eq!(later - mjd, Days::new(31));
+    assert!(mjd < later);
+
+    let noon = ModifiedJulianDate::from_time_since_epoch(crate::HalfDays::new(-3));
+    assert_eq!(noon.day_number(), -2);
+    assert_eq!(later.day_number(), 51575);
+}
+
 #[cfg(kani)]
 mod proof_harness {
     use super::*;
@@ -0,0 +1,144 @@
+//! Implementation of a year-month combination in the proleptic Gregorian calendar, without a day.
+//! This is useful for monthly aggregation: bucketing dates by month, or iterating over months.
+
+use core::fmt::Display;
+
+use crate::{GregorianDate, Month, calendar::PaddedYear};
+
+/// A specific month of a specific year, in the proleptic Gregorian calendar: for example, February
+/// 2024. Ordered chronologically. As for `GregorianDate`, astronomical year numbering is used.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonth {
+    year: i32,
+    month: Month,
+}
+
+impl YearMonth {
+    /// Creates a new year-month combination. Since every month exists in every year, this cannot
+    /// fail.
+    pub const fn new(year: i32, month: Month) -> Self {
+        Self { year, month }
+    }
+
+    /// Returns the year of this year-month combination.
+    pub const fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of this year-month combination.
+    pub const fn month(&self) -> Month {
+        self.month
+    }
+
+    /// Returns the month directly following this one, rolling over into the next year after
+    /// December.
+    ///
+    /// Panics if the resulting year cannot be represented as `i32`.
+    pub const fn succ(self) -> Self {
+        match self.month {
+            Month::December => Self::new(self.year + 1, Month::January),
+            month => match Month::try_from(month as u8 + 1) {
+                Ok(month) => Self::new(self.year, month),
+                Err(_) => unreachable!(),
+            },
+        }
+    }
+
+    /// Returns the month directly preceding this one, rolling back into the previous year before
+    /// January.
+    ///
+    /// Panics if the resulting year cannot be represented as `i32`.
+    pub const fn pred(self) -> Self {
+        match self.month {
+            Month::January => Self::new(self.year - 1, Month::December),
+            month => match Month::try_from(month as u8 - 1) {
+                Ok(month) => Self::new(self.year, month),
+                Err(_) => unreachable!(),
+            },
+        }
+    }
+
+    /// Returns the number of days in this month, taking leap years into account.
+    pub const fn length_in_days(&self) -> u8 {
+        GregorianDate::days_in_month(self.year, self.month)
+    }
+
+    /// Returns the first day of this month.
+    pub const fn first_day(&self) -> GregorianDate {
+        match GregorianDate::new(self.year, self.month, 1) {
+            Ok(date) => date,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Returns the last day of this month.
+    pub const fn last_day(&self) -> GregorianDate {
+        match GregorianDate::new(self.year, self.month, self.length_in_days()) {
+            Ok(date) => date,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl From<GregorianDate> for YearMonth {
+    fn from(date: GregorianDate) -> Self {
+        date.year_month()
+    }
+}
+
+/// Formats this year-month combination as ISO 8601 calendar month, like "2024-02".
+impl Display for YearMonth {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}-{:02}", PaddedYear(self.year), self.month as u8)
+    }
+}
+
+/// Verifies that months may be iterated across a year boundary, and that their lengths account
+/// for leap years.
+#[test]
+fn month_iteration() {
+    let mut year_month = YearMonth::new(2023, Month::November);
+    let mut months = [YearMonth::new(0, Month::January); 4];
+    for month in months.iter_mut() {
+        *month = year_month;
+        year_month = year_month.succ();
+    }
+    assert_eq!(
+        months,
+        [
+            YearMonth::new(2023, Month::November),
+            YearMonth::new(2023, Month::December),
+            YearMonth::new(2024, Month::January),
+            YearMonth::new(2024, Month::February),
+        ]
+    );
+    assert_eq!(year_month, YearMonth::new(2024, Month::March));
+    assert_eq!(
+        YearMonth::new(2024, Month::January).pred(),
+        YearMonth::new(2023, Month::December)
+    );
+    assert!(months.is_sorted());
+
+    let lengths = months.map(|month| month.length_in_days());
+    assert_eq!(lengths, [30, 31, 31, 29]);
+    assert_eq!(YearMonth::new(2023, Month::February).length_in_days(), 28);
+    assert_eq!(YearMonth::new(1900, Month::February).length_in_days(), 28);
+    assert_eq!(YearMonth::new(2000, Month::February).length_in_days(), 29);
+
+    let date = GregorianDate::new(2024, Month::February, 13).unwrap();
+    assert_eq!(date.year_month_day(), (2024, Month::February, 13));
+    assert_eq!(YearMonth::from(date), YearMonth::new(2024, Month::February));
+    assert_eq!(
+        YearMonth::from(date).last_day(),
+        GregorianDate::new(2024, Month::February, 29).unwrap()
+    );
+    assert_eq!(YearMonth::from(date).first_day(), date.first_of_month());
+}
+
+/// Verifies that year-month combinations are formatted as ISO 8601 calendar months.
+#[cfg(feature = "std")]
+#[test]
+fn formatting() {
+    assert_eq!(YearMonth::new(2024, Month::February).to_string(), "2024-02");
+    assert_eq!(YearMonth::new(-44, Month::March).to_string(), "-0044-03");
+}
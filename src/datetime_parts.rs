@@ -0,0 +1,156 @@
+//! Implementation of a plain structure that holds the individual date-time components of a
+//! `TimePoint`. This is friendlier towards FFI and serialization than the positional tuples
+//! returned by the `into_fine_gregorian_datetime` family of functions.
+
+use core::fmt::{Alignment, Display, Write};
+
+use num_traits::Zero;
+
+use crate::{
+    Duration, FractionalDigits, FromDateTime, FromFineDateTime, IntoFineDateTime, Month, TimePoint,
+    calendar::PaddedYear,
+    errors::InvalidGregorianDateTime,
+    format::{FormatBuffer, pad},
+    units::{Second, UnitRatio},
+};
+
+/// The date-time components of some time point, with the date expressed in the proleptic
+/// Gregorian calendar. Does not identify the time scale in which it is expressed: that is left to
+/// the `TimePoint` from which it was created.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DateTimeParts<Representation = i64, Period = Second> {
+    pub year: i32,
+    pub month: Month,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub subseconds: Duration<Representation, Period>,
+}
+
+impl<Scale: ?Sized, Representation, Period> TimePoint<Scale, Representation, Period>
+where
+    Self: IntoFineDateTime<Representation, Period>,
+{
+    /// Splits this time point into its date-time components, with the date expressed in the
+    /// proleptic Gregorian calendar.
+    pub fn to_parts(self) -> DateTimeParts<Representation, Period> {
+        let (date, hour, minute, second, subseconds) = self.into_fine_gregorian_datetime();
+        DateTimeParts {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+            hour,
+            minute,
+            second,
+            subseconds,
+        }
+    }
+}
+
+impl<Scale: ?Sized, Representation, Period> TimePoint<Scale, Representation, Period>
+where
+    Self: FromFineDateTime<Representation, Period>,
+    TimePoint<Scale, i64, Second>: FromDateTime,
+{
+    /// Constructs a time point from its date-time components, interpreting the date in the
+    /// proleptic Gregorian calendar. Returns an error if the components do not form a valid
+    /// date-time in this time scale.
+    pub fn from_parts(
+        parts: DateTimeParts<Representation, Period>,
+    ) -> Result<
+        Self,
+        InvalidGregorianDateTime<<Self as FromFineDateTime<Representation, Period>>::Error>,
+    > {
+        Self::from_fine_gregorian_datetime(
+            parts.year,
+            parts.month,
+            parts.day,
+            parts.hour,
+            parts.minute,
+            parts.second,
+            parts.subseconds,
+        )
+    }
+}
+
+impl<Representation, Period> Display for DateTimeParts<Representation, Period>
+where
+    Duration<Representation, Period>: Zero,
+    Representation: Copy + FractionalDigits,
+    Period: UnitRatio,
+{
+    /// Formats these date-time components like a `TimePoint`, but without time scale: for example,
+    /// "2024-03-01T12:00:00.25". The precision flag determines the maximum number of subsecond
+    /// digits.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut buffer = FormatBuffer::<128>::new();
+        write!(
+            buffer,
+            "{}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            PaddedYear(self.year),
+            self.month as u8,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+        )?;
+        if !self.subseconds.is_zero() {
+            buffer.write_char('.')?;
+            for digit in self.subseconds.fractional_digits(f.precision(), 10) {
+                write!(buffer, "{digit}")?;
+            }
+        }
+        pad(f, buffer.as_str(), Alignment::Left)
+    }
+}
+
+/// Verifies that a subsecond UTC time point survives a round trip through its date-time parts.
+#[test]
+fn roundtrip() {
+    use crate::{MilliSeconds, UtcTime};
+    let time = UtcTime::from_fine_gregorian_datetime(
+        2016,
+        Month::December,
+        31,
+        23,
+        59,
+        60,
+        MilliSeconds::new(250i64),
+    )
+    .unwrap();
+    let parts = time.to_parts();
+    assert_eq!(
+        parts,
+        DateTimeParts {
+            year: 2016,
+            month: Month::December,
+            day: 31,
+            hour: 23,
+            minute: 59,
+            second: 60,
+            subseconds: MilliSeconds::new(250),
+        }
+    );
+    assert_eq!(UtcTime::from_parts(parts), Ok(time));
+}
+
+/// Verifies that date-time parts are formatted like time points, without time scale.
+#[cfg(feature = "std")]
+#[test]
+fn formatting() {
+    use crate::MilliSeconds;
+    let mut parts = DateTimeParts {
+        year: 2024,
+        month: Month::March,
+        day: 1,
+        hour: 12,
+        minute: 0,
+        second: 0,
+        subseconds: MilliSeconds::new(250i64),
+    };
+    assert_eq!(parts.to_string(), "2024-03-01T12:00:00.25");
+    assert_eq!(format!("{parts:.3}"), "2024-03-01T12:00:00.250");
+    parts.subseconds = MilliSeconds::new(0);
+    assert_eq!(parts.to_string(), "2024-03-01T12:00:00");
+}
@@ -3,17 +3,25 @@
 //! concept is similar to that applied in the C++ `chrono` library.
 
 use core::{
-    fmt::Debug,
+    cmp::Ordering,
+    fmt::{Alignment, Debug, Display},
     hash::Hash,
-    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, Mul, Neg, Rem, Sub, SubAssign},
 };
 
-use num_traits::{Bounded, ConstZero, Signed, Zero};
+use num_traits::{
+    AsPrimitive, Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub, ConstOne,
+    ConstZero, Float, FromBytes, One, SaturatingAdd, SaturatingMul, SaturatingSub, Signed, ToBytes,
+    WrappingAdd, WrappingMul, WrappingSub, Zero,
+};
 
 use crate::{
-    Fraction, FractionalDigits, MulCeil, MulFloor, MulRound, TryFromExact, TryIntoExact, TryMul,
+    AbsDiff, CheckedMulRound, Fraction, FractionalDigits, MulCeil, MulFloor, MulRound,
+    RoundingMode, TryFromExact, TryIntoExact, TryMul,
+    errors::{StdDurationConversionError, UnitConversionOverflow},
+    format::pad_with,
     units::{
-        Atto, ConvertUnit, Femto, Micro, Milli, Nano, Pico, Second, SecondsPerDay,
+        Atto, CommonUnit, ConvertUnit, Femto, Micro, Milli, Nano, Pico, Second, SecondsPerDay,
         SecondsPerHalfDay, SecondsPerHour, SecondsPerMinute, SecondsPerMonth, SecondsPerWeek,
         SecondsPerYear, TryConvertUnit, UnitRatio,
     },
@@ -71,6 +79,16 @@ where
         self.fractional_digits(precision, 10)
     }
 
+    /// Returns an iterator over the fractional (sub-unit) digits of this duration, expressed in
+    /// hexadecimal. Useful for inspecting the raw bits of binary fraction periods.
+    pub fn fractional_hex_digits(&self, precision: Option<usize>) -> impl Iterator<Item = u8>
+    where
+        Representation: Copy + FractionalDigits,
+        Period: UnitRatio,
+    {
+        self.fractional_digits(precision, 16)
+    }
+
     /// Converts a `Duration` towards a different time unit. May only be used if the time unit is
     /// smaller than the current one (e.g., seconds to milliseconds) or if the representation of
     /// this `Duration` is a float.
@@ -92,6 +110,67 @@ where
         Some(Duration::new(self.count.try_convert()?))
     }
 
+    /// Converts a `Duration` towards a smaller time unit, like `into_unit`, but returns `None` if
+    /// the result overflows the underlying integer representation. This is distinct from
+    /// `try_into_unit`, which only checks whether the conversion is lossless.
+    pub fn checked_into_unit<Target>(self) -> Option<Duration<Representation, Target>>
+    where
+        Representation: ConvertUnit<Period, Target> + CheckedMul + TryFromExact<u128> + Zero,
+        Period: UnitRatio,
+        Target: UnitRatio + ?Sized,
+    {
+        if self.count.is_zero() {
+            return Some(Duration::zero());
+        }
+        // `ConvertUnit` is only implemented for integers if this ratio is a whole number.
+        let ratio = Period::FRACTION.divide_by(&Target::FRACTION);
+        let factor =
+            Representation::try_from_exact(ratio.numerator() / ratio.denominator()).ok()?;
+        Some(Duration::new(self.count.checked_mul(&factor)?))
+    }
+
+    /// Converts a `Duration` towards a smaller time unit, like `into_unit`, but clamps the result
+    /// to the bounds of the underlying integer representation instead of overflowing. This gives a
+    /// deterministic, panic-free conversion for real-time code.
+    pub fn saturating_into_unit<Target>(self) -> Duration<Representation, Target>
+    where
+        Representation: ConvertUnit<Period, Target>
+            + Copy
+            + SaturatingMul
+            + Bounded
+            + PartialOrd
+            + TryFromExact<u128>
+            + Zero,
+        Period: UnitRatio,
+        Target: UnitRatio + ?Sized,
+    {
+        // `ConvertUnit` is only implemented for integers if this ratio is a whole number.
+        let ratio = Period::FRACTION.divide_by(&Target::FRACTION);
+        match Representation::try_from_exact(ratio.numerator() / ratio.denominator()) {
+            Ok(factor) => Duration::new(self.count.saturating_mul(&factor)),
+            // If even the conversion factor itself does not fit, any nonzero count overflows.
+            Err(_) if self.count.is_zero() => Duration::zero(),
+            Err(_) if self.count > Representation::zero() => Duration::max_value(),
+            Err(_) => Duration::min_value(),
+        }
+    }
+
+    /// Converts a `Duration` towards a smaller time unit, like `into_unit`, but wraps around at
+    /// the bounds of the underlying integer representation instead of overflowing.
+    pub fn wrapping_into_unit<Target>(self) -> Duration<Representation, Target>
+    where
+        Representation: ConvertUnit<Period, Target> + WrappingMul + Copy + 'static,
+        u128: AsPrimitive<Representation>,
+        Period: UnitRatio,
+        Target: UnitRatio + ?Sized,
+    {
+        // Truncating the factor is consistent with wrapping arithmetic: both work modulo the
+        // range of the representation.
+        let ratio = Period::FRACTION.divide_by(&Target::FRACTION);
+        let factor: Representation = (ratio.numerator() / ratio.denominator()).as_();
+        Duration::new(self.count.wrapping_mul(&factor))
+    }
+
     /// Converts towards a different time unit, rounding towards the nearest whole unit.
     pub fn round<Target>(self) -> Duration<Representation, Target>
     where
@@ -127,6 +206,27 @@ where
         Duration::new(self.count.mul_floor(unit_ratio))
     }
 
+    /// Converts towards a different time unit, rounding in the direction given by `mode` if the
+    /// unit is not entirely commensurate with the present unit.
+    pub fn into_unit_rounded<Target>(self, mode: RoundingMode) -> Duration<Representation, Target>
+    where
+        Representation: MulRound<Fraction, Output = Representation>
+            + MulCeil<Fraction, Output = Representation>
+            + MulFloor<Fraction, Output = Representation>
+            + Zero
+            + PartialOrd,
+        Target: UnitRatio + ?Sized,
+        Period: UnitRatio,
+    {
+        match mode {
+            RoundingMode::Nearest => self.round(),
+            RoundingMode::Ceil => self.ceil(),
+            RoundingMode::Floor => self.floor(),
+            RoundingMode::TowardZero if self.count < Representation::zero() => self.ceil(),
+            RoundingMode::TowardZero => self.floor(),
+        }
+    }
+
     /// Segments this `Duration` by factoring out the largest possible number of whole multiples of
     /// a given unit. Returns this whole number as well as the remainder.
     ///
@@ -152,6 +252,78 @@ where
         (factored, remainder)
     }
 
+    /// Splits this `Duration` into the whole number of `Target` units that it contains and the
+    /// remainder, expressed in the original unit. This is the primary way of decomposing a
+    /// duration into coarser units without silently losing the remainder, as `floor` would.
+    ///
+    /// The whole part is rounded towards negative infinity, such that the remainder is never
+    /// negative. For equal units, the remainder is zero.
+    pub fn split_at<Target>(
+        self,
+    ) -> (
+        Duration<Representation, Target>,
+        Duration<Representation, Period>,
+    )
+    where
+        Representation: Copy
+            + MulFloor<Fraction, Output = Representation>
+            + Sub<Representation, Output = Representation>
+            + ConvertUnit<Target, Period>,
+        Period: UnitRatio,
+        Target: UnitRatio + ?Sized,
+    {
+        self.factor_out::<Target>()
+    }
+
+    /// Returns this duration as a (potentially lossy) floating point number of seconds. Useful
+    /// when interfacing with external tools that exchange time as a scalar.
+    pub(crate) fn as_seconds_f64(&self) -> f64
+    where
+        Representation: Copy + AsPrimitive<f64>,
+        Period: UnitRatio,
+    {
+        let count: f64 = self.count.as_();
+        count * (Period::FRACTION.numerator() as f64) / (Period::FRACTION.denominator() as f64)
+    }
+
+    /// Returns the ratio of this duration to `rhs` as a floating point number. Unlike division of
+    /// the underlying counts, this does not truncate for integer representations, which makes it
+    /// suitable for computing, say, the fraction of some interval that has elapsed. Since both
+    /// durations share the same period, it cancels and only the counts need to be converted.
+    pub fn div_duration_f64(&self, rhs: &Self) -> f64
+    where
+        Representation: Copy + AsPrimitive<f64>,
+    {
+        let numerator: f64 = self.count.as_();
+        let denominator: f64 = rhs.count.as_();
+        numerator / denominator
+    }
+
+    /// Scales this duration by the given (non-negative) fraction. The result is exact where the
+    /// representation permits, which is always the case for floats; for integers, an inexact
+    /// result is rounded to the nearest integer.
+    ///
+    /// # Panics
+    /// Panics if the result overflows the representation. Use `checked_scale` to handle this case
+    /// instead.
+    pub fn scale(self, fraction: Fraction) -> Self
+    where
+        Representation: CheckedMulRound<Fraction, Output = Representation>,
+    {
+        self.checked_scale(fraction)
+            .expect("scaled duration overflows the representation")
+    }
+
+    /// Scales this duration by the given (non-negative) fraction, like `scale`, but returns `None`
+    /// if the result overflows the representation. Inexact integer results are still rounded to
+    /// the nearest integer.
+    pub fn checked_scale(self, fraction: Fraction) -> Option<Self>
+    where
+        Representation: CheckedMulRound<Fraction, Output = Representation>,
+    {
+        self.count.checked_mul_round(fraction).map(Duration::new)
+    }
+
     /// Infallibly converts towards a different representation.
     pub fn cast<Target>(self) -> Duration<Target, Period>
     where
@@ -170,6 +342,25 @@ where
     {
         Ok(Duration::new(self.count.try_into_exact()?))
     }
+
+    /// Converts towards a narrower representation, clamping the count to the range of `Target`
+    /// instead of failing when it does not fit. Useful where a bounded (if lossy) result is
+    /// preferable over an error, as for display or telemetry.
+    pub fn saturating_cast<Target>(self) -> Duration<Target, Period>
+    where
+        Representation: Copy + PartialOrd + AsPrimitive<Target>,
+        Target: Copy + Bounded + Into<Representation> + 'static,
+    {
+        let (min, max) = (Target::min_value().into(), Target::max_value().into());
+        let count = if self.count < min {
+            min
+        } else if self.count > max {
+            max
+        } else {
+            self.count
+        };
+        Duration::new(count.as_())
+    }
 }
 
 #[cfg(kani)]
@@ -195,6 +386,48 @@ where
     }
 }
 
+impl<Representation, Period> Display for Duration<Representation, Period>
+where
+    Representation: Copy + PartialOrd + Zero + AbsDiff,
+    Representation::Magnitude:
+        Copy + Display + MulFloor<Fraction, Output = Representation::Magnitude> + FractionalDigits,
+    Period: UnitRatio + ?Sized,
+{
+    /// Formats this duration as a decimal number of seconds, followed by the unit "s": for
+    /// example, "1.5s". This is the same format accepted by `Duration::parse_with_suffix`. The
+    /// precision flag determines the number of fractional digits; the `+` flag forces a sign to be
+    /// printed for non-negative durations. Width, fill, and alignment are also respected, with
+    /// right alignment by default.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let is_negative = self.count < Representation::zero();
+        let magnitude = self.abs_diff(Self::zero());
+        let seconds = magnitude.floor::<Second>().count();
+        let (sign_plus, precision) = (f.sign_plus(), f.precision());
+
+        // Large float durations may have hundreds of digits, so the output is written directly
+        // instead of through a fixed-size buffer.
+        pad_with(f, Alignment::Right, |f| {
+            if is_negative {
+                f.write_char('-')?;
+            } else if sign_plus {
+                f.write_char('+')?;
+            }
+            write!(f, "{seconds}")?;
+            let mut digits = magnitude
+                .count
+                .fractional_digits(Period::FRACTION, precision, 10)
+                .peekable();
+            if digits.peek().is_some() {
+                f.write_char('.')?;
+                for digit in digits {
+                    write!(f, "{digit}")?;
+                }
+            }
+            f.write_char('s')
+        })
+    }
+}
+
 impl<Representation, Period> Copy for Duration<Representation, Period>
 where
     Representation: Copy,
@@ -288,10 +521,215 @@ pub type Months<T> = Duration<T, SecondsPerMonth>;
 /// The length of an average year in the Gregorian calendar.
 pub type Years<T> = Duration<T, SecondsPerYear>;
 
+/// Implements `TryFrom` conversions from durations in some coarse unit into durations in each of
+/// the given finer units. Only applied to unit pairs of which the ratio is an integer, such that
+/// these conversions never lose precision. They may still overflow the representation, in which
+/// case an error is returned: hence `TryFrom` rather than `From`. Conversions that also widen the
+/// representation far enough to never overflow are implemented as `From` instead, below.
+macro_rules! lossless_unit_conversions {
+    ($coarse:ty => $($fine:ty),+ $(,)?) => {
+        $(
+            impl<Representation> TryFrom<Duration<Representation, $coarse>>
+                for Duration<Representation, $fine>
+            where
+                Representation: ConvertUnit<$coarse, $fine>
+                    + CheckedMul
+                    + TryFromExact<u128>
+                    + Zero,
+            {
+                type Error = UnitConversionOverflow;
+
+                fn try_from(
+                    duration: Duration<Representation, $coarse>,
+                ) -> Result<Self, Self::Error> {
+                    duration.checked_into_unit().ok_or(UnitConversionOverflow)
+                }
+            }
+        )+
+    };
+}
+
+lossless_unit_conversions!(
+    SecondsPerYear => SecondsPerMonth, Second, Milli, Micro, Nano, Pico, Femto, Atto
+);
+lossless_unit_conversions!(SecondsPerMonth => Second, Milli, Micro, Nano, Pico, Femto, Atto);
+lossless_unit_conversions!(
+    SecondsPerWeek => SecondsPerDay, SecondsPerHalfDay, SecondsPerHour, SecondsPerMinute, Second,
+    Milli, Micro, Nano, Pico, Femto, Atto
+);
+lossless_unit_conversions!(
+    SecondsPerDay => SecondsPerHalfDay, SecondsPerHour, SecondsPerMinute, Second, Milli, Micro,
+    Nano, Pico, Femto, Atto
+);
+lossless_unit_conversions!(
+    SecondsPerHalfDay => SecondsPerHour, SecondsPerMinute, Second, Milli, Micro, Nano, Pico,
+    Femto, Atto
+);
+lossless_unit_conversions!(
+    SecondsPerHour => SecondsPerMinute, Second, Milli, Micro, Nano, Pico, Femto, Atto
+);
+lossless_unit_conversions!(SecondsPerMinute => Second, Milli, Micro, Nano, Pico, Femto, Atto);
+lossless_unit_conversions!(Second => Milli, Micro, Nano, Pico, Femto, Atto);
+lossless_unit_conversions!(Milli => Micro, Nano, Pico, Femto, Atto);
+lossless_unit_conversions!(Micro => Nano, Pico, Femto, Atto);
+lossless_unit_conversions!(Nano => Pico, Femto, Atto);
+lossless_unit_conversions!(Pico => Femto, Atto);
+lossless_unit_conversions!(Femto => Atto);
+
+/// Implements `From` conversions from durations in some coarse unit and narrow integer
+/// representation into durations in finer units and a wider representation. Only applied to unit
+/// pairs of which the ratio is an integer small enough that any value of the narrow representation
+/// still fits in the wide representation after scaling, such that these conversions never fail.
+macro_rules! widening_unit_conversions {
+    ($($narrow:ty => $wide:ty),+; $conversions:tt) => {
+        $(widening_unit_conversions!(@impl $narrow => $wide; $conversions);)+
+    };
+    (@impl $narrow:ty => $wide:ty; { $($coarse:ty => [$($fine:ty),+]),+ $(,)? }) => {
+        $($(
+            impl From<Duration<$narrow, $coarse>> for Duration<$wide, $fine> {
+                fn from(duration: Duration<$narrow, $coarse>) -> Self {
+                    Duration::<$wide, $coarse>::new(duration.count.into()).into_unit()
+                }
+            }
+        )+)+
+    };
+}
+
+// Scaling a 32-bit integer into 64 bits leaves room for ratios up to 2^32.
+widening_unit_conversions!(i32 => i64, u32 => u64; {
+    SecondsPerYear => [SecondsPerMonth, Second],
+    SecondsPerMonth => [Second, Milli],
+    SecondsPerWeek => [
+        SecondsPerDay, SecondsPerHalfDay, SecondsPerHour, SecondsPerMinute, Second, Milli
+    ],
+    SecondsPerDay => [SecondsPerHalfDay, SecondsPerHour, SecondsPerMinute, Second, Milli],
+    SecondsPerHalfDay => [SecondsPerHour, SecondsPerMinute, Second, Milli],
+    SecondsPerHour => [SecondsPerMinute, Second, Milli, Micro],
+    SecondsPerMinute => [Second, Milli, Micro],
+    Second => [Milli, Micro, Nano],
+    Milli => [Micro, Nano, Pico],
+    Micro => [Nano, Pico, Femto],
+    Nano => [Pico, Femto, Atto],
+    Pico => [Femto, Atto],
+    Femto => [Atto],
+});
+
+// Scaling a 64-bit integer into 128 bits leaves room for ratios up to 2^64.
+widening_unit_conversions!(i64 => i128, u64 => u128; {
+    SecondsPerYear => [SecondsPerMonth, Second, Milli, Micro, Nano],
+    SecondsPerMonth => [Second, Milli, Micro, Nano, Pico],
+    SecondsPerWeek => [
+        SecondsPerDay, SecondsPerHalfDay, SecondsPerHour, SecondsPerMinute, Second, Milli, Micro,
+        Nano, Pico
+    ],
+    SecondsPerDay => [
+        SecondsPerHalfDay, SecondsPerHour, SecondsPerMinute, Second, Milli, Micro, Nano, Pico
+    ],
+    SecondsPerHalfDay => [SecondsPerHour, SecondsPerMinute, Second, Milli, Micro, Nano, Pico],
+    SecondsPerHour => [SecondsPerMinute, Second, Milli, Micro, Nano, Pico, Femto],
+    SecondsPerMinute => [Second, Milli, Micro, Nano, Pico, Femto],
+    Second => [Milli, Micro, Nano, Pico, Femto, Atto],
+    Milli => [Micro, Nano, Pico, Femto, Atto],
+    Micro => [Nano, Pico, Femto, Atto],
+    Nano => [Pico, Femto, Atto],
+    Pico => [Femto, Atto],
+    Femto => [Atto],
+});
+
+/// Implements `const_into_unit` for durations with primitive integer representations. Trait
+/// methods cannot be called in `const` contexts, so the conversion factor is computed directly from
+/// the unit ratios instead of going through `ConvertUnit`.
+macro_rules! const_unit_conversion {
+    ($($repr:ty),+ $(,)?) => {
+        $(
+            impl<Period> Duration<$repr, Period>
+            where
+                Period: UnitRatio + ?Sized,
+            {
+                /// Converts a `Duration` towards a smaller time unit, like `into_unit`, but may be
+                /// evaluated in `const` contexts, for example to build lookup tables.
+                ///
+                /// # Panics
+                /// Panics if the `Target` unit does not evenly divide the current unit, or if the
+                /// result overflows. In `const` contexts, this results in a compilation error.
+                pub const fn const_into_unit<Target>(self) -> Duration<$repr, Target>
+                where
+                    Target: UnitRatio + ?Sized,
+                {
+                    let ratio = Period::FRACTION.divide_by(&Target::FRACTION);
+                    assert!(
+                        ratio.denominator() == 1,
+                        "target unit must evenly divide the current unit"
+                    );
+                    assert!(
+                        ratio.numerator() <= <$repr>::MAX as u128,
+                        "conversion factor exceeds the range of the representation"
+                    );
+                    match self.count.checked_mul(ratio.numerator() as $repr) {
+                        Some(count) => Duration::new(count),
+                        None => panic!("unit conversion overflows the representation"),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+const_unit_conversion!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+/// Discoverable constructors for durations in the most common time units. Each returns a duration
+/// expressed in its natural period, such that no conversion (and hence no rounding) takes place:
+/// use `into_unit()` to convert afterwards if some other unit is desired.
+impl<Representation> Duration<Representation> {
+    /// Constructs a duration of the given number of weeks.
+    pub const fn from_weeks(count: Representation) -> Weeks<Representation> {
+        Weeks::new(count)
+    }
+
+    /// Constructs a duration of the given number of days.
+    pub const fn from_days(count: Representation) -> Days<Representation> {
+        Days::new(count)
+    }
+
+    /// Constructs a duration of the given number of hours.
+    pub const fn from_hours(count: Representation) -> Hours<Representation> {
+        Hours::new(count)
+    }
+
+    /// Constructs a duration of the given number of minutes.
+    pub const fn from_minutes(count: Representation) -> Minutes<Representation> {
+        Minutes::new(count)
+    }
+
+    /// Constructs a duration of the given number of seconds.
+    pub const fn from_secs(count: Representation) -> Seconds<Representation> {
+        Seconds::new(count)
+    }
+
+    /// Constructs a duration of the given number of milliseconds.
+    pub const fn from_millis(count: Representation) -> MilliSeconds<Representation> {
+        MilliSeconds::new(count)
+    }
+
+    /// Constructs a duration of the given number of microseconds.
+    pub const fn from_micros(count: Representation) -> MicroSeconds<Representation> {
+        MicroSeconds::new(count)
+    }
+
+    /// Constructs a duration of the given number of nanoseconds.
+    pub const fn from_nanos(count: Representation) -> NanoSeconds<Representation> {
+        NanoSeconds::new(count)
+    }
+}
+
 /// Two `Duration`s may only be added if they are of the same `Period`. We also (relatively
 /// arbitrarily) restrict addition to `Duration`s with the same underlying representation. This
 /// turns out to be very useful in improving type inference, with the reduced flexibility being of
 /// little consequence for any "regular" representation.
+///
+/// Overflow is handled as by the underlying representation: for primitive integers, this means a
+/// panic in debug builds and silent wrapping in release builds. Where overflow may occur, use
+/// `checked_add`, `saturating_add`, or `wrapping_add` to choose the behaviour explicitly.
 impl<Representation, Period> Add for Duration<Representation, Period>
 where
     Representation: Add<Output = Representation>,
@@ -322,6 +760,9 @@ where
 /// arbitrarily) restrict subtraction to `Duration`s with the same underlying representation. This
 /// turns out to be very useful in improving type inference, with the reduced flexibility being of
 /// little consequence for any "regular" representation.
+///
+/// As for addition, overflow is handled as by the underlying representation. Use `checked_sub`,
+/// `saturating_sub`, or `wrapping_sub` to choose the behaviour explicitly.
 impl<Representation, Period> Sub for Duration<Representation, Period>
 where
     Representation: Sub<Output = Representation>,
@@ -349,6 +790,10 @@ where
 
 /// A `Duration` may be negated if its `Representation` is `Signed`. This means nothing more than
 /// reversing its direction in time.
+///
+/// Note that two's complement integers cannot represent the negation of their minimum value: for
+/// those, negation of `Representation::MIN` panics in debug builds and wraps around in release
+/// builds. Use `Duration::checked_neg` where such inputs may occur.
 impl<Representation, Period> Neg for Duration<Representation, Period>
 where
     Representation: Neg<Output = Representation>,
@@ -364,6 +809,42 @@ where
     }
 }
 
+/// Because `Add` and `Sub` require both operands to share the same `Period`, durations of
+/// different periods must be combined explicitly. These functions promote both operands to their
+/// common unit (the finer of both) before combining them, such that no precision is lost.
+impl<Representation, Period> Duration<Representation, Period> {
+    /// Adds a duration expressed in a different unit, returning the sum in the finer of both
+    /// units: for example, adding `Seconds` and `MilliSeconds` results in `MilliSeconds`. Only
+    /// available for units between which an exact integer conversion exists.
+    pub fn add_mixed<Other>(
+        self,
+        rhs: Duration<Representation, Other>,
+    ) -> Duration<Representation, <Period as CommonUnit<Other>>::Output>
+    where
+        Period: CommonUnit<Other>,
+        Representation: ConvertUnit<Period, <Period as CommonUnit<Other>>::Output>
+            + ConvertUnit<Other, <Period as CommonUnit<Other>>::Output>
+            + Add<Output = Representation>,
+    {
+        Duration::new(self.into_unit().count + rhs.into_unit().count)
+    }
+
+    /// Subtracts a duration expressed in a different unit, returning the difference in the finer
+    /// of both units. Only available for units between which an exact integer conversion exists.
+    pub fn sub_mixed<Other>(
+        self,
+        rhs: Duration<Representation, Other>,
+    ) -> Duration<Representation, <Period as CommonUnit<Other>>::Output>
+    where
+        Period: CommonUnit<Other>,
+        Representation: ConvertUnit<Period, <Period as CommonUnit<Other>>::Output>
+            + ConvertUnit<Other, <Period as CommonUnit<Other>>::Output>
+            + Sub<Output = Representation>,
+    {
+        Duration::new(self.into_unit().count - rhs.into_unit().count)
+    }
+}
+
 impl<R1, R2, Period> Mul<R2> for Duration<R1, Period>
 where
     R1: Mul<R2>,
@@ -397,6 +878,306 @@ where
     }
 }
 
+/// The remainder of dividing one `Duration` by another of the same unit is the time left over
+/// after subtracting the largest whole number of the latter: for example, the time past the last
+/// whole hour. This uses the remainder operation of the underlying representation, which for
+/// primitive integers and floats truncates towards zero: the result takes the sign of the
+/// dividend, so negative durations result in negative remainders. Where a non-negative remainder
+/// is needed, use `Duration::split_at` instead.
+impl<R1, R2, Period> Rem<Duration<R2, Period>> for Duration<R1, Period>
+where
+    R1: Rem<R2>,
+    Period: ?Sized,
+{
+    type Output = Duration<<R1 as Rem<R2>>::Output, Period>;
+
+    fn rem(self, rhs: Duration<R2, Period>) -> Self::Output {
+        Self::Output {
+            count: self.count % rhs.count,
+            period: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<Representation, Period> Duration<Representation, Period>
+where
+    Period: ?Sized,
+{
+    /// Adds two `Duration`s. Returns `None` if the result overflows the underlying
+    /// representation.
+    pub fn checked_add(self, rhs: Self) -> Option<Self>
+    where
+        Representation: CheckedAdd,
+    {
+        Some(Self::new(self.count.checked_add(&rhs.count)?))
+    }
+
+    /// Subtracts two `Duration`s. Returns `None` if the result overflows the underlying
+    /// representation.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self>
+    where
+        Representation: CheckedSub,
+    {
+        Some(Self::new(self.count.checked_sub(&rhs.count)?))
+    }
+
+    /// Adds two `Duration`s, clamping the result to the bounds of the underlying representation
+    /// instead of overflowing.
+    pub fn saturating_add(self, rhs: Self) -> Self
+    where
+        Representation: SaturatingAdd,
+    {
+        Self::new(self.count.saturating_add(&rhs.count))
+    }
+
+    /// Subtracts two `Duration`s, clamping the result to the bounds of the underlying
+    /// representation instead of overflowing.
+    pub fn saturating_sub(self, rhs: Self) -> Self
+    where
+        Representation: SaturatingSub,
+    {
+        Self::new(self.count.saturating_sub(&rhs.count))
+    }
+
+    /// Adds two `Duration`s, wrapping around at the bounds of the underlying representation. This
+    /// makes the release-mode behaviour of the `+` operator explicit, and consistent across build
+    /// profiles.
+    pub fn wrapping_add(self, rhs: Self) -> Self
+    where
+        Representation: WrappingAdd,
+    {
+        Self::new(self.count.wrapping_add(&rhs.count))
+    }
+
+    /// Subtracts two `Duration`s, wrapping around at the bounds of the underlying representation.
+    pub fn wrapping_sub(self, rhs: Self) -> Self
+    where
+        Representation: WrappingSub,
+    {
+        Self::new(self.count.wrapping_sub(&rhs.count))
+    }
+
+    /// Multiplies this `Duration` by a unitless integer. Returns `None` if the result overflows
+    /// the underlying representation.
+    pub fn checked_mul(self, rhs: Representation) -> Option<Self>
+    where
+        Representation: CheckedMul,
+    {
+        Some(Self::new(self.count.checked_mul(&rhs)?))
+    }
+
+    /// Negates this `Duration`. Returns `None` if the result cannot be represented, which is the
+    /// case for the minimum value of signed integers and for any non-zero unsigned value.
+    pub fn checked_neg(self) -> Option<Self>
+    where
+        Representation: CheckedNeg,
+    {
+        Some(Self::new(self.count.checked_neg()?))
+    }
+
+    /// Divides this `Duration` by a unitless integer. Returns `None` if `rhs` is zero or if the
+    /// division overflows (e.g., `i64::MIN / -1`).
+    pub fn checked_div(self, rhs: Representation) -> Option<Self>
+    where
+        Representation: CheckedDiv,
+    {
+        Some(Self::new(self.count.checked_div(&rhs)?))
+    }
+
+    /// Returns the magnitude of the difference between two durations. Unlike `abs_sub` or
+    /// `(a - b).abs()`, this does not require negation, so it may also be used with unsigned
+    /// representations. Like `i64::abs_diff`, the result is expressed in the unsigned counterpart
+    /// of signed integer representations, such that it never overflows: the magnitude of
+    /// `i64::MIN` seconds is representable as `u64`, but not as `i64`.
+    pub fn abs_diff(self, other: Self) -> Duration<Representation::Magnitude, Period>
+    where
+        Representation: AbsDiff,
+    {
+        Duration::new(self.count.abs_diff(other.count))
+    }
+
+    /// Clamps this duration into the range `[-max, max]`, preserving its sign: the primitive
+    /// needed to rate-limit clock corrections, for example. For unsigned representations, this
+    /// reduces to clamping into `[0, max]`. The magnitude limit `max` must be non-negative.
+    pub fn clamp_magnitude(self, max: Self) -> Self
+    where
+        Representation: Copy + PartialOrd + Zero + Sub<Representation, Output = Representation>,
+    {
+        if self.count > max.count {
+            max
+        } else if self.count < Representation::zero() {
+            // Only reachable for signed representations, so negating `max` cannot underflow.
+            let min = Representation::zero() - max.count;
+            if self.count < min {
+                Self::new(min)
+            } else {
+                self
+            }
+        } else {
+            self
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Representation, Period> Duration<Representation, Period>
+where
+    Representation: Copy + PartialOrd + Zero + AbsDiff,
+    Representation::Magnitude: Copy
+        + MulFloor<Fraction, Output = Representation::Magnitude>
+        + TryIntoExact<u128>
+        + FractionalDigits,
+    Period: UnitRatio,
+{
+    /// Formats this duration as an ISO 8601 duration in weeks, like "P3W". This is only possible
+    /// if the duration is an exact multiple of weeks: otherwise, the duration is expressed in
+    /// days, hours, minutes, and seconds instead, like "P1DT2H3M4.5S", since ISO 8601 does not
+    /// permit combining weeks with other components.
+    ///
+    /// Returns `None` if the whole seconds of this duration cannot be expressed as `u128`, as is
+    /// the case for infinite, NaN, or overly large floating point durations.
+    pub fn to_iso8601_weeks(&self) -> Option<String> {
+        const SECONDS_PER_WEEK: u128 = 604_800;
+        let (is_negative, seconds, subseconds) = self.iso8601_magnitude()?;
+        if subseconds.is_empty() && seconds != 0 && seconds % SECONDS_PER_WEEK == 0 {
+            let sign = if is_negative { "-" } else { "" };
+            Some(format!("{sign}P{}W", seconds / SECONDS_PER_WEEK))
+        } else {
+            Some(format_iso8601(
+                is_negative,
+                &[(86_400, 'D')],
+                seconds,
+                &subseconds,
+            ))
+        }
+    }
+
+    /// Formats this duration as an ISO 8601 duration in terms of calendar components, like
+    /// "P1Y2M3DT4H5M6S". Years and months are interpreted as their average length in the
+    /// Gregorian calendar (see `SecondsPerYear` and `SecondsPerMonth`), which are only an
+    /// approximation of the length of any actual calendar year or month.
+    ///
+    /// Each component is obtained by factoring out the largest possible number of whole units,
+    /// starting with years. Hence, no rounding occurs: the remainder is always carried over into
+    /// the next smaller component, and the seconds component is printed with all its significant
+    /// fractional digits.
+    ///
+    /// Returns `None` under the same conditions as `to_iso8601_weeks`.
+    pub fn to_iso8601_calendar(&self) -> Option<String> {
+        let (is_negative, seconds, subseconds) = self.iso8601_magnitude()?;
+        Some(format_iso8601(
+            is_negative,
+            &[(31_556_952, 'Y'), (2_629_746, 'M'), (86_400, 'D')],
+            seconds,
+            &subseconds,
+        ))
+    }
+
+    /// Formats this duration as a human-friendly string, like "2h 3m 4s" or "500ms", as commonly
+    /// shown by command-line tools and dashboards. The duration is split into days, hours,
+    /// minutes, seconds, milliseconds, microseconds, and nanoseconds, of which at most
+    /// `max_components` consecutive ones are shown, starting at the largest non-zero component.
+    /// Components that are zero are omitted, and anything smaller than the last shown component
+    /// is truncated. An empty duration is printed as "0s".
+    ///
+    /// Returns `None` under the same conditions as `to_iso8601_weeks`: NaN and infinite durations
+    /// have no meaningful components.
+    pub fn to_human_string(&self, max_components: usize) -> Option<String> {
+        const UNITS: [&str; 7] = ["d", "h", "m", "s", "ms", "us", "ns"];
+        let (is_negative, seconds, subseconds) = self.iso8601_magnitude()?;
+        let nanoseconds = subseconds
+            .bytes()
+            .chain(core::iter::repeat(b'0'))
+            .take(9)
+            .fold(0u128, |nanoseconds, digit| {
+                nanoseconds * 10 + u128::from(digit - b'0')
+            });
+        let components = [
+            seconds / 86_400,
+            seconds % 86_400 / 3_600,
+            seconds % 3_600 / 60,
+            seconds % 60,
+            nanoseconds / 1_000_000,
+            nanoseconds / 1_000 % 1_000,
+            nanoseconds % 1_000,
+        ];
+
+        let largest = components.iter().position(|&count| count != 0);
+        let Some(largest) = largest.filter(|_| max_components != 0) else {
+            return Some(String::from("0s"));
+        };
+        let mut string = String::from(if is_negative { "-" } else { "" });
+        for (count, unit) in components
+            .iter()
+            .zip(UNITS)
+            .skip(largest)
+            .take(max_components)
+            .filter(|(count, _)| **count != 0)
+        {
+            if string.len() > usize::from(is_negative) {
+                string.push(' ');
+            }
+            string.push_str(&format!("{count}{unit}"));
+        }
+        Some(string)
+    }
+
+    /// Splits this duration into its sign, the whole number of seconds in its magnitude, and the
+    /// decimal digits of the remaining fraction of a second. Returns `None` if the whole seconds
+    /// cannot be expressed as `u128`, before any fractional digits are computed.
+    fn iso8601_magnitude(&self) -> Option<(bool, u128, String)> {
+        let is_negative = self.count < Representation::zero();
+        let magnitude = self.abs_diff(Self::zero());
+        let seconds = magnitude.floor::<Second>().count().try_into_exact().ok()?;
+        let subseconds = magnitude
+            .count
+            .fractional_digits(Period::FRACTION, None, 10)
+            .map(|digit| char::from(b'0' + digit))
+            .collect();
+        Some((is_negative, seconds, subseconds))
+    }
+}
+
+/// Formats a duration magnitude of whole `seconds` plus some fractional digits as ISO 8601
+/// duration. The date components are given as pairs of their length in seconds and designator, in
+/// decreasing order. Components that are zero are omitted; an empty duration is printed as "PT0S".
+#[cfg(feature = "std")]
+fn format_iso8601(
+    is_negative: bool,
+    date_components: &[(u128, char)],
+    mut seconds: u128,
+    subseconds: &str,
+) -> String {
+    let mut string = String::from(if is_negative { "-P" } else { "P" });
+    for (length, designator) in date_components {
+        let count = seconds / length;
+        seconds %= length;
+        if count != 0 {
+            string.push_str(&format!("{count}{designator}"));
+        }
+    }
+
+    let (hours, minutes, seconds) = (seconds / 3600, seconds % 3600 / 60, seconds % 60);
+    let is_empty = string.len() <= 2;
+    if hours != 0 || minutes != 0 || seconds != 0 || !subseconds.is_empty() || is_empty {
+        string.push('T');
+        if hours != 0 {
+            string.push_str(&format!("{hours}H"));
+        }
+        if minutes != 0 {
+            string.push_str(&format!("{minutes}M"));
+        }
+        if seconds != 0 || !subseconds.is_empty() || (hours == 0 && minutes == 0) {
+            string.push_str(&format!("{seconds}"));
+            if !subseconds.is_empty() {
+                string.push_str(&format!(".{subseconds}"));
+            }
+            string.push('S');
+        }
+    }
+    string
+}
+
 impl<Representation, Period> Bounded for Duration<Representation, Period>
 where
     Representation: Bounded,
@@ -438,6 +1219,17 @@ where
     }
 }
 
+/// The default `Duration` is zero, such that durations may be used in `#[derive(Default)]` types.
+impl<Representation, Period> Default for Duration<Representation, Period>
+where
+    Representation: Zero,
+    Period: ?Sized,
+{
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
 impl<Representation, Period> ConstZero for Duration<Representation, Period>
 where
     Representation: ConstZero,
@@ -449,6 +1241,210 @@ where
     };
 }
 
+impl<Representation, Period> Duration<Representation, Period>
+where
+    Representation: ConstOne,
+    Period: ?Sized,
+{
+    /// A `Duration` of exactly one tick of the underlying `Period`.
+    pub const UNIT: Self = Self {
+        count: Representation::ONE,
+        period: core::marker::PhantomData,
+    };
+}
+
+impl<Representation, Period> Duration<Representation, Period>
+where
+    Period: ?Sized,
+{
+    /// Returns a `Duration` of exactly one tick of the underlying `Period`. Unlike `UNIT`, also
+    /// available for representations that cannot express one as a constant.
+    pub fn unit() -> Self
+    where
+        Representation: One,
+    {
+        Self::new(Representation::one())
+    }
+}
+
+impl<Representation, Period> Duration<Representation, Period>
+where
+    Representation: PartialOrd + Zero,
+    Period: ?Sized,
+{
+    /// Returns the direction of this `Duration` by comparing it against zero. Unlike `signum`,
+    /// `is_positive`, and `is_negative`, this does not require a `Signed` representation, so it
+    /// may be used in generic code over unsigned representations as well. Values that cannot be
+    /// compared against zero (i.e., NaN) are considered equal to zero.
+    pub fn sign(&self) -> Ordering {
+        self.count
+            .partial_cmp(&Representation::zero())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl<Representation, Period> Duration<Representation, Period>
+where
+    Period: UnitRatio + ?Sized,
+{
+    /// Returns the ratio of one tick of this `Duration` to seconds. Permits inspecting the unit of
+    /// a `Duration` at runtime, for example when building dynamic unit-aware displays.
+    pub const fn period_ratio(&self) -> Fraction {
+        Period::FRACTION
+    }
+}
+
+impl<Representation, Period> Duration<Representation, Period>
+where
+    Representation: ToBytes,
+    Period: ?Sized,
+{
+    /// Returns the raw bytes of the underlying representation in little-endian byte order. For
+    /// primitive representations, this is a fixed-size byte array: this makes it suitable for
+    /// deterministic binary logging without requiring `serde`.
+    pub fn to_le_bytes(&self) -> Representation::Bytes {
+        self.count.to_le_bytes()
+    }
+
+    /// Returns the raw bytes of the underlying representation in big-endian byte order.
+    pub fn to_be_bytes(&self) -> Representation::Bytes {
+        self.count.to_be_bytes()
+    }
+}
+
+impl<Representation, Period> Duration<Representation, Period>
+where
+    Representation: FromBytes,
+    Period: ?Sized,
+{
+    /// Reconstructs a `Duration` from the raw bytes of its representation, as produced by
+    /// `to_le_bytes`.
+    pub fn from_le_bytes(bytes: &Representation::Bytes) -> Self {
+        Self::new(Representation::from_le_bytes(bytes))
+    }
+
+    /// Reconstructs a `Duration` from the raw bytes of its representation, as produced by
+    /// `to_be_bytes`.
+    pub fn from_be_bytes(bytes: &Representation::Bytes) -> Self {
+        Self::new(Representation::from_be_bytes(bytes))
+    }
+}
+
+impl<Representation, Period> Duration<Representation, Period>
+where
+    Representation: Copy + PartialOrd + Zero,
+    Period: UnitRatio,
+{
+    /// Splits this duration into its sign and the non-negative magnitudes of its days, hours,
+    /// minutes, seconds, and remaining subseconds. Factoring out the sign this way permits
+    /// formatting negative durations as, for example, "-(1m 30s)", rather than mixing signs
+    /// between components. The sign is expressed as in `sign`: `Ordering::Less` for negative
+    /// durations. Like `abs_diff`, the subseconds are expressed in the unsigned counterpart of
+    /// signed integer representations.
+    ///
+    /// Returns `None` if the number of whole seconds cannot be expressed as `u128`, which is only
+    /// possible for non-finite or very large floating point durations.
+    #[allow(clippy::type_complexity)]
+    pub fn to_signed_parts<Magnitude>(
+        self,
+    ) -> Option<(
+        Ordering,
+        Days<u128>,
+        Hours<u128>,
+        Minutes<u128>,
+        Seconds<u128>,
+        Duration<Magnitude, Period>,
+    )>
+    where
+        Representation: AbsDiff<Magnitude = Magnitude>,
+        Magnitude: Copy
+            + Sub<Magnitude, Output = Magnitude>
+            + MulFloor<Fraction, Output = Magnitude>
+            + ConvertUnit<Second, Period>
+            + TryIntoExact<u128>,
+    {
+        let sign = self.sign();
+        let magnitude = self.abs_diff(Self::zero());
+        let whole_seconds = magnitude.floor::<Second>();
+        let subseconds = magnitude - whole_seconds.into_unit();
+        let seconds: u128 = whole_seconds.count().try_into_exact().ok()?;
+        Some((
+            sign,
+            Days::new(seconds / 86_400),
+            Hours::new(seconds % 86_400 / 3_600),
+            Minutes::new(seconds % 3_600 / 60),
+            Seconds::new(seconds % 60),
+            subseconds,
+        ))
+    }
+}
+
+impl<Representation, Period> Duration<Representation, Period>
+where
+    Representation: Copy
+        + PartialOrd
+        + Zero
+        + MulFloor<Fraction, Output = Representation>
+        + TryIntoExact<u128>
+        + FractionalDigits,
+    Period: UnitRatio,
+{
+    /// Converts this duration into a `core::time::Duration`, but only if that is possible without
+    /// any loss of information: the duration must be a non-negative number, must fit in the range
+    /// of `core::time::Duration`, and may not contain any sub-nanosecond part.
+    pub fn try_into_std(self) -> Result<core::time::Duration, StdDurationConversionError> {
+        match self.count.partial_cmp(&Representation::zero()) {
+            None => return Err(StdDurationConversionError::NotANumber),
+            Some(Ordering::Less) => return Err(StdDurationConversionError::Negative),
+            _ => {}
+        }
+        let (seconds, nanoseconds, is_exact) = self.std_components();
+        let seconds = seconds.ok_or(StdDurationConversionError::Overflow)?;
+        if !is_exact {
+            return Err(StdDurationConversionError::SubnanosecondPrecision);
+        }
+        Ok(core::time::Duration::new(seconds, nanoseconds))
+    }
+
+    /// Converts this duration into a `core::time::Duration`, for the common case where some loss
+    /// of precision is acceptable, like when computing a timeout. Negative and NaN durations map to
+    /// zero, durations that are too large saturate to `core::time::Duration::MAX`, and any
+    /// sub-nanosecond part is truncated.
+    pub fn to_std_lossy(self) -> core::time::Duration {
+        if self.sign() != Ordering::Greater {
+            return core::time::Duration::ZERO;
+        }
+        match self.std_components() {
+            (Some(seconds), nanoseconds, _) => core::time::Duration::new(seconds, nanoseconds),
+            (None, _, _) => core::time::Duration::MAX,
+        }
+    }
+
+    /// Splits a non-negative duration into its whole seconds (if representable as `u64`), whole
+    /// nanoseconds, and whether those two components express the duration exactly. Durations whose
+    /// seconds are out of range (including infinities) are rejected before any digits are
+    /// computed.
+    fn std_components(self) -> (Option<u64>, u32, bool) {
+        let Some(seconds) = self
+            .floor::<Second>()
+            .count()
+            .try_into_exact()
+            .ok()
+            .and_then(|seconds: u128| u64::try_from(seconds).ok())
+        else {
+            return (None, 0, false);
+        };
+        let mut digits = self.count.fractional_digits(Period::FRACTION, None, 10);
+        let nanoseconds = digits
+            .by_ref()
+            .chain(core::iter::repeat(0))
+            .take(9)
+            .fold(0, |nanoseconds, digit| nanoseconds * 10 + u32::from(digit));
+        let is_exact = digits.all(|digit| digit == 0);
+        (Some(seconds), nanoseconds, is_exact)
+    }
+}
+
 impl<Representation, Period> Duration<Representation, Period>
 where
     Representation: Signed,
@@ -484,6 +1480,31 @@ where
     }
 }
 
+impl<Representation, Period> Duration<Representation, Period>
+where
+    Representation: Float,
+    Period: ?Sized,
+{
+    /// Computes `self * factor + addend` with only a single rounding error, using a fused
+    /// multiply-add. This is more accurate than the naive expression, which is useful when
+    /// rounding errors would otherwise accumulate, such as in iterative time propagation.
+    pub fn mul_add(self, factor: Representation, addend: Self) -> Self {
+        Self::new(self.count.mul_add(factor, addend.count))
+    }
+
+    /// Constructs a new `Duration`, but only if the given count is finite: returns `None` for NaN
+    /// and infinite counts. Such values would otherwise silently poison all later arithmetic and
+    /// comparisons, so this should be preferred when ingesting floats from external sources.
+    pub fn new_finite(count: Representation) -> Option<Self> {
+        count.is_finite().then(|| Self::new(count))
+    }
+
+    /// Returns whether this duration is finite: that is, neither NaN nor infinite.
+    pub fn is_finite(&self) -> bool {
+        self.count.is_finite()
+    }
+}
+
 impl<Representation, Period> TryMul<Fraction> for Duration<Representation, Period>
 where
     Representation: TryMul<Fraction>,
@@ -660,6 +1681,36 @@ fn convert_binary_fraction_seconds() {
     assert_eq!(fraction6.count(), 0x1000000000000);
 }
 
+/// Verifies that unit durations correspond with exactly one tick of the underlying period.
+#[test]
+fn unit_durations() {
+    assert_eq!(Seconds::<i64>::unit().count(), 1);
+    assert_eq!(Seconds::<i64>::UNIT, Seconds::new(1));
+    assert_eq!(NanoSeconds::<u64>::UNIT, NanoSeconds::new(1));
+    assert_eq!(
+        NanoSeconds::<i128>::unit(),
+        Seconds::new(1i128).into_unit::<Nano>() / 1_000_000_000
+    );
+    assert_eq!(Days::<f64>::unit().into_unit(), Hours::new(24.));
+}
+
+/// Verifies that durations can be split into a whole number of coarser units and a remainder.
+#[test]
+fn split_at() {
+    use crate::units::{SecondsPerHour, SecondsPerMinute};
+    let (hours, remainder) = Seconds::new(3661i64).split_at::<SecondsPerHour>();
+    assert_eq!(hours, Hours::new(1));
+    assert_eq!(remainder, Seconds::new(61));
+
+    let (minutes, remainder) = Seconds::new(-30i64).split_at::<SecondsPerMinute>();
+    assert_eq!(minutes, Minutes::new(-1));
+    assert_eq!(remainder, Seconds::new(30));
+
+    let (seconds, remainder) = Seconds::new(3661i64).split_at::<Second>();
+    assert_eq!(seconds, Seconds::new(3661));
+    assert_eq!(remainder, Seconds::new(0));
+}
+
 /// Verification of the rounding behaviour of `Duration`s when a float is used as underlying
 /// representation.
 #[test]
@@ -677,6 +1728,104 @@ fn rounding_floats() {
     assert_eq!(year_fraction.round(), Years::new(2.));
 }
 
+/// Verifies that checked multiplication and division detect overflow and division by zero.
+#[test]
+fn checked_mul_div() {
+    assert_eq!(Seconds::new(i64::MAX).checked_mul(2), None);
+    assert_eq!(Seconds::new(21i64).checked_mul(2), Some(Seconds::new(42)));
+    assert_eq!(Seconds::new(42i64).checked_div(0), None);
+    assert_eq!(Seconds::new(i64::MIN).checked_div(-1), None);
+    assert_eq!(Seconds::new(42u32).checked_div(2), Some(Seconds::new(21)));
+}
+
+/// Verifies that the absolute difference between durations is symmetric, also for unsigned
+/// representations.
+#[test]
+fn abs_diff() {
+    let a = NanoSeconds::new(5u64);
+    let b = NanoSeconds::new(u64::MAX);
+    assert_eq!(a.abs_diff(b), NanoSeconds::new(u64::MAX - 5));
+    assert_eq!(b.abs_diff(a), NanoSeconds::new(u64::MAX - 5));
+    assert_eq!(a.abs_diff(a), NanoSeconds::new(0));
+    assert_eq!(
+        Seconds::new(-3i32).abs_diff(Seconds::new(4)),
+        Seconds::new(7u32)
+    );
+
+    // Signed magnitudes are expressed as unsigned, so that even `i64::MIN` does not overflow.
+    let min = Seconds::new(i64::MIN);
+    assert_eq!(min.abs_diff(Seconds::new(0)), Seconds::new(1u64 << 63));
+    assert_eq!(min.abs_diff(Seconds::new(i64::MAX)), Seconds::new(u64::MAX));
+    assert_eq!(
+        Seconds::new(-1.5f64).abs_diff(Seconds::new(2.0)),
+        Seconds::new(3.5)
+    );
+}
+
+/// Verifies that durations can be formatted as ISO 8601 week and calendar durations.
+#[cfg(feature = "std")]
+#[test]
+fn iso8601_formatting() {
+    assert_eq!(
+        Days::new(14i64).to_iso8601_weeks(),
+        Some(String::from("P2W"))
+    );
+    assert_eq!(
+        Weeks::new(-3i32).to_iso8601_weeks(),
+        Some(String::from("-P3W"))
+    );
+    assert_eq!(
+        Days::new(15i64).to_iso8601_weeks(),
+        Some(String::from("P15D"))
+    );
+    assert_eq!(
+        MilliSeconds::new(93_784_500u64).to_iso8601_weeks(),
+        Some(String::from("P1DT2H3M4.5S"))
+    );
+    assert_eq!(
+        Seconds::new(0i64).to_iso8601_weeks(),
+        Some(String::from("PT0S"))
+    );
+
+    assert_eq!(
+        Years::new(1i64).to_iso8601_calendar(),
+        Some(String::from("P1Y"))
+    );
+    assert_eq!(
+        Months::new(14i64).to_iso8601_calendar(),
+        Some(String::from("P1Y2M"))
+    );
+    assert_eq!(
+        Days::new(3i64).to_iso8601_calendar(),
+        Some(String::from("P3D"))
+    );
+    assert_eq!(
+        Seconds::new(-90i64).to_iso8601_calendar(),
+        Some(String::from("-PT1M30S"))
+    );
+    assert_eq!(
+        Seconds::new(3600.25f64).to_iso8601_calendar(),
+        Some(String::from("PT1H0.25S"))
+    );
+    assert_eq!(
+        MilliSeconds::new(-500i64).to_iso8601_calendar(),
+        Some(String::from("-PT0.5S"))
+    );
+
+    // Extreme values either format exactly or are rejected, but never overflow.
+    assert_eq!(
+        Seconds::new(i64::MIN).to_iso8601_calendar(),
+        Some(String::from("-P292277024626Y11M4DT50M50S"))
+    );
+    assert_eq!(
+        Seconds::new(i64::MIN).to_iso8601_weeks(),
+        Some(String::from("-P106751991167300DT15H30M8S"))
+    );
+    assert_eq!(Seconds::new(1e300f64).to_iso8601_weeks(), None);
+    assert_eq!(Seconds::new(f64::INFINITY).to_iso8601_calendar(), None);
+    assert_eq!(Seconds::new(f64::NAN).to_iso8601_calendar(), None);
+}
+
 #[cfg(kani)]
 mod proof_harness {
     use super::*;
@@ -816,3 +1965,581 @@ fn rounding_integers() {
     let seconds_per_minute = Seconds::new(-99i8);
     assert_eq!(seconds_per_minute.round(), Minutes::new(-2));
 }
+
+/// Verifies that durations are displayed as decimal seconds, respecting the sign, precision, and
+/// width flags.
+#[cfg(feature = "std")]
+#[test]
+fn display() {
+    assert_eq!(format!("{}", Seconds::new(5i64)), "5s");
+    assert_eq!(format!("{:+}", Seconds::new(5i64)), "+5s");
+    assert_eq!(format!("{:+}", Seconds::new(-5i64)), "-5s");
+    assert_eq!(format!("{}", MilliSeconds::new(-1500i64)), "-1.5s");
+    assert_eq!(format!("{:>8.2}", MilliSeconds::new(1500i64)), "   1.50s");
+    assert_eq!(format!("{:<6}|", Seconds::new(12i64)), "12s   |");
+    assert_eq!(format!("{:+>6}", Seconds::new(12i64)), "+++12s");
+    assert_eq!(format!("{}", Hours::new(1i64)), "3600s");
+
+    // Extreme values must not overflow, neither in magnitude nor in formatted length.
+    assert_eq!(
+        format!("{}", Seconds::new(i64::MIN)),
+        "-9223372036854775808s"
+    );
+    let large = format!("{:>400}", Seconds::new(1e300f64));
+    assert_eq!(large.len(), 400);
+    assert_eq!(large.trim_start(), format!("1{}s", "0".repeat(300)));
+}
+
+/// Verifies that checked negation fails only for unrepresentable results.
+#[test]
+fn checked_neg() {
+    assert_eq!(Seconds::new(5i64).checked_neg(), Some(Seconds::new(-5)));
+    assert_eq!(Seconds::new(-5i64).checked_neg(), Some(Seconds::new(5)));
+    assert_eq!(
+        Seconds::new(i64::MAX).checked_neg(),
+        Some(Seconds::new(-i64::MAX))
+    );
+    assert_eq!(Seconds::new(i64::MIN).checked_neg(), None);
+    assert_eq!(Seconds::new(0u32).checked_neg(), Some(Seconds::new(0)));
+    assert_eq!(Seconds::new(1u32).checked_neg(), None);
+}
+
+/// Verifies that durations are formatted as human-friendly strings.
+#[cfg(feature = "std")]
+#[test]
+fn human_string() {
+    assert_eq!(
+        Seconds::new(3661i64).to_human_string(3),
+        Some(String::from("1h 1m 1s"))
+    );
+    assert_eq!(
+        MilliSeconds::new(500i64).to_human_string(3),
+        Some(String::from("500ms"))
+    );
+    assert_eq!(
+        Seconds::new(93_600i64).to_human_string(3),
+        Some(String::from("1d 2h"))
+    );
+    assert_eq!(
+        Seconds::new(93_605i64).to_human_string(2),
+        Some(String::from("1d 2h"))
+    );
+    assert_eq!(
+        Seconds::new(86_405i64).to_human_string(2),
+        Some(String::from("1d"))
+    );
+    assert_eq!(
+        Seconds::new(7384i64).to_human_string(usize::MAX),
+        Some(String::from("2h 3m 4s"))
+    );
+    assert_eq!(
+        MilliSeconds::new(-1500i64).to_human_string(3),
+        Some(String::from("-1s 500ms"))
+    );
+    assert_eq!(
+        NanoSeconds::new(1_002_003i64).to_human_string(3),
+        Some(String::from("1ms 2us 3ns"))
+    );
+    assert_eq!(
+        Seconds::new(0i64).to_human_string(3),
+        Some(String::from("0s"))
+    );
+    assert_eq!(
+        Seconds::new(5i64).to_human_string(0),
+        Some(String::from("0s"))
+    );
+    assert_eq!(
+        Duration::<f64, Second>::new(1.25).to_human_string(3),
+        Some(String::from("1s 250ms"))
+    );
+
+    assert_eq!(
+        Seconds::new(i64::MIN).to_human_string(4),
+        Some(String::from("-106751991167300d 15h 30m 8s"))
+    );
+    assert_eq!(Seconds::new(f64::NAN).to_human_string(3), None);
+    assert_eq!(Seconds::new(f64::INFINITY).to_human_string(3), None);
+}
+
+/// Verifies that the remainder of two durations is computed in their common unit.
+#[test]
+fn remainder() {
+    assert_eq!(
+        Seconds::new(3661i64) % Seconds::new(3600i64),
+        Seconds::new(61)
+    );
+    assert_eq!(
+        Seconds::new(3600i64) % Seconds::new(3600i64),
+        Seconds::new(0)
+    );
+    assert_eq!(
+        Seconds::new(-3661i64) % Seconds::new(3600i64),
+        Seconds::new(-61)
+    );
+    assert_eq!(
+        MilliSeconds::new(2.5f64) % MilliSeconds::new(1.0f64),
+        MilliSeconds::new(0.5)
+    );
+}
+
+/// Verifies that the sign of a duration is determined for both signed and unsigned
+/// representations.
+#[test]
+fn sign() {
+    assert_eq!(Seconds::new(0u32).sign(), Ordering::Equal);
+    assert_eq!(Seconds::new(5u32).sign(), Ordering::Greater);
+    assert_eq!(Seconds::new(0i64).sign(), Ordering::Equal);
+    assert_eq!(Seconds::new(5i64).sign(), Ordering::Greater);
+    assert_eq!(Seconds::new(-5i64).sign(), Ordering::Less);
+    assert_eq!(Seconds::new(-0.5f64).sign(), Ordering::Less);
+    assert_eq!(Seconds::new(f64::NAN).sign(), Ordering::Equal);
+}
+
+/// Verifies that overflow is detected when converting towards smaller units.
+#[test]
+fn checked_into_unit() {
+    assert_eq!(Seconds::new(i64::MAX).checked_into_unit::<Nano>(), None);
+    assert_eq!(Seconds::new(i64::MIN).checked_into_unit::<Nano>(), None);
+    assert_eq!(
+        Seconds::new(3i64).checked_into_unit::<Nano>(),
+        Some(NanoSeconds::new(3_000_000_000))
+    );
+    assert_eq!(
+        Hours::new(-2i64).checked_into_unit::<Second>(),
+        Some(Seconds::new(-7200))
+    );
+    assert_eq!(Seconds::new(1i8).checked_into_unit::<Milli>(), None);
+    assert_eq!(
+        Seconds::new(0i8).checked_into_unit::<Nano>(),
+        Some(NanoSeconds::new(0))
+    );
+    assert_eq!(
+        Seconds::new(0u8).checked_into_unit::<Second>(),
+        Some(Seconds::new(0))
+    );
+}
+
+/// Verifies that saturating and wrapping unit conversions clamp and wrap at the bounds of the
+/// representation, respectively, instead of panicking.
+#[test]
+fn saturating_into_unit() {
+    assert_eq!(
+        Seconds::new(u32::MAX).saturating_into_unit::<Milli>(),
+        MilliSeconds::new(u32::MAX)
+    );
+    assert_eq!(
+        Seconds::new(5u32).saturating_into_unit::<Milli>(),
+        MilliSeconds::new(5_000)
+    );
+    assert_eq!(
+        Seconds::new(i32::MIN).saturating_into_unit::<Milli>(),
+        MilliSeconds::new(i32::MIN)
+    );
+    assert_eq!(
+        Seconds::new(1i8).saturating_into_unit::<Milli>(),
+        MilliSeconds::new(i8::MAX)
+    );
+    assert_eq!(
+        Seconds::new(-1i8).saturating_into_unit::<Milli>(),
+        MilliSeconds::new(i8::MIN)
+    );
+    assert_eq!(
+        Seconds::new(0i8).saturating_into_unit::<Milli>(),
+        MilliSeconds::new(0)
+    );
+
+    assert_eq!(
+        Seconds::new(u32::MAX).wrapping_into_unit::<Milli>(),
+        MilliSeconds::new(u32::MAX.wrapping_mul(1_000))
+    );
+    assert_eq!(
+        Seconds::new(-3i64).wrapping_into_unit::<Milli>(),
+        MilliSeconds::new(-3_000)
+    );
+    assert_eq!(
+        Seconds::new(1u8).wrapping_into_unit::<Milli>(),
+        MilliSeconds::new((1_000u32 % 256) as u8)
+    );
+}
+
+/// Verifies that fused multiply-add avoids the intermediate rounding of the naive expression.
+#[test]
+fn mul_add() {
+    let duration = Seconds::new(0.1f64);
+    let addend = Seconds::new(-1.0f64);
+    let naive = duration * 10.0 + addend;
+    let fused = duration.mul_add(10.0, addend);
+    assert_eq!(naive, Seconds::new(0.0));
+    assert_eq!(fused, Seconds::new(5.551115123125783e-17));
+    assert_ne!(naive, fused);
+    assert_eq!(
+        MilliSeconds::new(1.5f32).mul_add(2.0, MilliSeconds::new(1.0)),
+        MilliSeconds::new(4.0)
+    );
+}
+
+/// Verifies that the ratio of a unit to seconds is available at runtime.
+#[test]
+fn period_ratio() {
+    use crate::units::{BinaryFraction8, unit_ratio};
+    assert_eq!(unit_ratio::<Milli>(), (1, 1000));
+    assert_eq!(unit_ratio::<SecondsPerHour>(), (3600, 1));
+    assert_eq!(unit_ratio::<BinaryFraction8>(), (1, 1 << 64));
+    assert_eq!(MilliSeconds::new(5).period_ratio(), Fraction::new(1, 1000));
+    assert_eq!(Hours::new(2.5).period_ratio(), Fraction::new(3600, 1));
+}
+
+/// Verifies that the checked, saturating, and wrapping variants of addition and subtraction
+/// behave as their primitive counterparts at the bounds of the representation.
+#[test]
+fn overflowing_arithmetic() {
+    let max = Seconds::new(i64::MAX);
+    let min = Seconds::new(i64::MIN);
+    let one = Seconds::new(1i64);
+    assert_eq!(max.checked_add(one), None);
+    assert_eq!(min.checked_sub(one), None);
+    assert_eq!(one.checked_add(one), Some(Seconds::new(2)));
+    assert_eq!(max.saturating_add(one), max);
+    assert_eq!(min.saturating_sub(one), min);
+    assert_eq!(max.wrapping_add(one), min);
+    assert_eq!(min.wrapping_sub(one), max);
+    assert_eq!(one.wrapping_sub(Seconds::new(3)), Seconds::new(-2));
+    assert_eq!(
+        Seconds::new(0u8).wrapping_sub(Seconds::new(1)),
+        Seconds::new(u8::MAX)
+    );
+}
+
+/// Verifies that the sign of a duration is factored out of its components, such that all of them
+/// are non-negative.
+#[test]
+fn signed_parts() {
+    assert_eq!(
+        Seconds::new(-90i64).to_signed_parts(),
+        Some((
+            Ordering::Less,
+            Days::new(0),
+            Hours::new(0),
+            Minutes::new(1),
+            Seconds::new(30),
+            Seconds::new(0)
+        ))
+    );
+    assert_eq!(
+        MilliSeconds::new(90_123_500i64).to_signed_parts(),
+        Some((
+            Ordering::Greater,
+            Days::new(1),
+            Hours::new(1),
+            Minutes::new(2),
+            Seconds::new(3),
+            MilliSeconds::new(500)
+        ))
+    );
+    let (sign, _, _, minutes, seconds, subseconds) =
+        MilliSeconds::new(-60_001i64).to_signed_parts().unwrap();
+    assert_eq!(
+        (sign, minutes, seconds),
+        (Ordering::Less, Minutes::new(1), Seconds::new(0))
+    );
+    assert_eq!(subseconds, MilliSeconds::new(1));
+    assert_eq!(
+        Seconds::new(0u32).to_signed_parts().unwrap().0,
+        Ordering::Equal
+    );
+
+    // The sign is split off before the magnitude is taken, so the most negative value is fine.
+    assert_eq!(
+        Seconds::new(i64::MIN).to_signed_parts(),
+        Some((
+            Ordering::Less,
+            Days::new(106_751_991_167_300),
+            Hours::new(15),
+            Minutes::new(30),
+            Seconds::new(8),
+            Seconds::new(0u64)
+        ))
+    );
+    let (_, _, _, _, seconds, subseconds) = MilliSeconds::new(i64::MIN).to_signed_parts().unwrap();
+    assert_eq!(seconds, Seconds::new(55));
+    assert_eq!(subseconds, MilliSeconds::new(808u64));
+
+    // Floats whose whole seconds do not fit in a `u128` cannot be split into parts.
+    assert!(Seconds::new(f64::NAN).to_signed_parts().is_none());
+    assert!(Seconds::new(f64::INFINITY).to_signed_parts().is_none());
+    assert!(Seconds::new(f64::NEG_INFINITY).to_signed_parts().is_none());
+    assert!(Seconds::new(1e300f64).to_signed_parts().is_none());
+    assert!(Seconds::new(-1.5f64).to_signed_parts().is_some());
+}
+
+/// Verifies that conversion into `core::time::Duration` is exact where requested, and saturates
+/// or truncates otherwise.
+#[test]
+fn std_duration() {
+    use crate::errors::StdDurationConversionError;
+    use core::time::Duration as StdDuration;
+    assert_eq!(
+        PicoSeconds::new(1_500_000i64).try_into_std(),
+        Ok(StdDuration::from_nanos(1_500))
+    );
+    assert_eq!(
+        PicoSeconds::new(1_500i64).try_into_std(),
+        Err(StdDurationConversionError::SubnanosecondPrecision)
+    );
+    assert_eq!(
+        PicoSeconds::new(1_500i64).to_std_lossy(),
+        StdDuration::from_nanos(1)
+    );
+    assert_eq!(
+        AttoSeconds::new(1.5f64).try_into_std(),
+        Err(StdDurationConversionError::SubnanosecondPrecision)
+    );
+    assert_eq!(AttoSeconds::new(1.5f64).to_std_lossy(), StdDuration::ZERO);
+    assert_eq!(
+        Seconds::new(1.5f64).try_into_std(),
+        Ok(StdDuration::from_millis(1_500))
+    );
+    assert_eq!(
+        Days::new(2u32).try_into_std(),
+        Ok(StdDuration::from_secs(172_800))
+    );
+
+    assert_eq!(
+        Seconds::new(-1i64).try_into_std(),
+        Err(StdDurationConversionError::Negative)
+    );
+    assert_eq!(Seconds::new(-1i64).to_std_lossy(), StdDuration::ZERO);
+    assert_eq!(Seconds::new(f64::NAN).to_std_lossy(), StdDuration::ZERO);
+    let huge = Seconds::new(u128::MAX);
+    assert_eq!(
+        huge.try_into_std(),
+        Err(StdDurationConversionError::Overflow)
+    );
+    assert_eq!(huge.to_std_lossy(), StdDuration::MAX);
+
+    assert_eq!(
+        Seconds::new(f64::NAN).try_into_std(),
+        Err(StdDurationConversionError::NotANumber)
+    );
+    assert_eq!(
+        Seconds::new(1e300f64).try_into_std(),
+        Err(StdDurationConversionError::Overflow)
+    );
+    assert_eq!(Seconds::new(1e300f64).to_std_lossy(), StdDuration::MAX);
+    assert_eq!(
+        Seconds::new(f64::INFINITY).try_into_std(),
+        Err(StdDurationConversionError::Overflow)
+    );
+    assert_eq!(Seconds::new(f64::INFINITY).to_std_lossy(), StdDuration::MAX);
+}
+
+/// Verifies that the typed constructors produce durations in their natural units.
+#[test]
+fn typed_constructors() {
+    assert_eq!(Duration::from_hours(2), Hours::new(2));
+    assert_eq!(
+        Duration::from_hours(2).into_unit(),
+        Minutes::new(120).into_unit::<Second>()
+    );
+    assert_eq!(Duration::from_weeks(1).into_unit(), Days::new(7));
+    assert_eq!(Duration::from_days(1).into_unit(), Hours::new(24));
+    assert_eq!(Duration::from_minutes(3).into_unit(), Seconds::new(180));
+    assert_eq!(Duration::from_secs(1).into_unit(), MilliSeconds::new(1_000));
+    assert_eq!(
+        Duration::from_millis(1).into_unit(),
+        MicroSeconds::new(1_000)
+    );
+    assert_eq!(
+        Duration::from_micros(1).into_unit(),
+        NanoSeconds::new(1_000)
+    );
+    assert_eq!(Duration::from_nanos(5u64), NanoSeconds::new(5u64));
+}
+
+/// Verifies that durations of different units may be combined, with the result expressed in the
+/// finer of both units regardless of operand order.
+#[test]
+fn mixed_period_arithmetic() {
+    use crate::units::{BinaryFraction1, BinaryFraction2};
+    assert_eq!(
+        Seconds::new(1).add_mixed(MilliSeconds::new(500)),
+        MilliSeconds::new(1_500)
+    );
+    assert_eq!(
+        MilliSeconds::new(500).add_mixed(Seconds::new(1)),
+        MilliSeconds::new(1_500)
+    );
+    assert_eq!(
+        Seconds::new(1).sub_mixed(MilliSeconds::new(1_500)),
+        MilliSeconds::new(-500)
+    );
+    assert_eq!(
+        Hours::new(1u64).add_mixed(Minutes::new(30)),
+        Minutes::new(90)
+    );
+    assert_eq!(Seconds::new(2).add_mixed(Seconds::new(3)), Seconds::new(5));
+    assert_eq!(
+        Duration::<u32, BinaryFraction1>::new(1)
+            .add_mixed(Duration::<u32, BinaryFraction2>::new(1)),
+        Duration::<u32, BinaryFraction2>::new(257)
+    );
+}
+
+/// Verifies that the ratio of two durations is computed without truncation.
+#[test]
+fn floating_ratio() {
+    let ratio = Seconds::new(1).div_duration_f64(&Seconds::new(3));
+    assert!((ratio - 1.0 / 3.0).abs() < 1e-12);
+    assert_eq!(Seconds::new(1) / 3, Seconds::new(0));
+    assert_eq!(
+        MilliSeconds::new(250u64).div_duration_f64(&MilliSeconds::new(1_000)),
+        0.25
+    );
+    assert_eq!(Seconds::new(-3i64).div_duration_f64(&Seconds::new(2)), -1.5);
+}
+
+/// Verifies that durations are clamped to a maximum magnitude, preserving their sign.
+#[test]
+fn clamp_magnitude() {
+    let limit = Seconds::new(5);
+    assert_eq!(Seconds::new(10).clamp_magnitude(limit), Seconds::new(5));
+    assert_eq!(Seconds::new(-10).clamp_magnitude(limit), Seconds::new(-5));
+    assert_eq!(Seconds::new(-3).clamp_magnitude(limit), Seconds::new(-3));
+    assert_eq!(Seconds::new(3).clamp_magnitude(limit), Seconds::new(3));
+    assert_eq!(
+        Seconds::new(10u32).clamp_magnitude(Seconds::new(5)),
+        Seconds::new(5)
+    );
+    assert_eq!(
+        Seconds::new(0u32).clamp_magnitude(Seconds::new(5)),
+        Seconds::new(0)
+    );
+}
+
+/// Verifies that non-finite float durations are rejected on construction.
+#[test]
+fn finite_construction() {
+    assert_eq!(Seconds::new_finite(f64::NAN), None);
+    assert_eq!(Seconds::new_finite(f64::INFINITY), None);
+    assert_eq!(Seconds::new_finite(f32::NEG_INFINITY), None);
+    assert_eq!(Seconds::new_finite(1.5f64), Some(Seconds::new(1.5)));
+    assert!(Seconds::new(1.5f64).is_finite());
+    assert!(!Seconds::new(f64::NAN).is_finite());
+}
+
+/// Verifies that the default duration is zero.
+#[test]
+fn default_is_zero() {
+    assert!(Duration::<i64>::default().is_zero());
+    assert_eq!(MilliSeconds::<u32>::default(), MilliSeconds::new(0));
+    assert_eq!(Seconds::<f64>::default(), Seconds::new(0.0));
+}
+
+/// Verifies that durations are scaled by fractions, rounding to nearest for integers.
+#[test]
+fn scale_by_fraction() {
+    assert_eq!(
+        Seconds::new(10).scale(Fraction::new(3, 2)),
+        Seconds::new(15)
+    );
+    assert_eq!(Seconds::new(10).scale(Fraction::new(1, 3)), Seconds::new(3));
+    assert_eq!(
+        Seconds::new(-10).scale(Fraction::new(2, 3)),
+        Seconds::new(-7)
+    );
+    assert_eq!(
+        Seconds::new(1.0f64).scale(Fraction::new(1, 4)),
+        Seconds::new(0.25)
+    );
+
+    // Overflow is reported rather than hidden behind the rounding fallback.
+    assert_eq!(
+        Seconds::new(i64::MAX).checked_scale(Fraction::new(3, 2)),
+        None
+    );
+    assert_eq!(
+        Seconds::new(i64::MAX).checked_scale(Fraction::new(2, 3)),
+        Some(Seconds::new(6_148_914_691_236_517_205))
+    );
+    assert_eq!(Seconds::new(200u8).checked_scale(Fraction::new(3, 2)), None);
+    assert_eq!(
+        Seconds::new(f64::MAX).checked_scale(Fraction::new(2, 1)),
+        None
+    );
+}
+
+/// Verifies that durations convert losslessly into finer units through `TryFrom`, and that
+/// overflow of the representation is reported rather than wrapped.
+#[test]
+fn lossless_from_conversions() {
+    assert_eq!(Seconds::try_from(Hours::new(2)), Ok(Seconds::new(7_200)));
+    let seconds: Seconds<i64> = Hours::new(2i64).try_into().unwrap();
+    assert_eq!(seconds, Seconds::new(7_200));
+    assert_eq!(Hours::try_from(Days::new(3)), Ok(Hours::new(72)));
+    assert_eq!(
+        Minutes::<u32>::try_from(Weeks::new(1u32)),
+        Ok(Minutes::new(10_080))
+    );
+    assert_eq!(HalfDays::try_from(Days::new(-2)), Ok(HalfDays::new(-4)));
+    assert_eq!(Months::try_from(Years::new(2)), Ok(Months::new(24)));
+    assert_eq!(
+        NanoSeconds::try_from(MilliSeconds::new(3i128)),
+        Ok(NanoSeconds::new(3_000_000))
+    );
+
+    assert_eq!(
+        NanoSeconds::<i32>::try_from(Hours::new(1i32)),
+        Err(UnitConversionOverflow)
+    );
+    assert_eq!(
+        AttoSeconds::<i64>::try_from(Days::new(1i64)),
+        Err(UnitConversionOverflow)
+    );
+    assert_eq!(
+        Seconds::<i64>::try_from(Hours::new(i64::MAX)),
+        Err(UnitConversionOverflow)
+    );
+}
+
+/// Verifies that durations convert infallibly through `From` into finer units when the
+/// representation is widened far enough for the conversion to never overflow.
+#[test]
+fn widening_from_conversions() {
+    assert_eq!(Seconds::<i64>::from(Hours::new(2)), Seconds::new(7_200));
+    let seconds: Seconds<i64> = Hours::new(2).into();
+    assert_eq!(seconds, Seconds::new(7_200));
+    let milliseconds: MilliSeconds<u64> = Weeks::new(u32::MAX).into();
+    assert_eq!(
+        milliseconds,
+        MilliSeconds::new(u32::MAX as u64 * 604_800_000)
+    );
+    assert_eq!(
+        NanoSeconds::<i64>::from(Seconds::new(i32::MIN)),
+        NanoSeconds::new(i32::MIN as i64 * 1_000_000_000)
+    );
+    assert_eq!(
+        NanoSeconds::<i128>::from(Years::new(i64::MAX)),
+        NanoSeconds::new(i64::MAX as i128 * 31_556_952_000_000_000)
+    );
+    assert_eq!(
+        AttoSeconds::<u128>::from(Seconds::new(u64::MAX)),
+        AttoSeconds::new(u64::MAX as u128 * 1_000_000_000_000_000_000)
+    );
+}
+
+/// Verifies that coarse-to-fine conversions of integer durations may be evaluated at compile time.
+#[test]
+fn const_unit_conversion() {
+    const SECONDS_PER_DAY: Seconds<i64> = Days::new(1i64).const_into_unit();
+    const _: () = assert!(SECONDS_PER_DAY.count() == 86_400);
+    const MILLISECONDS: MilliSeconds<u32> = Hours::new(3u32).const_into_unit();
+    assert_eq!(MILLISECONDS, Hours::new(3).into_unit());
+    assert_eq!(
+        Seconds::new(-2i8).const_into_unit::<Second>(),
+        Seconds::new(-2)
+    );
+    assert_eq!(
+        NanoSeconds::new(5i128).const_into_unit::<Atto>(),
+        AttoSeconds::new(5_000_000_000)
+    );
+}
@@ -4,7 +4,9 @@
 
 use thiserror::Error;
 
-use crate::{Date, DurationDesignator, HistoricDate, Month, parse::DecimalNumber};
+use crate::{
+    Date, DurationDesignator, HebrewMonth, HistoricDate, Month, TimeScaleId, parse::DecimalNumber,
+};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
 #[error("{day} {month} {year} does not exist in the historic calendar")]
@@ -22,6 +24,15 @@ pub struct InvalidGregorianDate {
     pub day: u8,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("invalid packed yyyymmdd date")]
+pub enum InvalidPackedDate {
+    #[error(transparent)]
+    InvalidMonthNumber(#[from] InvalidMonthNumber),
+    #[error(transparent)]
+    InvalidGregorianDate(#[from] InvalidGregorianDate),
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
 #[error("{day} {month} {year} does not exist in the proleptic Julian calendar")]
 pub struct InvalidJulianDate {
@@ -30,6 +41,30 @@ pub struct InvalidJulianDate {
     pub day: u8,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[error("day {day} of month {month} of year {year} does not exist in the Coptic calendar")]
+pub struct InvalidCopticDate {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[error("day {day} of month {month} of year {year} does not exist in the Ethiopic calendar")]
+pub struct InvalidEthiopicDate {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Error)]
+#[error("{day} {month} {year} does not exist in the Hebrew calendar")]
+pub struct InvalidHebrewDate {
+    pub year: i32,
+    pub month: HebrewMonth,
+    pub day: u8,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
 #[error("invalid combination of year and day-of-year")]
 pub enum InvalidDayOfYear {
@@ -52,6 +87,14 @@ pub struct InvalidMonthNumber {
     pub month: u8,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("string is not the name or three-letter abbreviation of a month")]
+pub struct MonthParsingError;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("string is not the name or three-letter abbreviation of a day of the week")]
+pub struct WeekDayParsingError;
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
 #[error("invalid week day number {week_day}")]
 pub struct InvalidWeekDayNumber {
@@ -66,6 +109,12 @@ pub struct InvalidTimeOfDay {
     pub second: u8,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("UTC offset of {minutes} minutes is not within 24 hours of UTC")]
+pub struct InvalidUtcOffset {
+    pub minutes: i32,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
 #[error("invalid historic date-time")]
 pub enum InvalidHistoricDateTime<InvalidDateTime: core::error::Error> {
@@ -74,6 +123,20 @@ pub enum InvalidHistoricDateTime<InvalidDateTime: core::error::Error> {
     InvalidDateTime(#[source] InvalidDateTime),
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("unable to build date-time")]
+pub enum DateTimeBuilderError<InvalidDateTime: core::error::Error> {
+    #[error(transparent)]
+    InvalidHistoricDate(#[from] InvalidHistoricDate),
+    #[error(transparent)]
+    InvalidGregorianDate(#[from] InvalidGregorianDate),
+    #[error(transparent)]
+    InvalidJulianDate(#[from] InvalidJulianDate),
+    #[error("no date was provided")]
+    MissingDate,
+    InvalidDateTime(#[source] InvalidDateTime),
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
 #[error("invalid Gregorian date-time")]
 pub enum InvalidGregorianDateTime<InvalidDateTime: core::error::Error> {
@@ -90,6 +153,25 @@ pub enum InvalidJulianDateTime<InvalidDateTime: core::error::Error> {
     InvalidDateTime(#[source] InvalidDateTime),
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("cannot convert to the requested representation and unit")]
+pub enum ConversionError<CastError> {
+    CastError(#[source] CastError),
+    #[error("unit conversion is not exact in the requested representation")]
+    InexactUnitConversion,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
+#[error("error parsing `AnyTimePoint`")]
+pub enum AnyTimePointParsingError {
+    #[error("time point does not end with the abbreviation of a known time scale")]
+    UnknownTimeScale,
+    #[error(transparent)]
+    TimePointParsingError(#[from] TimePointParsingError<InvalidTimeOfDay>),
+    UtcParsingError(#[source] TimePointParsingError<InvalidUtcDateTime>),
+    GlonassParsingError(#[source] TimePointParsingError<InvalidGlonassDateTime>),
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
 #[error("error parsing `TimePoint`")]
 pub enum TimePointParsingError<DateTimeError> {
@@ -103,6 +185,11 @@ pub enum TimePointParsingError<DateTimeError> {
     ExpectedSpace,
     #[error("expected but did not find time scale designator")]
     ExpectedTimeScaleDesignator,
+    #[error("expected time scale designator {expected} but found {found}")]
+    TimeScaleMismatch {
+        expected: &'static str,
+        found: TimeScaleId,
+    },
     #[error("could not parse entire string: data remains after time point")]
     UnexpectedRemainder,
     #[error(transparent)]
@@ -152,6 +239,21 @@ pub enum GregorianDateParsingError {
     UnexpectedRemainder,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
+#[error("error parsing ordinal date")]
+pub enum OrdinalDateParsingError {
+    #[error(transparent)]
+    IntegerParsingError(#[from] lexical_core::Error),
+    #[error(transparent)]
+    InvalidDayOfYear(#[from] InvalidDayOfYear),
+    #[error("expected but did not find year-day delimiter '-'")]
+    ExpectedYearDayDelimiter,
+    #[error("day-of-year representation must be exactly three digits")]
+    DayOfYearRepresentationNotThreeDigits,
+    #[error("could not parse entire string: data remains after ordinal date")]
+    UnexpectedRemainder,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
 #[error("error parsing `JulianDate`")]
 pub enum JulianDateParsingError {
@@ -173,6 +275,28 @@ pub enum JulianDateParsingError {
     UnexpectedRemainder,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
+#[error("error parsing `JulianDay`")]
+pub enum JulianDayParsingError {
+    #[error(transparent)]
+    NumberParsingError(#[from] NumberParsingError),
+    #[error(transparent)]
+    CannotRepresentDecimalNumber(#[from] CannotRepresentDecimalNumber),
+    #[error("could not parse entire string: data remains after Julian day")]
+    UnexpectedRemainder,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
+#[error("error parsing `ModifiedJulianDate`")]
+pub enum ModifiedJulianDateParsingError {
+    #[error(transparent)]
+    NumberParsingError(#[from] NumberParsingError),
+    #[error(transparent)]
+    CannotRepresentDecimalNumber(#[from] CannotRepresentDecimalNumber),
+    #[error("could not parse entire string: data remains after modified Julian date")]
+    UnexpectedRemainder,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
 #[error("error parsing `TimeOfDay`")]
 pub enum TimeOfDayParsingError {
@@ -192,6 +316,10 @@ pub enum TimeOfDayParsingError {
     NumberParsingError(#[from] NumberParsingError),
     #[error("could not parse entire string: data remains after time-of-day")]
     UnexpectedRemainder,
+    #[error(transparent)]
+    InvalidTimeOfDay(#[from] InvalidTimeOfDay),
+    #[error(transparent)]
+    CannotRepresentDecimalNumber(#[from] CannotRepresentDecimalNumber),
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
@@ -214,6 +342,27 @@ pub enum DurationParsingError {
     OnlyLowestOrderComponentMayHaveDecimalFraction,
     #[error(transparent)]
     CannotRepresentDecimalNumber(#[from] CannotRepresentDecimalNumber),
+    #[error("time designator 'T' must separate date components from time components")]
+    MisplacedTimeDesignator,
+    #[error("a leading sign may not be combined with signed duration components")]
+    AmbiguousSign,
+    #[error("negative duration cannot be expressed in underlying representation")]
+    NegativeDurationNotRepresentable,
+    #[error("duration cannot be expressed in underlying representation")]
+    DurationNotRepresentable,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
+#[error("error parsing `Duration` with unit suffix")]
+pub enum DurationSuffixParsingError {
+    #[error(transparent)]
+    NumberParsingError(#[from] NumberParsingError),
+    #[error("expected but did not find unit suffix after number")]
+    MissingUnitSuffix,
+    #[error("unit suffix is not one of 'ns', 'us', 'µs', 'ms', 's', 'm', 'h', 'd', or 'w'")]
+    UnknownUnitSuffix,
+    #[error(transparent)]
+    CannotRepresentDecimalNumber(#[from] CannotRepresentDecimalNumber),
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
@@ -249,10 +398,61 @@ pub enum NumberParsingError {
     TooManyFractionalDigits { fractional_digits: usize },
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("unable to convert between `UtcTime` and `time::OffsetDateTime`")]
+pub enum TimeCrateConversionError {
+    #[error("leap seconds cannot be represented by the `time` crate")]
+    LeapSecond,
+    #[error("time point lies outside of the representable range")]
+    OutOfRange,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error(
+    "leap second table is only valid until {}, but was used for {}",
+    <Date<i32> as Into<HistoricDate>>::into(*valid_until),
+    <Date<i32> as Into<HistoricDate>>::into(*date)
+)]
+pub struct LeapTableStale {
+    pub valid_until: Date<i32>,
+    pub date: Date<i32>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("system time cannot be represented as `i64` nanoseconds since 1972")]
+pub struct SystemTimeOutOfRange;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("unit conversion overflows the range of the representation")]
+pub struct UnitConversionOverflow;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("time scale conversion overflows the range of the representation")]
+pub struct TimeScaleConversionOverflow;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("abbreviation does not identify a known time scale")]
+pub struct UnknownTimeScale;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("Julian dates are not supported for non-uniform time scale {scale}")]
+pub struct JulianDateUnsupported {
+    pub scale: &'static str,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+#[error("NTP timestamp {timestamp:#018x} in era {era} cannot be represented as date")]
+pub struct InvalidNtpTimestamp {
+    pub timestamp: u64,
+    pub era: i32,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
 pub enum InvalidUtcDateTime {
     #[error("invalid time-of-day")]
     InvalidTimeOfDay(#[from] InvalidTimeOfDay),
+    #[error("invalid UTC offset")]
+    InvalidUtcOffset(#[from] InvalidUtcOffset),
     #[error("not a valid UTC leap second date-time: {}T{hour:02}-{minute:02}-{second:02}", <Date<i32> as Into<HistoricDate>>::into(*date))]
     NonLeapSecondDateTime {
         date: Date<i32>,
@@ -274,3 +474,49 @@ pub enum InvalidGlonassDateTime {
         second: u8,
     },
 }
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Error)]
+pub enum StdDurationConversionError {
+    #[error("negative durations cannot be expressed as `core::time::Duration`")]
+    Negative,
+    #[error("NaN durations cannot be expressed as `core::time::Duration`")]
+    NotANumber,
+    #[error("duration exceeds the range of `core::time::Duration`")]
+    Overflow,
+    #[error("duration has a sub-nanosecond part that `core::time::Duration` cannot express")]
+    SubnanosecondPrecision,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
+#[error("error parsing RINEX `LEAP SECONDS` header record")]
+pub enum RinexLeapSecondParsingError {
+    #[error(transparent)]
+    IntegerParsingError(#[from] lexical_core::Error),
+    #[error("header line is not labelled `LEAP SECONDS`")]
+    MissingLabel,
+    #[error("header record does not contain the current number of leap seconds")]
+    MissingCurrentLeapSeconds,
+    #[error("future leap seconds, week number, and day number must be given together")]
+    IncompleteFutureLeapSeconds,
+    #[error("day number {day} is outside of the valid range for the time system")]
+    InvalidDayNumber { day: u8 },
+    #[error("time system identifier is not supported: expected `GPS` or `BDS`")]
+    UnsupportedTimeSystem,
+    #[error("leap second count {count} does not result in a valid TAI - UTC offset")]
+    LeapSecondsOutOfRange { count: i16 },
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Error)]
+#[error("error parsing TZif leap second records")]
+pub enum TzifParsingError {
+    #[error("data does not start with the `TZif` magic sequence")]
+    InvalidMagic,
+    #[error("TZif version {version} is not supported: expected version 2 or 3")]
+    UnsupportedVersion { version: u8 },
+    #[error("TZif data ends before all of its records have been read")]
+    UnexpectedEndOfData,
+    #[error("leap second occurrence {occurrence} does not lie at the end of a UTC day")]
+    MisalignedOccurrence { occurrence: i64 },
+    #[error("leap second correction {correction} does not follow from the previous correction")]
+    InvalidCorrection { correction: i32 },
+}
@@ -0,0 +1,108 @@
+//! Helpers shared by the `Display` implementations of this library. Since this library supports
+//! `no_std` environments, formatted output is first rendered into a fixed-size stack buffer, after
+//! which the formatter's width, fill, and alignment flags are applied to the result.
+
+use core::fmt::{Alignment, Formatter, Write};
+
+/// Fixed-capacity buffer into which some formatted output may be rendered. Writing more than `N`
+/// bytes results in a formatting error.
+pub(crate) struct FormatBuffer<const N: usize> {
+    bytes: [u8; N],
+    length: usize,
+}
+
+impl<const N: usize> FormatBuffer<N> {
+    /// Creates a new, empty buffer.
+    pub(crate) const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            length: 0,
+        }
+    }
+
+    /// Returns the contents written into this buffer so far.
+    pub(crate) fn as_str(&self) -> &str {
+        // Only complete `str`s are ever written into the buffer, so it always contains valid UTF-8.
+        core::str::from_utf8(&self.bytes[..self.length]).unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl<const N: usize> Write for FormatBuffer<N> {
+    fn write_str(&mut self, string: &str) -> core::fmt::Result {
+        let end = self.length + string.len();
+        if end > N {
+            return Err(core::fmt::Error);
+        }
+        self.bytes[self.length..end].copy_from_slice(string.as_bytes());
+        self.length = end;
+        Ok(())
+    }
+}
+
+/// Writes `string` into the formatter, padded up to the formatter's width (if any) using its fill
+/// character. If no alignment is requested, `default_alignment` is used. Unlike `Formatter::pad`,
+/// this does not interpret the precision flag as maximum width: for time points and durations,
+/// precision instead determines the number of fractional digits.
+pub(crate) fn pad(
+    f: &mut Formatter<'_>,
+    string: &str,
+    default_alignment: Alignment,
+) -> core::fmt::Result {
+    let length = string.chars().count();
+    let padding = f.width().unwrap_or(0).saturating_sub(length);
+    let (before, after) = match f.align().unwrap_or(default_alignment) {
+        Alignment::Left => (0, padding),
+        Alignment::Right => (padding, 0),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(string)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+/// Counts the number of characters written into it, without storing them.
+struct CharacterCount(usize);
+
+impl Write for CharacterCount {
+    fn write_str(&mut self, string: &str) -> core::fmt::Result {
+        self.0 += string.chars().count();
+        Ok(())
+    }
+}
+
+/// Like `pad`, but renders the output using `render` instead of from a string. This is meant for
+/// output of which the length has no useful upper bound, such as float durations, which would not
+/// fit in a fixed-size buffer. If padding is needed, `render` is called twice: once to determine
+/// the length of the output, and once to write it.
+pub(crate) fn pad_with(
+    f: &mut Formatter<'_>,
+    default_alignment: Alignment,
+    render: impl Fn(&mut dyn Write) -> core::fmt::Result,
+) -> core::fmt::Result {
+    let Some(width) = f.width() else {
+        return render(f);
+    };
+    let mut count = CharacterCount(0);
+    render(&mut count)?;
+    let padding = width.saturating_sub(count.0);
+    let (before, after) = match f.align().unwrap_or(default_alignment) {
+        Alignment::Left => (0, padding),
+        Alignment::Right => (padding, 0),
+        Alignment::Center => (padding / 2, padding - padding / 2),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    render(f)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
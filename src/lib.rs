@@ -3,11 +3,19 @@
 #![forbid(unsafe_code)]
 mod arithmetic;
 pub use arithmetic::{
-    Fraction, FractionalDigits, MulCeil, MulFloor, MulRound, TryFromExact, TryIntoExact, TryMul,
+    AbsDiff, CheckedMulRound, ConstBounded, DoubleDouble, Fraction, FractionalDigits, MulCeil,
+    MulFloor, MulRound, RoundingMode, TryFromExact, TryIntoExact, TryMul,
 };
+#[cfg(feature = "proptest")]
+mod arbitrary;
+mod builder;
+mod datetime_parts;
+pub use builder::DateTimeBuilder;
+pub use datetime_parts::DateTimeParts;
 mod calendar;
 pub use calendar::{
-    Date, GregorianDate, HistoricDate, JulianDate, JulianDay, ModifiedJulianDate, Month, WeekDay,
+    Calendar, CalendarKind, CopticDate, Date, EthiopicDate, GregorianDate, HebrewDate, HebrewMonth,
+    HistoricDate, JulianDate, JulianDay, ModifiedJulianDate, Month, WeekDay, YearMonth,
 };
 mod duration;
 pub use duration::{
@@ -15,17 +23,30 @@ pub use duration::{
     Minutes, Months, NanoSeconds, PicoSeconds, Seconds, Weeks, Years,
 };
 pub mod errors;
+mod format;
 mod parse;
 pub use parse::{DurationComponent, DurationDesignator};
+#[cfg(feature = "std")]
+mod stopwatch;
+#[cfg(feature = "std")]
+pub use stopwatch::Stopwatch;
+#[cfg(feature = "time")]
+mod time_crate;
 mod time_point;
-pub use time_point::TimePoint;
+pub use time_point::{AutoPrecisionDisplay, HexFractionDisplay, TimePoint};
 mod time_scale;
 pub use time_scale::{
-    AbsoluteTimeScale, Bdt, BeiDouTime, FromDateTime, FromFineDateTime, FromLeapSecondDateTime,
-    FromTimeScale, GalileoTime, GlonassTime, Glonasst, GpsTime, Gpst, Gst, IntoDateTime,
-    IntoFineDateTime, IntoLeapSecondDateTime, IntoTimeScale, LeapSecondProvider, QzssTime, Qzsst,
-    STATIC_LEAP_SECOND_PROVIDER, StaticLeapSecondProvider, Tai, TaiTime, Tcg, TcgTime,
-    TerrestrialTime, TimeScale, Tt, TtTime, UniformDateTimeScale, Utc, UtcTime,
+    AbsoluteTimeScale, AnyTimePoint, Bdt, BeiDouTime, EopLeapSeconds, EopProvider, FromDateTime,
+    FromFineDateTime, FromLeapSecondDateTime, FromTimeScale, GalileoTime, GlonassTime, Glonasst,
+    GpsTime, Gpst, Gst, IntoDateTime, IntoFineDateTime, IntoLeapSecondDateTime, IntoTimeScale,
+    LeapSecondProvider, OffsetTime, QzssTime, Qzsst, STATIC_LEAP_SECOND_PROVIDER,
+    StaticLeapSecondProvider, Tai, TaiTime, Tcg, TcgTime, TerrestrialTime, TimeScale, TimeScaleId,
+    TryFromTimeScale, Tt, TtTime, UniformDateTimeScale, Utc, UtcTime, convert_time_scale, gmst,
+    try_convert_time_scale,
 };
+#[cfg(feature = "std")]
+pub use time_scale::{RinexLeapSecondProvider, TzifLeapSecondProvider};
+mod two_part_duration;
+pub use two_part_duration::TwoPartDuration;
 mod units;
 pub use units::*;
@@ -9,7 +9,8 @@ use crate::{
     errors::{CannotRepresentDecimalNumber, NumberParsingError},
 };
 
-/// Generic `Number` representation that may be used while parsing.
+/// Generic `Number` representation that may be used while parsing. The fraction always carries the
+/// same sign as the number as a whole, such that numbers in the range (-1, 0) may be represented.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub(crate) struct DecimalNumber {
     pub(crate) integer: i64,
@@ -20,6 +21,7 @@ pub(crate) struct DecimalNumber {
 impl DecimalNumber {
     /// Parses a decimal number. Does not need to consume the entire input string.
     pub(crate) fn parse_partial(string: &str) -> Result<(Self, &str), NumberParsingError> {
+        let is_negative = string.starts_with('-');
         let (integer, parsed_bytes) = parse_partial::<i64>(string.as_bytes())?;
         let remainder = string.get(parsed_bytes..).unwrap();
         if let Some('.') = remainder.chars().next() {
@@ -42,7 +44,7 @@ impl DecimalNumber {
                 Ok((
                     DecimalNumber {
                         integer,
-                        fraction,
+                        fraction: if is_negative { -fraction } else { fraction },
                         fractional_digits,
                     },
                     remainder,
@@ -81,22 +83,23 @@ impl DecimalNumber {
         Into: UnitRatio,
         Representation: TryFromExact<i64> + TryMul<Fraction, Output = Representation>,
     {
-        let fraction = Fraction::new(1, 10u128.pow(self.fractional_digits));
-        let mantissa = if self.integer >= 0 {
-            10i64.pow(self.fractional_digits) * self.integer + self.fraction
-        } else {
-            10i64.pow(self.fractional_digits) * self.integer - self.fraction
-        };
-        let uncorrected_duration = Duration::<_, From>::new(mantissa)
-            .try_into_unit()
-            .ok_or(CannotRepresentDecimalNumber { number: self })?;
-        let duration: Duration<Representation, Into> = match uncorrected_duration.try_cast() {
-            Ok(uncorrected_duration) => uncorrected_duration,
-            Err(_) => Err(CannotRepresentDecimalNumber { number: self })?,
+        // The conversion is applied as a single multiplication, such that it succeeds whenever the
+        // end result is exact, even if the unit is coarser than the one expressed (e.g., when
+        // converting "1500 ms" into seconds).
+        let error = CannotRepresentDecimalNumber { number: self };
+        let scale = 10i64.checked_pow(self.fractional_digits).ok_or(error)?;
+        let unit_ratio = From::FRACTION
+            .divide_by(&Into::FRACTION)
+            .divide_by(&Fraction::new(scale as u128, 1));
+        let mantissa = scale
+            .checked_mul(self.integer)
+            .and_then(|mantissa| mantissa.checked_add(self.fraction))
+            .ok_or(error)?;
+        let mantissa = match Representation::try_from_exact(mantissa) {
+            Ok(mantissa) => mantissa,
+            Err(_) => Err(error)?,
         };
-        duration
-            .try_mul(fraction)
-            .ok_or(CannotRepresentDecimalNumber { number: self })
+        mantissa.try_mul(unit_ratio).map(Duration::new).ok_or(error)
     }
 
     /// Decimal number that evaluates to zero.
@@ -5,30 +5,51 @@ use core::str::FromStr;
 use num_traits::ConstZero;
 
 use crate::{
-    Duration, UnitRatio,
+    Duration, Fraction, TryFromExact, TryMul, UnitRatio,
     errors::{
         CannotRepresentDecimalNumber, DurationComponentParsingError,
-        DurationDesignatorParsingError, DurationParsingError,
+        DurationDesignatorParsingError, DurationParsingError, DurationSuffixParsingError,
     },
     parse::DecimalNumber,
-    units::{Second, SecondsPerDay, SecondsPerHour, SecondsPerMinute, SecondsPerYear},
+    units::{
+        Micro, Milli, Nano, Second, SecondsPerDay, SecondsPerHour, SecondsPerMinute,
+        SecondsPerWeek, SecondsPerYear,
+    },
 };
 
-impl<Period> FromStr for Duration<i64, Period>
+impl<Representation, Period> FromStr for Duration<Representation, Period>
 where
+    Representation: TryFromExact<i64>,
     Period: UnitRatio,
 {
     type Err = DurationParsingError;
 
     /// Parses a `Duration` type based on some ISO 8601 duration string. However, we additionally
     /// impose that months may not be used as duration, to prevent confusion with minutes (and
-    /// because their precise duration cannot be unambiguously defined). Furthermore, we do not
-    /// support use of the time designator ('T') inside duration expressions. Finally, we support
-    /// years, days, hours, minutes, and seconds with any number of digits.
+    /// because their precise duration cannot be unambiguously defined). The time designator ('T')
+    /// is optional: if present, it must separate the date components (years and days) from the
+    /// time components (hours, minutes, and seconds). Finally, we support years, days, hours,
+    /// minutes, and seconds with any number of digits.
+    ///
+    /// Negative durations may be expressed in one of two ways: either the whole duration is
+    /// negated using a leading sign (e.g., "-PT5M"), or individual components are given a sign
+    /// (e.g., "PT-5M"). Combining both forms is rejected as ambiguous. If the resulting duration
+    /// is negative but the representation is unsigned, an error is returned.
     ///
     /// For years, following the rest of `finetime`, a duration of 31556952 seconds is used, which
     /// corresponds with the exact average duration of a Gregorian year.
     fn from_str(mut string: &str) -> Result<Self, Self::Err> {
+        // Parse the optional sign that applies to the duration as a whole.
+        let is_negated = if let Some(remainder) = string.strip_prefix('-') {
+            string = remainder;
+            true
+        } else if let Some(remainder) = string.strip_prefix('+') {
+            string = remainder;
+            false
+        } else {
+            false
+        };
+
         // Parse the mandatory duration prefix 'P'.
         if string.starts_with("P") {
             string = string.get(1..).unwrap();
@@ -36,23 +57,41 @@ where
             return Err(DurationParsingError::ExpectedDurationPrefix);
         }
 
-        let mut duration = Self::ZERO;
+        let mut duration = Duration::<i64, Period>::ZERO;
         let mut previous_designator = None;
+        let mut has_time_designator = false;
 
         loop {
+            // Parse the optional time designator, which may only precede time components.
+            if !has_time_designator && let Some(remainder) = string.strip_prefix('T') {
+                if previous_designator.is_some_and(|previous| previous < DurationDesignator::Days) {
+                    return Err(DurationParsingError::MisplacedTimeDesignator);
+                }
+                string = remainder;
+                has_time_designator = true;
+            }
+
+            if is_negated && string.starts_with(['-', '+']) {
+                return Err(DurationParsingError::AmbiguousSign);
+            }
+
             let (component, remainder) = DurationComponent::parse_partial(string)?;
             string = remainder;
 
+            if has_time_designator && component.designator >= DurationDesignator::Days {
+                return Err(DurationParsingError::MisplacedTimeDesignator);
+            }
+
             // Verify that the units are provided in decreasing order.
-            if let Some(previous) = previous_designator {
-                if component.designator >= previous {
-                    return Err(DurationParsingError::NonDecreasingDesignators {
-                        current: component.designator,
-                        previous,
-                    });
-                }
-                previous_designator = Some(component.designator);
+            if let Some(previous) = previous_designator
+                && component.designator >= previous
+            {
+                return Err(DurationParsingError::NonDecreasingDesignators {
+                    current: component.designator,
+                    previous,
+                });
             }
+            previous_designator = Some(component.designator);
 
             duration += component.into_period()?;
 
@@ -61,12 +100,55 @@ where
             }
 
             if string.is_empty() {
-                return Ok(duration);
+                break;
+            }
+        }
+
+        if is_negated {
+            duration = -duration;
+        }
+
+        match duration.try_cast() {
+            Ok(duration) => Ok(duration),
+            Err(_) if duration.count() < 0 => {
+                Err(DurationParsingError::NegativeDurationNotRepresentable)
             }
+            Err(_) => Err(DurationParsingError::DurationNotRepresentable),
         }
     }
 }
 
+impl<Representation, Period> Duration<Representation, Period>
+where
+    Representation: TryFromExact<i64> + TryMul<Fraction, Output = Representation>,
+    Period: UnitRatio,
+{
+    /// Parses a `Duration` from a plain decimal number directly followed by a unit suffix, as
+    /// commonly found in logs: for example, "1500ms", "2.5s", or "3h". The parsed value is
+    /// converted into the `Period` of the returned duration; if that cannot be done exactly, an
+    /// error is returned.
+    ///
+    /// Supported suffixes are "ns", "us" (or "µs"), "ms", "s", "m", "h", "d", and "w". The suffix
+    /// must match one of these exactly: in particular, "m" always denotes minutes, and "ms"
+    /// milliseconds. No whitespace is permitted between the number and its suffix.
+    pub fn parse_with_suffix(string: &str) -> Result<Self, DurationSuffixParsingError> {
+        let (number, suffix) = DecimalNumber::parse_partial(string)?;
+        let duration = match suffix {
+            "ns" => number.convert_period::<Nano, Period, _>()?,
+            "us" | "µs" => number.convert_period::<Micro, Period, _>()?,
+            "ms" => number.convert_period::<Milli, Period, _>()?,
+            "s" => number.convert_period::<Second, Period, _>()?,
+            "m" => number.convert_period::<SecondsPerMinute, Period, _>()?,
+            "h" => number.convert_period::<SecondsPerHour, Period, _>()?,
+            "d" => number.convert_period::<SecondsPerDay, Period, _>()?,
+            "w" => number.convert_period::<SecondsPerWeek, Period, _>()?,
+            "" => return Err(DurationSuffixParsingError::MissingUnitSuffix),
+            _ => return Err(DurationSuffixParsingError::UnknownUnitSuffix),
+        };
+        Ok(duration)
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<Representation, Period> serde::Serialize for Duration<Representation, Period>
 where
@@ -242,4 +324,121 @@ fn fractional_durations() {
 
     let seconds = Seconds::from_str("P23H59.5M").unwrap();
     assert_eq!(seconds, Seconds::new(23 * 3600 + 59 * 60 + 30));
+
+    // Fractions whose scale or mantissa does not fit an `i64` must be rejected, not overflow.
+    assert!(MilliSeconds::<i64>::from_str("PT0.1234567890123456789S").is_err());
+    assert!(MilliSeconds::<i64>::from_str("PT9223372036854775.807S").is_ok());
+    assert!(MilliSeconds::<i64>::from_str("PT9223372036854775.808S").is_err());
+}
+
+/// Verifies that durations with a leading sign are negated as a whole, and that negative
+/// durations are rejected for unsigned representations.
+#[test]
+fn signed_durations() {
+    use crate::{Hours, MilliSeconds, Seconds};
+    assert_eq!(Seconds::from_str("-PT5M").unwrap(), Seconds::new(-300i64));
+    assert_eq!(Seconds::from_str("+PT5M").unwrap(), Seconds::new(300i64));
+    assert_eq!(
+        Seconds::from_str("-P1DT1H").unwrap(),
+        Seconds::new(-90000i64)
+    );
+    assert_eq!(
+        MilliSeconds::from_str("-PT0.5S").unwrap(),
+        MilliSeconds::new(-500i64)
+    );
+    assert_eq!(
+        Seconds::from_str("-PT5M").map(|s: Seconds<i32>| s.count()),
+        Ok(-300)
+    );
+
+    // Per-component signs are supported, but may not be combined with a leading sign.
+    assert_eq!(Seconds::from_str("PT1H-5M").unwrap(), Seconds::new(3300i64));
+    assert_eq!(
+        Seconds::<i64>::from_str("-PT-5M"),
+        Err(DurationParsingError::AmbiguousSign)
+    );
+
+    assert_eq!(
+        Seconds::<u32>::from_str("-PT5M"),
+        Err(DurationParsingError::NegativeDurationNotRepresentable)
+    );
+    assert_eq!(Seconds::<u32>::from_str("PT5M"), Ok(Seconds::new(300u32)));
+    assert_eq!(
+        Hours::<u8>::from_str("P1000H"),
+        Err(DurationParsingError::DurationNotRepresentable)
+    );
+}
+
+/// Verifies that the time designator is only accepted between date and time components.
+#[test]
+fn time_designator() {
+    use crate::Seconds;
+    assert_eq!(Seconds::from_str("PT5M").unwrap(), Seconds::new(300i64));
+    assert_eq!(Seconds::from_str("P1DT5S").unwrap(), Seconds::new(86405i64));
+    assert_eq!(
+        Seconds::<i64>::from_str("P5MT5S"),
+        Err(DurationParsingError::MisplacedTimeDesignator)
+    );
+    assert_eq!(
+        Seconds::<i64>::from_str("PT1D"),
+        Err(DurationParsingError::MisplacedTimeDesignator)
+    );
+}
+
+/// Verifies parsing of plain numbers with a unit suffix.
+#[test]
+fn suffixed_durations() {
+    use crate::{MilliSeconds, Seconds};
+    assert_eq!(
+        Duration::<i64, Milli>::parse_with_suffix("1500ms"),
+        Ok(MilliSeconds::new(1500))
+    );
+    assert_eq!(
+        Duration::<f64>::parse_with_suffix("1500ms"),
+        Ok(Seconds::new(1.5))
+    );
+    assert_eq!(
+        Duration::<i64>::parse_with_suffix("2.5h"),
+        Ok(Seconds::new(9000))
+    );
+    assert_eq!(
+        Duration::<i64>::parse_with_suffix("-3m"),
+        Ok(Seconds::new(-180))
+    );
+    assert_eq!(
+        Duration::<i64>::parse_with_suffix("1w"),
+        Ok(Seconds::new(604_800))
+    );
+    assert_eq!(
+        Duration::<i64, Nano>::parse_with_suffix("2µs"),
+        Ok(Duration::new(2_000))
+    );
+    assert_eq!(
+        Duration::<i64>::parse_with_suffix("1500ms"),
+        Err(DurationSuffixParsingError::CannotRepresentDecimalNumber(
+            CannotRepresentDecimalNumber {
+                number: DecimalNumber {
+                    integer: 1500,
+                    fraction: 0,
+                    fractional_digits: 0
+                }
+            }
+        ))
+    );
+    assert_eq!(
+        Duration::<i64>::parse_with_suffix("12"),
+        Err(DurationSuffixParsingError::MissingUnitSuffix)
+    );
+    assert_eq!(
+        Duration::<i64>::parse_with_suffix("12 s"),
+        Err(DurationSuffixParsingError::UnknownUnitSuffix)
+    );
+    assert_eq!(
+        Duration::<i64>::parse_with_suffix("12y"),
+        Err(DurationSuffixParsingError::UnknownUnitSuffix)
+    );
+    assert!(matches!(
+        Duration::<u64>::parse_with_suffix("-1s"),
+        Err(DurationSuffixParsingError::CannotRepresentDecimalNumber(_))
+    ));
 }
@@ -2,7 +2,10 @@
 
 use core::str::FromStr;
 
-use crate::{GregorianDate, Month, errors::GregorianDateParsingError};
+use crate::{
+    GregorianDate, Month,
+    errors::{GregorianDateParsingError, OrdinalDateParsingError},
+};
 
 impl FromStr for GregorianDate {
     type Err = GregorianDateParsingError;
@@ -65,6 +68,34 @@ impl GregorianDate {
     }
 }
 
+impl GregorianDate {
+    /// Parses a `GregorianDate` from an ISO 8601 extended ordinal date (see section 5.2.3.1), like
+    /// "2024-015": a year, followed by a hyphen and a three-digit day-of-year. As for calendar
+    /// dates, any number of digits is accepted for the years term. The day-of-year must lie within
+    /// the year: day 366 is only accepted in leap years.
+    pub fn from_ordinal_str(mut string: &str) -> Result<Self, OrdinalDateParsingError> {
+        let (year, consumed_bytes) = lexical_core::parse_partial(string.as_bytes())?;
+        string = string.get(consumed_bytes..).unwrap();
+
+        if string.starts_with('-') {
+            string = string.get(1..).unwrap();
+        } else {
+            return Err(OrdinalDateParsingError::ExpectedYearDayDelimiter);
+        }
+
+        let (day_of_year, consumed_bytes) = lexical_core::parse_partial(string.as_bytes())?;
+        if consumed_bytes != 3 {
+            return Err(OrdinalDateParsingError::DayOfYearRepresentationNotThreeDigits);
+        }
+        string = string.get(consumed_bytes..).unwrap();
+        if !string.is_empty() {
+            return Err(OrdinalDateParsingError::UnexpectedRemainder);
+        }
+
+        Ok(GregorianDate::from_ordinal_date(year, day_of_year)?)
+    }
+}
+
 /// Tests whether a given string parses to the same Gregorian date as the passed year, month, and
 /// day arguments.
 #[cfg(test)]
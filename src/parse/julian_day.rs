@@ -0,0 +1,67 @@
+//! Implementation of string parsing logic for `JulianDay` types.
+
+use core::str::FromStr;
+
+use crate::{
+    Fraction, JulianDay, TryFromExact, TryMul, UnitRatio, errors::JulianDayParsingError,
+    parse::DecimalNumber, units::SecondsPerDay,
+};
+
+impl<Representation, Period> FromStr for JulianDay<Representation, Period>
+where
+    Representation: TryFromExact<i64> + TryMul<Fraction, Output = Representation>,
+    Period: UnitRatio,
+{
+    type Err = JulianDayParsingError;
+
+    /// Parses a `JulianDay` from a plain decimal number of days, like "2451545.0". The result is
+    /// stored in the `Period` of the Julian day type: if the number cannot be represented exactly
+    /// in that period, an error is returned.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (number, remainder) = DecimalNumber::parse_partial(string)?;
+        if !remainder.is_empty() {
+            return Err(JulianDayParsingError::UnexpectedRemainder);
+        }
+        let time_since_epoch = number.convert_period::<SecondsPerDay, Period, Representation>()?;
+        Ok(Self::from_time_since_epoch(time_since_epoch))
+    }
+}
+
+/// Verifies that Julian days survive a round trip through their string representation.
+#[cfg(feature = "std")]
+#[test]
+fn round_trip() {
+    use crate::{Days, HalfDays};
+
+    let jd = JulianDay::<f64>::from_str("2451545.0").unwrap();
+    assert_eq!(jd.time_since_epoch(), HalfDays::new(4903090.));
+    assert_eq!(jd.to_string(), "2451545");
+    assert_eq!(format!("{jd:.1}"), "2451545.0");
+    assert_eq!(JulianDay::<f64>::from_str(&jd.to_string()).unwrap(), jd);
+
+    let jd = JulianDay::<i64>::from_str("-1234.5").unwrap();
+    assert_eq!(jd.time_since_epoch(), HalfDays::new(-2469));
+    assert_eq!(jd.to_string(), "-1234.5");
+    assert_eq!(JulianDay::<i64>::from_str(&jd.to_string()).unwrap(), jd);
+
+    let jd = JulianDay::<i64>::from_str("-0.5").unwrap();
+    assert_eq!(jd.time_since_epoch(), HalfDays::new(-1));
+    assert_eq!(jd.to_string(), "-0.5");
+
+    let jd = JulianDay::<i64, SecondsPerDay>::from_str("2451545").unwrap();
+    assert_eq!(jd.time_since_epoch(), Days::new(2451545));
+    assert_eq!(format!("{jd:.3}"), "2451545.000");
+}
+
+/// Verifies that numbers that cannot be stored exactly in the Julian day period are rejected.
+#[test]
+fn invalid_julian_days() {
+    assert!(matches!(
+        JulianDay::<i64>::from_str("2451545.25"),
+        Err(JulianDayParsingError::CannotRepresentDecimalNumber(_))
+    ));
+    assert_eq!(
+        JulianDay::<i64>::from_str("2451545.0 TT"),
+        Err(JulianDayParsingError::UnexpectedRemainder)
+    );
+}
@@ -7,9 +7,13 @@ mod duration;
 mod gregorian_date;
 mod historic_date;
 mod julian_date;
+mod julian_day;
+mod modified_julian_date;
+mod month;
 pub use duration::{DurationComponent, DurationDesignator};
 mod decimal;
 pub(crate) use decimal::DecimalNumber;
 mod time_of_day;
 pub(crate) use time_of_day::TimeOfDay;
 mod time_point;
+mod week_day;
@@ -0,0 +1,52 @@
+//! Implementation of string parsing logic for `ModifiedJulianDate` types.
+
+use core::str::FromStr;
+
+use crate::{
+    Fraction, ModifiedJulianDate, TryFromExact, TryMul, UnitRatio,
+    errors::ModifiedJulianDateParsingError, parse::DecimalNumber, units::SecondsPerDay,
+};
+
+impl<Representation, Period> FromStr for ModifiedJulianDate<Representation, Period>
+where
+    Representation: TryFromExact<i64> + TryMul<Fraction, Output = Representation>,
+    Period: UnitRatio,
+{
+    type Err = ModifiedJulianDateParsingError;
+
+    /// Parses a `ModifiedJulianDate` from a plain decimal number of days, like "51544.5". The
+    /// result is stored in the `Period` of the modified Julian date type: if the number cannot be
+    /// represented exactly in that period, an error is returned.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (number, remainder) = DecimalNumber::parse_partial(string)?;
+        if !remainder.is_empty() {
+            return Err(ModifiedJulianDateParsingError::UnexpectedRemainder);
+        }
+        let time_since_epoch = number.convert_period::<SecondsPerDay, Period, Representation>()?;
+        Ok(Self::from_time_since_epoch(time_since_epoch))
+    }
+}
+
+/// Verifies that modified Julian dates survive a round trip through their string representation.
+#[cfg(feature = "std")]
+#[test]
+fn round_trip() {
+    use crate::{Days, HalfDays, units::SecondsPerHalfDay};
+
+    let mjd = ModifiedJulianDate::<f64>::from_str("51544.5").unwrap();
+    assert_eq!(mjd.time_since_epoch(), Days::new(51544.5));
+    assert_eq!(mjd.to_string(), "51544.5");
+    assert_eq!(format!("{mjd:.3}"), "51544.500");
+    assert_eq!(
+        ModifiedJulianDate::<f64>::from_str(&mjd.to_string()).unwrap(),
+        mjd
+    );
+
+    let mjd = ModifiedJulianDate::<i64, SecondsPerHalfDay>::from_str("-100.5").unwrap();
+    assert_eq!(mjd.time_since_epoch(), HalfDays::new(-201));
+    assert_eq!(mjd.to_string(), "-100.5");
+    assert_eq!(
+        ModifiedJulianDate::<i64, SecondsPerHalfDay>::from_str(&mjd.to_string()).unwrap(),
+        mjd
+    );
+}
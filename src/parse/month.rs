@@ -0,0 +1,89 @@
+//! Implementation of string parsing logic for `Month` names.
+
+use core::str::FromStr;
+
+use crate::{Month, errors::MonthParsingError};
+
+impl FromStr for Month {
+    type Err = MonthParsingError;
+
+    /// Parses a `Month` from its English name (e.g., "January") or its three-letter abbreviation
+    /// (e.g., "Jan"). Matching is case-insensitive.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        const NAMES: [&str; 12] = [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ];
+        let index = NAMES
+            .iter()
+            .position(|name| matches_name(string, name))
+            .ok_or(MonthParsingError)?;
+        Ok(Month::try_from(index as u8 + 1).unwrap())
+    }
+}
+
+/// Returns whether `string` equals `name` or its three-letter abbreviation, ignoring case.
+pub(super) fn matches_name(string: &str, name: &str) -> bool {
+    string.eq_ignore_ascii_case(name)
+        || (string.len() == 3 && string.eq_ignore_ascii_case(&name[..3]))
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Month {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Month {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        Self::from_str(&string).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Verifies that months are parsed from their full names and abbreviations, regardless of case,
+/// and that other strings are rejected.
+#[test]
+fn month_names() {
+    assert_eq!("January".parse(), Ok(Month::January));
+    assert_eq!("jan".parse(), Ok(Month::January));
+    assert_eq!("SEPTEMBER".parse(), Ok(Month::September));
+    assert_eq!("Sep".parse(), Ok(Month::September));
+    assert_eq!("may".parse(), Ok(Month::May));
+    assert_eq!("Dec".parse(), Ok(Month::December));
+    assert_eq!("".parse::<Month>(), Err(MonthParsingError));
+    assert_eq!("Ja".parse::<Month>(), Err(MonthParsingError));
+    assert_eq!("Janu".parse::<Month>(), Err(MonthParsingError));
+    assert_eq!("Januaryy".parse::<Month>(), Err(MonthParsingError));
+    assert_eq!(" January".parse::<Month>(), Err(MonthParsingError));
+    assert_eq!("1".parse::<Month>(), Err(MonthParsingError));
+    assert_eq!("Dé".parse::<Month>(), Err(MonthParsingError));
+}
+
+/// Verifies that the displayed names of all months are parsed back into the same value.
+#[cfg(feature = "std")]
+#[test]
+fn month_name_roundtrip() {
+    for number in 1..=12 {
+        let month = Month::try_from(number).unwrap();
+        assert_eq!(month.to_string().parse(), Ok(month));
+    }
+}
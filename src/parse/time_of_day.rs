@@ -2,8 +2,10 @@
 //! combinations of hour, minute, and second.
 
 use crate::{
-    errors::{NumberParsingError, TimeOfDayParsingError},
+    Duration, Fraction, TryFromExact, TryMul, UnitRatio,
+    errors::{InvalidTimeOfDay, NumberParsingError, TimeOfDayParsingError},
     parse::DecimalNumber,
+    units::Second,
 };
 
 /// Wrapper for a time-of-day, as used primarily for parsing. Explicitly used only for parsing
@@ -88,3 +90,74 @@ impl TimeOfDay {
         ))
     }
 }
+
+impl<Representation, Period> Duration<Representation, Period>
+where
+    Period: UnitRatio,
+    Representation: TryFromExact<i64> + TryMul<Fraction, Output = Representation>,
+{
+    /// Parses a standalone time-of-day of the form "HH:MM:SS" or "HH:MM:SS.sss" into the time
+    /// elapsed since midnight. This is useful when the date and time-of-day are given in separate
+    /// fields. Since no time scale is associated with the time-of-day, leap seconds (a seconds
+    /// value of 60) are accepted in any minute: it is up to the caller to combine the result with
+    /// a date in a scale where that leap second exists.
+    ///
+    /// Fails if the time-of-day is malformed or out of range, or if it cannot be expressed
+    /// exactly in the `Representation` and `Period` of the resulting `Duration`.
+    pub fn parse_time_of_day(string: &str) -> Result<Self, TimeOfDayParsingError> {
+        let (time_of_day, remainder) = TimeOfDay::parse_partial(string)?;
+        if !remainder.is_empty() {
+            return Err(TimeOfDayParsingError::UnexpectedRemainder);
+        }
+        let TimeOfDay {
+            hour,
+            minute,
+            second,
+            subseconds,
+        } = time_of_day;
+        if hour >= 24 || minute >= 60 || second > 60 {
+            return Err(InvalidTimeOfDay {
+                hour,
+                minute,
+                second,
+            }
+            .into());
+        }
+        let seconds_since_midnight = DecimalNumber {
+            integer: i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second),
+            ..subseconds
+        };
+        Ok(seconds_since_midnight.convert_period::<Second, Period, Representation>()?)
+    }
+}
+
+/// Verifies that standalone times-of-day are parsed into the time elapsed since midnight,
+/// including leap seconds and fractional seconds.
+#[test]
+fn parse_time_of_day() {
+    use crate::{MilliSeconds, Seconds};
+    assert_eq!(
+        Seconds::<i64>::parse_time_of_day("12:34:56"),
+        Ok(Seconds::new(45_296))
+    );
+    assert_eq!(
+        MilliSeconds::<i64>::parse_time_of_day("23:59:60.25"),
+        Ok(MilliSeconds::new(86_400_250))
+    );
+    assert!(matches!(
+        Seconds::<i64>::parse_time_of_day("23:59:60.25"),
+        Err(TimeOfDayParsingError::CannotRepresentDecimalNumber(_))
+    ));
+    assert_eq!(
+        Seconds::<i64>::parse_time_of_day("24:00:00"),
+        Err(TimeOfDayParsingError::InvalidTimeOfDay(InvalidTimeOfDay {
+            hour: 24,
+            minute: 0,
+            second: 0
+        }))
+    );
+    assert_eq!(
+        Seconds::<i64>::parse_time_of_day("12:34:56Z"),
+        Err(TimeOfDayParsingError::UnexpectedRemainder)
+    );
+}
@@ -3,8 +3,9 @@
 use core::str::FromStr;
 
 use crate::{
-    Fraction, FromFineDateTime, HistoricDate, TimePoint, TryFromExact, TryMul, UnitRatio,
-    errors::TimePointParsingError, parse::TimeOfDay, time_scale::TimeScale, units::Second,
+    Fraction, FromFineDateTime, HistoricDate, TimePoint, TimeScaleId, TryFromExact, TryMul,
+    UnitRatio, errors::TimePointParsingError, parse::TimeOfDay, time_scale::TimeScale,
+    units::Second,
 };
 
 impl<Scale, Representation, Period> FromStr for TimePoint<Scale, Representation, Period>
@@ -42,6 +43,17 @@ where
             return Err(TimePointParsingError::ExpectedSpace);
         }
 
+        // A designator of some other known time scale is reported as such, rather than as a
+        // missing designator: this helps to catch time points parsed into the wrong scale.
+        if let Ok(found) = string.parse::<TimeScaleId>()
+            && found.abbreviation() != Scale::ABBREVIATION
+        {
+            return Err(TimePointParsingError::TimeScaleMismatch {
+                expected: Scale::ABBREVIATION,
+                found,
+            });
+        }
+
         if string.starts_with(Scale::ABBREVIATION) {
             string = string.get(Scale::ABBREVIATION.len()..).unwrap();
         } else {
@@ -103,6 +115,38 @@ where
     }
 }
 
+/// Verifies that the time scale designator of a parsed time point must match the time scale into
+/// which it is parsed.
+#[test]
+fn time_scale_designator() {
+    use crate::{GpsTime, Month, TaiTime, UtcTime};
+    let string = "2025-01-15T10:00:00 GPST";
+    let expected = GpsTime::from_gregorian_datetime(2025, Month::January, 15, 10, 0, 0).unwrap();
+    assert_eq!(GpsTime::from_str(string), Ok(expected));
+    assert_eq!(
+        TaiTime::<i64>::from_str(string),
+        Err(TimePointParsingError::TimeScaleMismatch {
+            expected: "TAI",
+            found: TimeScaleId::Gpst
+        })
+    );
+    assert!(matches!(
+        UtcTime::<i64>::from_str("2025-01-15T10:00:00 TAI"),
+        Err(TimePointParsingError::TimeScaleMismatch {
+            expected: "UTC",
+            found: TimeScaleId::Tai
+        })
+    ));
+    assert_eq!(
+        TaiTime::<i64>::from_str("2025-01-15T10:00:00 XYZ"),
+        Err(TimePointParsingError::ExpectedTimeScaleDesignator)
+    );
+    assert_eq!(
+        TaiTime::<i64>::from_str("2025-01-15T10:00:00 TAIX"),
+        Err(TimePointParsingError::UnexpectedRemainder)
+    );
+}
+
 #[cfg(test)]
 #[allow(clippy::too_many_arguments)]
 fn check_historic_datetime(
@@ -0,0 +1,75 @@
+//! Implementation of string parsing logic for `WeekDay` names.
+
+use core::str::FromStr;
+
+use crate::{WeekDay, errors::WeekDayParsingError, parse::month::matches_name};
+
+impl FromStr for WeekDay {
+    type Err = WeekDayParsingError;
+
+    /// Parses a `WeekDay` from its English name (e.g., "Monday") or its three-letter abbreviation
+    /// (e.g., "Mon"). Matching is case-insensitive.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        const NAMES: [&str; 7] = [
+            "Sunday",
+            "Monday",
+            "Tuesday",
+            "Wednesday",
+            "Thursday",
+            "Friday",
+            "Saturday",
+        ];
+        let index = NAMES
+            .iter()
+            .position(|name| matches_name(string, name))
+            .ok_or(WeekDayParsingError)?;
+        Ok(WeekDay::try_from(index as u8).unwrap())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for WeekDay {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for WeekDay {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let string = String::deserialize(deserializer)?;
+        Self::from_str(&string).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Verifies that days of the week are parsed from their full names and abbreviations, regardless
+/// of case, and that other strings are rejected.
+#[test]
+fn week_day_names() {
+    assert_eq!("Monday".parse(), Ok(WeekDay::Monday));
+    assert_eq!("mon".parse(), Ok(WeekDay::Monday));
+    assert_eq!("WEDNESDAY".parse(), Ok(WeekDay::Wednesday));
+    assert_eq!("Thu".parse(), Ok(WeekDay::Thursday));
+    assert_eq!("sunday".parse(), Ok(WeekDay::Sunday));
+    assert_eq!("".parse::<WeekDay>(), Err(WeekDayParsingError));
+    assert_eq!("Mo".parse::<WeekDay>(), Err(WeekDayParsingError));
+    assert_eq!("Mond".parse::<WeekDay>(), Err(WeekDayParsingError));
+    assert_eq!("Funday".parse::<WeekDay>(), Err(WeekDayParsingError));
+    assert_eq!("0".parse::<WeekDay>(), Err(WeekDayParsingError));
+}
+
+/// Verifies that the displayed names of all days of the week are parsed back into the same value.
+#[cfg(feature = "std")]
+#[test]
+fn week_day_name_roundtrip() {
+    for number in 0..7 {
+        let week_day = WeekDay::try_from(number).unwrap();
+        assert_eq!(week_day.to_string().parse(), Ok(week_day));
+    }
+}
@@ -0,0 +1,78 @@
+//! Implementation of a simple stopwatch, which may be used to measure elapsed (wall clock) time
+//! in terms of `Duration`s, for example while benchmarking.
+
+use crate::NanoSeconds;
+
+/// Measures the elapsed time since it was started, based on the monotonic clock of the operating
+/// system. Elapsed times are returned as `NanoSeconds<u64>`, such that they interoperate directly
+/// with the rest of this library.
+///
+/// Since the monotonic clock is not associated with any particular time scale, the stopwatch can
+/// only be used to measure durations: not to obtain time points.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Stopwatch {
+    start: std::time::Instant,
+    last_lap: std::time::Instant,
+}
+
+impl Stopwatch {
+    /// Starts a new stopwatch at the current instant.
+    pub fn start() -> Self {
+        let now = std::time::Instant::now();
+        Self {
+            start: now,
+            last_lap: now,
+        }
+    }
+
+    /// Returns the time elapsed since this stopwatch was started (or last reset).
+    pub fn elapsed(&self) -> NanoSeconds<u64> {
+        Self::nanoseconds_between(self.start, std::time::Instant::now())
+    }
+
+    /// Returns the time elapsed since the previous lap (or since the stopwatch was started, if no
+    /// lap was recorded yet), and starts a new lap.
+    pub fn lap(&mut self) -> NanoSeconds<u64> {
+        let now = std::time::Instant::now();
+        let lap_time = Self::nanoseconds_between(self.last_lap, now);
+        self.last_lap = now;
+        lap_time
+    }
+
+    /// Restarts this stopwatch at the current instant, discarding any recorded laps.
+    pub fn reset(&mut self) {
+        *self = Self::start();
+    }
+
+    /// Returns the number of nanoseconds elapsed between two instants. Saturates at `u64::MAX`
+    /// nanoseconds, which corresponds with over 584 years.
+    fn nanoseconds_between(
+        earlier: std::time::Instant,
+        later: std::time::Instant,
+    ) -> NanoSeconds<u64> {
+        let nanoseconds = later.saturating_duration_since(earlier).as_nanos();
+        NanoSeconds::new(u64::try_from(nanoseconds).unwrap_or(u64::MAX))
+    }
+}
+
+/// Verifies that the elapsed time never decreases, and that laps add up to at most the total
+/// elapsed time.
+#[test]
+fn monotonic_laps() {
+    let mut stopwatch = Stopwatch::start();
+    let mut previous_elapsed = stopwatch.elapsed();
+    let mut total_lap_time = NanoSeconds::new(0u64);
+    for _ in 0..100 {
+        std::thread::sleep(std::time::Duration::from_micros(10));
+        let lap_time = stopwatch.lap();
+        assert!(lap_time > NanoSeconds::new(0));
+        total_lap_time += lap_time;
+        let elapsed = stopwatch.elapsed();
+        assert!(elapsed >= previous_elapsed);
+        assert!(elapsed >= total_lap_time);
+        previous_elapsed = elapsed;
+    }
+
+    stopwatch.reset();
+    assert!(stopwatch.elapsed() < previous_elapsed);
+}
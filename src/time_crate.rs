@@ -0,0 +1,132 @@
+//! Conversions between `UtcTime` and the `OffsetDateTime` type of the `time` crate. These are only
+//! available when the `time` feature is enabled.
+//!
+//! The `time` crate does not model leap seconds. Rather than silently mapping a leap second onto
+//! the following (or preceding) second, which would make the conversion non-injective, converting
+//! a `UtcTime` that falls within a leap second into an `OffsetDateTime` returns an error.
+
+use crate::{
+    Duration, GregorianDate, IntoFineDateTime, Month, TryIntoExact, UtcTime,
+    errors::TimeCrateConversionError, units::Nano,
+};
+
+impl<Representation> TryFrom<UtcTime<Representation, Nano>> for time::OffsetDateTime
+where
+    UtcTime<Representation, Nano>: IntoFineDateTime<Representation, Nano>,
+    Representation: Copy + TryIntoExact<i64>,
+{
+    type Error = TimeCrateConversionError;
+
+    /// Converts a `UtcTime` into a `time::OffsetDateTime` with UTC offset. Returns an error if the
+    /// time point falls within a leap second, or if it lies outside of the range supported by the
+    /// `time` crate.
+    fn try_from(utc_time: UtcTime<Representation, Nano>) -> Result<Self, Self::Error> {
+        let (date, hour, minute, second, subseconds) = utc_time.into_fine_datetime();
+        if second == 60 {
+            return Err(TimeCrateConversionError::LeapSecond);
+        }
+        let date = GregorianDate::from_date(date);
+        let month = time::Month::try_from(date.month() as u8)
+            .map_err(|_| TimeCrateConversionError::OutOfRange)?;
+        let date = time::Date::from_calendar_date(date.year(), month, date.day())
+            .map_err(|_| TimeCrateConversionError::OutOfRange)?;
+        let time = time::Time::from_hms(hour, minute, second)
+            .map_err(|_| TimeCrateConversionError::OutOfRange)?;
+        let subseconds: i64 = subseconds
+            .count()
+            .try_into_exact()
+            .map_err(|_| TimeCrateConversionError::OutOfRange)?;
+        time::OffsetDateTime::new_utc(date, time)
+            .checked_add(time::Duration::nanoseconds(subseconds))
+            .ok_or(TimeCrateConversionError::OutOfRange)
+    }
+}
+
+impl<Representation> TryFrom<time::OffsetDateTime> for UtcTime<Representation, Nano>
+where
+    UtcTime<i64, Nano>: TryIntoExact<UtcTime<Representation, Nano>>,
+{
+    type Error = TimeCrateConversionError;
+
+    /// Converts a `time::OffsetDateTime` into a `UtcTime`. The date-time is first normalized to
+    /// UTC. Since the `time` crate cannot represent leap seconds, neither will the result of this
+    /// conversion. Returns an error if the result cannot be expressed in `Representation`.
+    fn try_from(date_time: time::OffsetDateTime) -> Result<Self, Self::Error> {
+        let date_time = date_time
+            .checked_to_offset(time::UtcOffset::UTC)
+            .ok_or(TimeCrateConversionError::OutOfRange)?;
+        let month = Month::try_from(date_time.month() as u8)
+            .map_err(|_| TimeCrateConversionError::OutOfRange)?;
+        let utc_time = UtcTime::<i64, Nano>::from_fine_gregorian_datetime(
+            date_time.year(),
+            month,
+            date_time.day(),
+            date_time.hour(),
+            date_time.minute(),
+            date_time.second(),
+            Duration::new(date_time.nanosecond().into()),
+        )
+        .map_err(|_| TimeCrateConversionError::OutOfRange)?;
+        utc_time
+            .try_into_exact()
+            .map_err(|_| TimeCrateConversionError::OutOfRange)
+    }
+}
+
+/// Verifies that a regular instant round-trips through `time::OffsetDateTime`.
+#[test]
+fn round_trip() {
+    use crate::NanoSeconds;
+    let utc_time = UtcTime::<i64, Nano>::from_fine_gregorian_datetime(
+        2024,
+        Month::March,
+        1,
+        12,
+        34,
+        56,
+        NanoSeconds::new(789_012_345),
+    )
+    .unwrap();
+    let offset_date_time = time::OffsetDateTime::try_from(utc_time).unwrap();
+    let expected = time::OffsetDateTime::new_utc(
+        time::Date::from_calendar_date(2024, time::Month::March, 1).unwrap(),
+        time::Time::from_hms_nano(12, 34, 56, 789_012_345).unwrap(),
+    );
+    assert_eq!(offset_date_time, expected);
+    assert_eq!(UtcTime::try_from(offset_date_time), Ok(utc_time));
+
+    // Non-UTC offsets are normalized.
+    let offset = offset_date_time.to_offset(time::UtcOffset::from_hms(2, 0, 0).unwrap());
+    assert_eq!(UtcTime::try_from(offset), Ok(utc_time));
+}
+
+/// Verifies that leap seconds are rejected, while the seconds surrounding them convert normally.
+#[test]
+fn leap_seconds() {
+    use crate::NanoSeconds;
+    let leap_second = UtcTime::<i64, Nano>::from_fine_gregorian_datetime(
+        2016,
+        Month::December,
+        31,
+        23,
+        59,
+        60,
+        NanoSeconds::new(500_000_000),
+    )
+    .unwrap();
+    assert_eq!(
+        time::OffsetDateTime::try_from(leap_second),
+        Err(TimeCrateConversionError::LeapSecond)
+    );
+
+    let before = leap_second - NanoSeconds::new(1_000_000_000);
+    let after = leap_second + NanoSeconds::new(1_000_000_000);
+    let before_converted = time::OffsetDateTime::try_from(before).unwrap();
+    let after_converted = time::OffsetDateTime::try_from(after).unwrap();
+    assert_eq!(
+        after_converted - before_converted,
+        time::Duration::seconds(1)
+    );
+    assert_eq!(UtcTime::try_from(before_converted), Ok(before));
+    assert_eq!(UtcTime::try_from(after_converted), Ok(after));
+}
@@ -2,19 +2,30 @@
 //! fundamental timekeeping logic of this library.
 
 use core::{
-    fmt::{Debug, Display},
+    cmp::Ordering,
+    fmt::{Alignment, Debug, Display, Write},
     hash::Hash,
-    ops::{Add, AddAssign, Sub, SubAssign},
+    ops::{Add, AddAssign, Div, Sub, SubAssign},
 };
 
-use num_traits::{Bounded, Zero};
+use num_traits::{
+    AsPrimitive, Bounded, CheckedAdd, CheckedMul, CheckedSub, ConstZero, Float, FromBytes, One,
+    SaturatingAdd, SaturatingSub, ToBytes, WrappingAdd, WrappingSub, Zero,
+};
 
 use crate::{
-    ConvertUnit, Date, Duration, Fraction, FractionalDigits, FromDateTime, FromFineDateTime,
-    GregorianDate, HalfDays, HistoricDate, IntoDateTime, IntoFineDateTime, JulianDate, JulianDay,
-    ModifiedJulianDate, Month, MulCeil, MulFloor, MulRound, TryConvertUnit, TryFromExact,
-    TryIntoExact, UnitRatio,
-    errors::{InvalidGregorianDateTime, InvalidHistoricDateTime, InvalidJulianDateTime},
+    Calendar, ConstBounded, ConvertUnit, CopticDate, Date, Duration, EthiopicDate, Fraction,
+    FractionalDigits, FromDateTime, FromFineDateTime, FromTimeScale, GregorianDate, HalfDays,
+    HebrewDate, HistoricDate, IntoDateTime, IntoFineDateTime, JulianDate, JulianDay,
+    ModifiedJulianDate, Month, MulCeil, MulFloor, MulRound, RoundingMode, TryConvertUnit,
+    TryFromExact, TryIntoExact, UnitRatio, WeekDay,
+    calendar::{CalendarKind, PaddedYear},
+    duration::Days,
+    errors::{
+        ConversionError, InvalidGregorianDateTime, InvalidHistoricDateTime, InvalidJulianDateTime,
+        JulianDateUnsupported,
+    },
+    format::{FormatBuffer, pad},
     time_scale::{AbsoluteTimeScale, TimeScale, UniformDateTimeScale},
     units::{Second, SecondsPerDay, SecondsPerHalfDay},
 };
@@ -27,6 +38,98 @@ pub struct TimePoint<Scale: ?Sized, Representation = i64, Period: ?Sized = Secon
     time_scale: core::marker::PhantomData<Scale>,
 }
 
+impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representation, Period>
+where
+    Representation: ConstZero,
+{
+    /// The zero instant of this time scale: its epoch. Available as constant, such that it may be
+    /// used in `const` and `static` tables of reference instants.
+    pub const EPOCH_POINT: Self = Self::from_time_since_epoch(Duration::ZERO);
+
+    /// Returns the zero instant of this time scale: its epoch.
+    pub const fn epoch() -> Self {
+        Self::EPOCH_POINT
+    }
+}
+
+impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representation, Period>
+where
+    Representation: ConstBounded,
+{
+    /// The earliest instant that is representable by this `TimePoint` type.
+    pub const MIN: Self = Self::from_time_since_epoch(Duration::new(Representation::MIN));
+
+    /// The latest instant that is representable by this `TimePoint` type.
+    pub const MAX: Self = Self::from_time_since_epoch(Duration::new(Representation::MAX));
+}
+
+impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representation, Period>
+where
+    Representation: ToBytes,
+{
+    /// Returns the raw bytes of the time elapsed since the epoch in little-endian byte order. Only
+    /// the representation is stored: time scale and unit must be known when decoding. For
+    /// primitive representations, the result is a fixed-size byte array, which makes it suitable
+    /// for deterministic binary logging on embedded targets, without requiring `serde`.
+    pub fn to_le_bytes(&self) -> Representation::Bytes {
+        self.time_since_epoch.to_le_bytes()
+    }
+
+    /// Returns the raw bytes of the time elapsed since the epoch in big-endian byte order.
+    pub fn to_be_bytes(&self) -> Representation::Bytes {
+        self.time_since_epoch.to_be_bytes()
+    }
+}
+
+impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representation, Period>
+where
+    Representation: FromBytes,
+{
+    /// Reconstructs a `TimePoint` from the raw bytes of its representation, as produced by
+    /// `to_le_bytes`.
+    pub fn from_le_bytes(bytes: &Representation::Bytes) -> Self {
+        Self::from_time_since_epoch(Duration::from_le_bytes(bytes))
+    }
+
+    /// Reconstructs a `TimePoint` from the raw bytes of its representation, as produced by
+    /// `to_be_bytes`.
+    pub fn from_be_bytes(bytes: &Representation::Bytes) -> Self {
+        Self::from_time_since_epoch(Duration::from_be_bytes(bytes))
+    }
+}
+
+impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representation, Period>
+where
+    Representation: Bounded,
+{
+    /// Returns the earliest and latest instants that are representable by this `TimePoint` type,
+    /// as a `(min, max)` pair. Useful to validate inputs before conversion into this type.
+    pub fn representable_range() -> (Self, Self) {
+        (Self::min_value(), Self::max_value())
+    }
+}
+
+impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representation, Period>
+where
+    Representation: Float,
+{
+    /// Constructs a new `TimePoint` from a known time since epoch, but only if it is finite:
+    /// returns `None` for NaN and infinite counts.
+    pub fn from_time_since_epoch_finite(
+        time_since_epoch: Duration<Representation, Period>,
+    ) -> Option<Self> {
+        time_since_epoch
+            .is_finite()
+            .then(|| Self::from_time_since_epoch(time_since_epoch))
+    }
+
+    /// Returns whether this time point is finite: that is, its time since epoch is neither NaN
+    /// nor infinite.
+    pub fn is_finite(&self) -> bool {
+        self.time_since_epoch.is_finite()
+    }
+}
+
 impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representation, Period> {
     /// Constructs a new `TimePoint` from a known time since epoch.
     pub const fn from_time_since_epoch(time_since_epoch: Duration<Representation, Period>) -> Self {
@@ -44,6 +147,18 @@ impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representat
         self.time_since_epoch
     }
 
+    /// Relabels this time point as belonging to the `Target` time scale, without any conversion:
+    /// the time since epoch is kept as-is, and only the time scale type changes.
+    ///
+    /// This is an escape hatch for interoperability code, intended for cases where it is known from
+    /// external context that the count already refers to the epoch of `Target`: for example,
+    /// because the offset between both scales was applied manually. In all other cases, the result
+    /// will silently describe a different instant than `self`: prefer `into_time_scale()`, which
+    /// applies the proper conversion between both scales.
+    pub fn reinterpret_scale<Target: ?Sized>(self) -> TimePoint<Target, Representation, Period> {
+        TimePoint::from_time_since_epoch(self.time_since_epoch)
+    }
+
     /// Returns the raw underlying representation of this time point.
     pub const fn count(&self) -> Representation
     where
@@ -106,6 +221,39 @@ impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representat
         TimePoint::from_time_since_epoch(self.time_since_epoch.floor())
     }
 
+    /// Converts towards a different time unit, rounding in the direction given by `mode` if the
+    /// unit is not entirely commensurate with the present unit. Rounding towards zero rounds
+    /// towards the epoch of the time scale.
+    pub fn into_unit_rounded<Target>(
+        self,
+        mode: RoundingMode,
+    ) -> TimePoint<Scale, Representation, Target>
+    where
+        Representation: MulRound<Fraction, Output = Representation>
+            + MulCeil<Fraction, Output = Representation>
+            + MulFloor<Fraction, Output = Representation>
+            + Zero
+            + PartialOrd,
+        Period: UnitRatio,
+        Target: UnitRatio,
+    {
+        TimePoint::from_time_since_epoch(self.time_since_epoch.into_unit_rounded(mode))
+    }
+
+    /// Snaps this `TimePoint` down to a whole multiple of the (coarser) `NativePeriod`, while
+    /// keeping the current period. Unlike `floor`, which converts into the target unit, this
+    /// zeroes all sub-period parts but retains the representation of `self`. Useful when writing
+    /// time points into fields of a fixed resolution.
+    pub fn align<NativePeriod>(self) -> Self
+    where
+        Representation:
+            MulFloor<Fraction, Output = Representation> + ConvertUnit<NativePeriod, Period>,
+        Period: UnitRatio,
+        NativePeriod: UnitRatio,
+    {
+        self.floor::<NativePeriod>().into_unit()
+    }
+
     /// Infallibly converts towards a different representation.
     pub fn cast<Target>(self) -> TimePoint<Scale, Target, Period>
     where
@@ -126,6 +274,504 @@ impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representat
             self.time_since_epoch.try_cast()?,
         ))
     }
+
+    /// Converts towards a narrower representation, clamping the time since the epoch to the range
+    /// of `Target` instead of failing when it does not fit. Useful where a bounded (if lossy)
+    /// result is preferable over an error, as for display or telemetry.
+    pub fn saturating_cast<Target>(self) -> TimePoint<Scale, Target, Period>
+    where
+        Representation: Copy + PartialOrd + AsPrimitive<Target>,
+        Target: Copy + Bounded + Into<Representation> + 'static,
+    {
+        TimePoint::from_time_since_epoch(self.time_since_epoch.saturating_cast())
+    }
+
+    /// Converts towards a different representation and time unit in one go. The representation
+    /// is converted first, such that conversions towards wider types (e.g., `i32` seconds to `i64`
+    /// nanoseconds) do not overflow in the original representation. Returns an error if either
+    /// step cannot be performed exactly.
+    pub fn convert<TargetRepresentation, TargetPeriod>(
+        self,
+    ) -> Result<
+        TimePoint<Scale, TargetRepresentation, TargetPeriod>,
+        ConversionError<<Representation as TryIntoExact<TargetRepresentation>>::Error>,
+    >
+    where
+        Representation: TryIntoExact<TargetRepresentation>,
+        TargetRepresentation: TryConvertUnit<Period, TargetPeriod>,
+    {
+        self.try_cast()
+            .map_err(ConversionError::CastError)?
+            .try_into_unit()
+            .ok_or(ConversionError::InexactUnitConversion)
+    }
+
+    /// Returns the instant halfway between two time points. For integer representations, the
+    /// result is rounded towards negative infinity (the earlier of the two candidates), regardless
+    /// of the order in which both time points are passed.
+    ///
+    /// The midpoint is computed as `a + (b - a) / 2` rather than `(a + b) / 2`, such that no
+    /// intermediate overflow occurs for time points that lie close together near the edges of the
+    /// representable range. The difference between both time points must still be representable.
+    pub fn midpoint(a: Self, b: Self) -> Self
+    where
+        Representation: Copy
+            + PartialOrd
+            + One
+            + Add<Representation, Output = Representation>
+            + Sub<Representation, Output = Representation>
+            + Div<Representation, Output = Representation>,
+    {
+        let (earlier, later) = if a.time_since_epoch <= b.time_since_epoch {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        let two = Representation::one() + Representation::one();
+        let half_difference = (later.time_since_epoch - earlier.time_since_epoch) / two;
+        Self::from_time_since_epoch(earlier.time_since_epoch + half_difference)
+    }
+
+    /// Returns whether this time point happens strictly before some other time point, which may be
+    /// expressed in a different time scale. The other time point is first converted into the time
+    /// scale of `self`, such that the resulting ordering is physically meaningful: comparing the
+    /// raw time-since-epoch counts of time points in different scales generally is not.
+    pub fn happens_before<Other>(&self, other: &TimePoint<Other, Representation, Period>) -> bool
+    where
+        Representation: Copy + PartialOrd,
+        Period: Sized,
+        Self: FromTimeScale<Other, Representation, Period>,
+    {
+        let other = Self::from_time_scale(*other);
+        self.time_since_epoch < other.time_since_epoch
+    }
+
+    /// Converts this time point into the `Other` time scale and back, and returns the residual:
+    /// the round-tripped time point minus `self`. This quantifies the loss of a conversion chain,
+    /// which is zero for exact conversions but need not be for conversions between scales with
+    /// differing rates or with leap seconds, especially when using floating point representations.
+    pub fn round_trip_error<Other>(self) -> Duration<Representation, Period>
+    where
+        Representation: Copy + Sub<Representation, Output = Representation>,
+        Scale: Sized,
+        Period: Sized,
+        Self: FromTimeScale<Other, Representation, Period>,
+        TimePoint<Other, Representation, Period>: FromTimeScale<Scale, Representation, Period>,
+    {
+        let other = TimePoint::<Other, Representation, Period>::from_time_scale(self);
+        Self::from_time_scale(other).time_since_epoch - self.time_since_epoch
+    }
+
+    /// Compares this time point against one with a different representation, like an `i32` and
+    /// an `i64` count of seconds. The other count is first converted into the representation of
+    /// `self`; if that is not possible without loss, `self` is converted into the representation
+    /// of `other` instead. Returns `None` if neither conversion is exact, or if the counts are not
+    /// comparable (e.g., because one of them is NaN).
+    pub fn cmp_widened<R2>(&self, other: &TimePoint<Scale, R2, Period>) -> Option<Ordering>
+    where
+        Representation: Copy + PartialOrd + TryFromExact<R2>,
+        R2: Copy + PartialOrd + TryFromExact<Representation>,
+    {
+        if let Ok(other) = Representation::try_from_exact(other.count()) {
+            self.count().partial_cmp(&other)
+        } else if let Ok(this) = R2::try_from_exact(self.count()) {
+            this.partial_cmp(&other.count())
+        } else {
+            None
+        }
+    }
+
+    /// Computes the difference between two time points, like `self - rhs`, but widens both counts
+    /// to `i64` before subtracting. For representations of 32 bits or less, the resulting
+    /// difference can never overflow: this is the safe difference operation for narrow
+    /// representations.
+    pub fn wide_sub(self, rhs: Self) -> Duration<i64, Period>
+    where
+        Representation: Copy + Into<i64>,
+    {
+        Duration::new(self.count().into() - rhs.count().into())
+    }
+
+    /// Computes the signed difference between two time points, like `self - rhs`, but without
+    /// underflowing for unsigned representations when `rhs` is later than `self`. Both counts are
+    /// widened to `i128` before subtracting, so this also applies to `u64` counts, which
+    /// `wide_sub` does not accept.
+    ///
+    /// # Panics
+    /// Panics if the difference does not fit in an `i64`. Use `checked_signed_sub` to handle that
+    /// case instead.
+    pub fn signed_sub(self, rhs: Self) -> Duration<i64, Period>
+    where
+        Representation: Copy + Into<i128>,
+    {
+        self.checked_signed_sub(rhs)
+            .expect("time point difference does not fit in an `i64`")
+    }
+
+    /// Computes the signed difference between two time points, like `signed_sub`, but returns
+    /// `None` if the difference does not fit in an `i64`.
+    pub fn checked_signed_sub(self, rhs: Self) -> Option<Duration<i64, Period>>
+    where
+        Representation: Copy + Into<i128>,
+    {
+        let difference = self.count().into() - rhs.count().into();
+        Some(Duration::new(i64::try_from(difference).ok()?))
+    }
+
+    /// Returns the duration elapsed since some `earlier` time point. If `earlier` is actually
+    /// later than `self`, or if the difference cannot otherwise be represented, returns a zero
+    /// duration instead of underflowing. This mirrors `std::time::Instant::saturating_duration_since`
+    /// and is particularly useful for unsigned representations.
+    pub fn saturating_duration_since(&self, earlier: &Self) -> Duration<Representation, Period>
+    where
+        Representation: Copy + CheckedSub + Zero,
+    {
+        self.time_since_epoch
+            .count()
+            .checked_sub(&earlier.time_since_epoch.count())
+            .map(Duration::new)
+            .unwrap_or_default()
+    }
+
+    /// Returns the time point that lies `n` repetitions of `interval` after this one, i.e.,
+    /// `self + interval * n`. Returns `None` if any step of this computation overflows the
+    /// underlying representation. This is the single-shot counterpart of stepping through a
+    /// range of time points.
+    pub fn nth_after(self, interval: Duration<Representation, Period>, n: u32) -> Option<Self>
+    where
+        Representation: Copy + CheckedAdd + CheckedMul + TryFromExact<u32>,
+    {
+        let n = Representation::try_from_exact(n).ok()?;
+        let offset = interval.checked_mul(n)?;
+        let time_since_epoch = self.time_since_epoch.count().checked_add(&offset.count())?;
+        Some(Self::from_time_since_epoch(Duration::new(time_since_epoch)))
+    }
+
+    /// Returns the index of the cell of the grid `origin + index * step` that contains this time
+    /// point, i.e., the index of the last grid point at or before `self`. Time points before the
+    /// `origin` result in negative indices. This generalizes frame and epoch counters, like a
+    /// frame counter at 40 ms cadence that started at some mission-specific epoch.
+    ///
+    /// Panics if `step` is zero, or if the resulting index cannot be expressed as `i64`. Use
+    /// `checked_quantize` where such inputs may occur.
+    pub fn quantize(self, origin: Self, step: Duration<Representation, Period>) -> i64
+    where
+        Representation: Copy + Into<i128>,
+    {
+        self.checked_quantize(origin, step)
+            .expect("grid step is zero or grid index does not fit in an `i64`")
+    }
+
+    /// Computes the grid index of this time point, like `quantize`, but returns `None` if `step`
+    /// is zero or if the index cannot be expressed as `i64`. The difference with respect to the
+    /// `origin` is computed in `i128`, such that unsigned time points before the origin result in
+    /// negative indices rather than underflowing.
+    pub fn checked_quantize(
+        self,
+        origin: Self,
+        step: Duration<Representation, Period>,
+    ) -> Option<i64>
+    where
+        Representation: Copy + Into<i128>,
+    {
+        let difference = self.count().into().checked_sub(origin.count().into())?;
+        let step: i128 = step.count().into();
+        let mut index = difference.checked_div(step)?;
+        let remainder = difference.checked_rem(step)?;
+        // Integer division rounds towards zero, but grid cells extend forwards from their grid
+        // point, so the index must be rounded towards negative infinity instead.
+        if remainder != 0 && ((remainder < 0) != (step < 0)) {
+            index -= 1;
+        }
+        i64::try_from(index).ok()
+    }
+
+    /// Returns the grid point `origin + index * step`. This is the inverse of `quantize`: for any
+    /// time point on the grid, `dequantize(origin, step, t.quantize(origin, step))` returns `t`.
+    ///
+    /// Panics if `index` cannot be expressed in the underlying representation.
+    pub fn dequantize(origin: Self, step: Duration<Representation, Period>, index: i64) -> Self
+    where
+        Representation: Copy
+            + Add<Output = Representation>
+            + core::ops::Mul<Output = Representation>
+            + TryFromExact<i64>,
+    {
+        let index = Representation::try_from_exact(index).unwrap_or_else(|_| {
+            panic!("Grid index {index} cannot be expressed in the underlying representation")
+        });
+        Self::from_time_since_epoch(Duration::new(origin.count() + step.count() * index))
+    }
+
+    /// Shifts this time point forward by the given `Duration`. Returns `None` if the result is not
+    /// representable.
+    pub fn checked_add(self, rhs: Duration<Representation, Period>) -> Option<Self>
+    where
+        Representation: CheckedAdd,
+    {
+        Some(Self::from_time_since_epoch(
+            self.time_since_epoch.checked_add(rhs)?,
+        ))
+    }
+
+    /// Shifts this time point backward by the given `Duration`. Returns `None` if the result is
+    /// not representable.
+    pub fn checked_sub(self, rhs: Duration<Representation, Period>) -> Option<Self>
+    where
+        Representation: CheckedSub,
+    {
+        Some(Self::from_time_since_epoch(
+            self.time_since_epoch.checked_sub(rhs)?,
+        ))
+    }
+
+    /// Shifts this time point forward by the given `Duration`, clamping the result to the
+    /// representable range instead of overflowing.
+    pub fn saturating_add(self, rhs: Duration<Representation, Period>) -> Self
+    where
+        Representation: SaturatingAdd,
+    {
+        Self::from_time_since_epoch(self.time_since_epoch.saturating_add(rhs))
+    }
+
+    /// Shifts this time point backward by the given `Duration`, clamping the result to the
+    /// representable range instead of overflowing.
+    pub fn saturating_sub(self, rhs: Duration<Representation, Period>) -> Self
+    where
+        Representation: SaturatingSub,
+    {
+        Self::from_time_since_epoch(self.time_since_epoch.saturating_sub(rhs))
+    }
+
+    /// Shifts this time point forward by the given `Duration`, wrapping around at the bounds of
+    /// the representable range. This makes the release-mode behaviour of the `+` operator
+    /// explicit, and consistent across build profiles.
+    pub fn wrapping_add(self, rhs: Duration<Representation, Period>) -> Self
+    where
+        Representation: WrappingAdd,
+    {
+        Self::from_time_since_epoch(self.time_since_epoch.wrapping_add(rhs))
+    }
+
+    /// Shifts this time point backward by the given `Duration`, wrapping around at the bounds of
+    /// the representable range.
+    pub fn wrapping_sub(self, rhs: Duration<Representation, Period>) -> Self
+    where
+        Representation: WrappingSub,
+    {
+        Self::from_time_since_epoch(self.time_since_epoch.wrapping_sub(rhs))
+    }
+
+    /// Shifts this time point forward by a `Duration` of a narrower representation, which is
+    /// widened before the addition: for example, an `i32` duration may be added to an `i64` time
+    /// point. Overflow is handled as for the `+` operator.
+    pub fn add_widened<Narrow>(self, rhs: Duration<Narrow, Period>) -> Self
+    where
+        Representation: Add<Output = Representation>,
+        Narrow: Into<Representation>,
+    {
+        Self::from_time_since_epoch(self.time_since_epoch + rhs.cast())
+    }
+
+    /// Shifts this time point backward by a `Duration` of a narrower representation, which is
+    /// widened before the subtraction. Overflow is handled as for the `-` operator.
+    pub fn sub_widened<Narrow>(self, rhs: Duration<Narrow, Period>) -> Self
+    where
+        Representation: Sub<Output = Representation>,
+        Narrow: Into<Representation>,
+    {
+        Self::from_time_since_epoch(self.time_since_epoch - rhs.cast())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representation, Period>
+where
+    Representation: Copy
+        + Add<Output = Representation>
+        + Sub<Output = Representation>
+        + core::ops::Mul<Output = Representation>
+        + Div<Output = Representation>
+        + TryFromExact<u32>,
+{
+    /// Returns exactly `count` evenly spaced time points from `start` up to and including `stop`.
+    /// For a `count` of one, only `start` is returned; for zero, the result is empty.
+    ///
+    /// For integer representations, the interval between `start` and `stop` generally cannot be
+    /// divided evenly. The rounding error is then distributed evenly over the samples, such that
+    /// both endpoints are still exact. This function panics if `count - 1` cannot be expressed in
+    /// the underlying representation.
+    pub fn sample(start: Self, stop: Self, count: u32) -> Vec<Self> {
+        let Some(intervals) = count.checked_sub(1) else {
+            return Vec::new();
+        };
+        if intervals == 0 {
+            return vec![start];
+        }
+
+        let to_representation = |value: u32| {
+            Representation::try_from_exact(value).unwrap_or_else(|_| {
+                panic!("Sample index {value} cannot be expressed in the underlying representation")
+            })
+        };
+        let span = (stop - start).count();
+        let intervals_repr = to_representation(intervals);
+        let step = span / intervals_repr;
+        let remainder = span - step * intervals_repr;
+        (0..count)
+            .map(|index| {
+                let index = to_representation(index);
+                let offset = step * index + (remainder * index) / intervals_repr;
+                start + Duration::new(offset)
+            })
+            .collect()
+    }
+}
+
+/// Verifies that sampling returns evenly spaced time points with exact endpoints.
+#[cfg(feature = "std")]
+#[test]
+fn sample() {
+    use crate::{MilliSeconds, Seconds, TaiTime, units::Milli};
+    let start = TaiTime::from_time_since_epoch(Seconds::new(0));
+    let stop = TaiTime::from_time_since_epoch(Seconds::new(4));
+    let samples: Vec<_> = TaiTime::sample(start, stop, 5)
+        .into_iter()
+        .map(|time_point| time_point.time_since_epoch().count())
+        .collect();
+    assert_eq!(samples, vec![0, 1, 2, 3, 4]);
+
+    // Uneven divisions distribute the rounding error, but keep both endpoints exact.
+    let start = TaiTime::<i64, Milli>::from_time_since_epoch(MilliSeconds::new(0));
+    let stop = TaiTime::<i64, Milli>::from_time_since_epoch(MilliSeconds::new(10));
+    let samples: Vec<_> = TaiTime::sample(start, stop, 4)
+        .into_iter()
+        .map(|time_point| time_point.time_since_epoch().count())
+        .collect();
+    assert_eq!(samples, vec![0, 3, 6, 10]);
+
+    // Sampling also works backwards in time, and for floats.
+    let samples: Vec<_> = TaiTime::sample(stop, start, 3)
+        .into_iter()
+        .map(|time_point| time_point.time_since_epoch().count())
+        .collect();
+    assert_eq!(samples, vec![10, 5, 0]);
+    let start = TaiTime::from_time_since_epoch(Seconds::new(0.0));
+    let stop = TaiTime::from_time_since_epoch(Seconds::new(1.0));
+    let samples: Vec<_> = TaiTime::sample(start, stop, 5)
+        .into_iter()
+        .map(|time_point| time_point.time_since_epoch().count())
+        .collect();
+    assert_eq!(samples, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+
+    assert_eq!(TaiTime::sample(start, stop, 1), vec![start]);
+    assert!(TaiTime::sample(start, stop, 0).is_empty());
+}
+
+/// Verifies that time points are aligned to whole multiples of a coarser period.
+#[test]
+fn align() {
+    use crate::{
+        NanoSeconds, TaiTime,
+        units::{Milli, Nano},
+    };
+    let time = TaiTime::<i64, Nano>::from_time_since_epoch(NanoSeconds::new(1_234_567_891));
+    assert_eq!(
+        time.align::<Milli>(),
+        TaiTime::from_time_since_epoch(NanoSeconds::new(1_234_000_000))
+    );
+    assert_eq!(
+        time.align::<Second>(),
+        TaiTime::from_time_since_epoch(NanoSeconds::new(1_000_000_000))
+    );
+    assert_eq!(time.align::<Nano>(), time);
+
+    // Alignment rounds towards negative infinity, also before the epoch.
+    let time = TaiTime::<i64, Nano>::from_time_since_epoch(NanoSeconds::new(-1_500_000));
+    assert_eq!(
+        time.align::<Milli>(),
+        TaiTime::from_time_since_epoch(NanoSeconds::new(-2_000_000))
+    );
+}
+
+/// Verifies that repeated intervals are applied correctly, and that overflow is detected.
+#[test]
+fn nth_after() {
+    use crate::{Seconds, TaiTime};
+    let start = TaiTime::from_historic_datetime(2024, Month::January, 1, 0, 0, 0).unwrap();
+    assert_eq!(
+        start.nth_after(Seconds::new(1), 1000),
+        Some(TaiTime::from_historic_datetime(2024, Month::January, 1, 0, 16, 40).unwrap())
+    );
+    assert_eq!(start.nth_after(Seconds::new(1), 0), Some(start));
+    assert_eq!(start.nth_after(Seconds::new(i64::MAX / 2), 3), None);
+    let near_max = TaiTime::from_time_since_epoch(Seconds::new(i64::MAX - 10));
+    assert_eq!(near_max.nth_after(Seconds::new(1), 11), None);
+}
+
+/// Verifies that time points in different time scales are ordered according to the instant that
+/// they represent, rather than their raw counts.
+#[test]
+fn happens_before() {
+    use crate::{TaiTime, UtcTime};
+    let utc = UtcTime::from_historic_datetime(2017, Month::January, 1, 0, 0, 0).unwrap();
+    let tai_before = TaiTime::from_historic_datetime(2017, Month::January, 1, 0, 0, 36).unwrap();
+    let tai_equal = TaiTime::from_historic_datetime(2017, Month::January, 1, 0, 0, 37).unwrap();
+    let tai_after = TaiTime::from_historic_datetime(2017, Month::January, 1, 0, 0, 38).unwrap();
+
+    assert!(tai_before.happens_before(&utc));
+    assert!(!tai_equal.happens_before(&utc));
+    assert!(!tai_after.happens_before(&utc));
+    assert!(!utc.happens_before(&tai_before));
+    assert!(!utc.happens_before(&tai_equal));
+    assert!(utc.happens_before(&tai_after));
+}
+
+/// Verifies that the saturating duration between two time points clamps to zero if the reference
+/// point is later.
+#[test]
+fn saturating_duration_since() {
+    use crate::{NanoSeconds, TaiTime, units::Nano};
+    let earlier = TaiTime::<u64, Nano>::from_time_since_epoch(NanoSeconds::new(1_000));
+    let later = TaiTime::<u64, Nano>::from_time_since_epoch(NanoSeconds::new(1_500));
+    assert_eq!(
+        later.saturating_duration_since(&earlier),
+        NanoSeconds::new(500)
+    );
+    assert_eq!(
+        earlier.saturating_duration_since(&later),
+        NanoSeconds::new(0)
+    );
+    assert_eq!(
+        earlier.saturating_duration_since(&earlier),
+        NanoSeconds::new(0)
+    );
+}
+
+/// Verifies that midpoints of time points near the edge of the representable range do not
+/// overflow, and that they are rounded consistently towards negative infinity.
+#[test]
+fn midpoint() {
+    use crate::{Seconds, TaiTime};
+    let near_max = TaiTime::from_time_since_epoch(Seconds::new(i64::MAX - 3));
+    let max = TaiTime::from_time_since_epoch(Seconds::new(i64::MAX));
+    let expected = TaiTime::from_time_since_epoch(Seconds::new(i64::MAX - 2));
+    assert_eq!(TaiTime::midpoint(near_max, max), expected);
+    assert_eq!(TaiTime::midpoint(max, near_max), expected);
+    assert_eq!(TaiTime::midpoint(max, max), max);
+
+    let a = TaiTime::from_time_since_epoch(Seconds::new(-3i64));
+    let b = TaiTime::from_time_since_epoch(Seconds::new(0i64));
+    let expected = TaiTime::from_time_since_epoch(Seconds::new(-2i64));
+    assert_eq!(TaiTime::midpoint(a, b), expected);
+    assert_eq!(TaiTime::midpoint(b, a), expected);
+
+    let a = TaiTime::from_time_since_epoch(Seconds::new(1.0f64));
+    let b = TaiTime::from_time_since_epoch(Seconds::new(2.0f64));
+    assert_eq!(
+        TaiTime::midpoint(a, b),
+        TaiTime::from_time_since_epoch(Seconds::new(1.5))
+    );
 }
 
 impl<Scale: ?Sized> TimePoint<Scale, i64, Second>
@@ -199,6 +845,36 @@ where
     /// the use of Julian Dates" of the IAU, it is also not recommended to use such Julian date
     /// expressions: hence, we do not support it.
     pub fn from_julian_day(jd: JulianDay<Representation, Period>) -> Self {
+        Self::from_julian_day_unchecked(jd)
+    }
+}
+
+impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representation, Period>
+where
+    Scale: AbsoluteTimeScale,
+    Representation: Copy
+        + Sub<Output = Representation>
+        + TryFromExact<i32>
+        + ConvertUnit<SecondsPerHalfDay, Period>,
+{
+    /// Constructs a time point from a Julian day, like `from_julian_day`, but available for all
+    /// absolute time scales. Non-uniform time scales (such as UTC) result in an error at runtime
+    /// rather than at compile time. This is useful for code that is generic over the time scale.
+    pub fn try_from_julian_day(
+        jd: JulianDay<Representation, Period>,
+    ) -> Result<Self, JulianDateUnsupported> {
+        if Scale::IS_UNIFORM_DATE_TIME {
+            Ok(Self::from_julian_day_unchecked(jd))
+        } else {
+            Err(JulianDateUnsupported {
+                scale: Scale::ABBREVIATION,
+            })
+        }
+    }
+
+    /// Converts a Julian day into a time point without checking whether the time scale is
+    /// uniform with respect to date-times.
+    fn from_julian_day_unchecked(jd: JulianDay<Representation, Period>) -> Self {
         const JULIAN_EPOCH: Date<i32> = match Date::from_julian_date(-4712, Month::January, 1) {
             Ok(epoch) => epoch,
             Err(_) => panic!("Internal error: start of Julian period found invalid"),
@@ -301,6 +977,129 @@ where
     }
 }
 
+/// Verifies that representation and unit may be converted in a single call.
+#[test]
+fn convert() {
+    use crate::{Nano, TaiTime};
+    let time = TaiTime::<i32, Second>::from_time_since_epoch(crate::Seconds::new(i32::MAX));
+    let converted: TaiTime<i64, Nano> = time.convert().unwrap();
+    assert_eq!(
+        converted.time_since_epoch().count(),
+        i32::MAX as i64 * 1_000_000_000
+    );
+    assert_eq!(converted.convert::<i64, Second>(), Ok(time.cast()));
+
+    // Narrowing and coarsening conversions fail if they are not exact.
+    assert!(matches!(
+        converted.convert::<i32, Nano>(),
+        Err(ConversionError::CastError(_))
+    ));
+    let time = TaiTime::<i64, Nano>::from_time_since_epoch(crate::NanoSeconds::new(1));
+    assert_eq!(
+        time.convert::<i64, Second>(),
+        Err(ConversionError::InexactUnitConversion)
+    );
+}
+
+/// Verifies that the epoch of a time scale is available as constant zero instant.
+#[test]
+fn epoch_point() {
+    use crate::{GpsTime, Month, TaiTime};
+    const _: () = assert!(TaiTime::<i64>::EPOCH_POINT.count() == 0);
+    const EPOCHS: [GpsTime<i64, Second>; 2] = [GpsTime::EPOCH_POINT, GpsTime::epoch()];
+    let gps_epoch = GpsTime::from_historic_datetime(1980, Month::January, 6, 0, 0, 0).unwrap();
+    assert_eq!(EPOCHS, [gps_epoch, gps_epoch]);
+    assert_eq!(TaiTime::<f64>::epoch().count(), 0.);
+}
+
+/// Verifies that the representable range of a time point follows from its representation, and
+/// that it spans the expected duration for nanoseconds stored in an `i64`.
+#[test]
+fn representable_range() {
+    use crate::{TaiTime, units::Nano};
+    type Tai = TaiTime<i64, Nano>;
+    const _: () = assert!(Tai::MIN.count() == i64::MIN && Tai::MAX.count() == i64::MAX);
+    assert_eq!(Tai::representable_range(), (Tai::MIN, Tai::MAX));
+    let span = Tai::MAX.cast::<i128>() - Tai::MIN.cast::<i128>();
+    assert_eq!(span, Duration::new(u64::MAX as i128));
+    // Slightly less than 584.6 Julian years.
+    assert_eq!(span.count() / (36_525 * 864 * 1_000_000_000), 584);
+    assert_eq!(TaiTime::<u8>::representable_range().1.count(), u8::MAX);
+}
+
+/// Verifies that time points round-trip through their fixed-size byte representations.
+#[test]
+fn byte_layout() {
+    use crate::{TaiTime, units::BinaryFraction4};
+    let time = TaiTime::<u64, BinaryFraction4>::from_time_since_epoch(Duration::new(
+        0x0123_4567_89ab_cdef,
+    ));
+    let le: [u8; 8] = time.to_le_bytes();
+    let be: [u8; 8] = time.to_be_bytes();
+    assert_eq!(le, [0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01]);
+    assert_eq!(be, [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]);
+    assert_eq!(TaiTime::<u64, BinaryFraction4>::from_le_bytes(&le), time);
+    assert_eq!(TaiTime::<u64, BinaryFraction4>::from_be_bytes(&be), time);
+
+    let time = TaiTime::<i32>::from_time_since_epoch(Duration::new(-2));
+    let le: [u8; 4] = time.to_le_bytes();
+    assert_eq!(le, [0xfe, 0xff, 0xff, 0xff]);
+    assert_eq!(TaiTime::<i32>::from_le_bytes(&le), time);
+    assert_eq!(TaiTime::<i32>::from_be_bytes(&time.to_be_bytes()), time);
+}
+
+/// Verifies that time points may explicitly wrap around or saturate at the bounds of their
+/// representation, rather than relying on the build-dependent behaviour of the operators.
+#[test]
+fn overflowing_arithmetic() {
+    use crate::{Seconds, TaiTime};
+    let one = Seconds::new(1i64);
+    assert_eq!(TaiTime::<i64>::MAX.wrapping_add(one), TaiTime::MIN);
+    assert_eq!(TaiTime::<i64>::MIN.wrapping_sub(one), TaiTime::MAX);
+    assert_eq!(TaiTime::<i64>::MAX.saturating_add(one), TaiTime::MAX);
+    assert_eq!(TaiTime::<i64>::MIN.saturating_sub(one), TaiTime::MIN);
+    assert_eq!(TaiTime::<i64>::MAX.checked_add(one), None);
+    assert_eq!(TaiTime::<i64>::MIN.checked_sub(one), None);
+    assert_eq!(
+        TaiTime::<i64>::epoch().checked_add(one),
+        Some(TaiTime::from_time_since_epoch(one))
+    );
+}
+
+/// Verifies that time points with representations of different widths may be compared directly.
+#[test]
+fn cmp_widened() {
+    use crate::{Seconds, TaiTime};
+    let narrow = TaiTime::from_time_since_epoch(Seconds::new(1_000_000i32));
+    let wide = TaiTime::from_time_since_epoch(Seconds::new(1_000_000i64));
+    assert_eq!(narrow.cmp_widened(&wide), Some(Ordering::Equal));
+    assert_eq!(wide.cmp_widened(&narrow), Some(Ordering::Equal));
+
+    let far = TaiTime::from_time_since_epoch(Seconds::new(i64::MAX));
+    assert_eq!(narrow.cmp_widened(&far), Some(Ordering::Less));
+    assert_eq!(far.cmp_widened(&narrow), Some(Ordering::Greater));
+
+    let unsigned = TaiTime::from_time_since_epoch(Seconds::new(u64::MAX));
+    let negative = TaiTime::from_time_since_epoch(Seconds::new(-1i64));
+    assert_eq!(negative.cmp_widened(&unsigned), None);
+    let float = TaiTime::from_time_since_epoch(Seconds::new(1_000_000.0f64));
+    assert_eq!(float.cmp_widened(&wide), Some(Ordering::Equal));
+}
+
+/// Verifies that Julian days may be converted at runtime for uniform time scales only.
+#[test]
+fn try_from_julian_day() {
+    use crate::{TaiTime, UtcTime};
+    let julian_day = JulianDay::from_historic_date(2000, Month::January, 1).unwrap();
+    let tai = TaiTime::try_from_julian_day(julian_day).unwrap();
+    assert_eq!(tai, TaiTime::from_julian_day(julian_day));
+    assert_eq!(tai.into_julian_day(), julian_day);
+    assert_eq!(
+        UtcTime::try_from_julian_day(julian_day),
+        Err(JulianDateUnsupported { scale: "UTC" })
+    );
+}
+
 #[cfg(test)]
 fn check_julian_date(year: i32, month: Month, day: u8) {
     use crate::TtTime;
@@ -341,6 +1140,30 @@ impl<Scale: ?Sized, Representation> TimePoint<Scale, Representation, Second>
 where
     Self: IntoDateTime,
 {
+    /// Maps a `TimePoint` towards the corresponding date and time-of-day in some generic
+    /// `Calendar`, which may be any of the calendars provided by this library or a user-defined
+    /// one.
+    pub fn into_calendar_datetime<C: Calendar>(self) -> (C, u8, u8, u8) {
+        let (date, hour, minute, second) = self.into_datetime();
+        (C::from_date(date), hour, minute, second)
+    }
+
+    /// Maps a `TimePoint` towards the corresponding Coptic date and time-of-day.
+    pub fn into_coptic_datetime(self) -> (CopticDate, u8, u8, u8) {
+        self.into_calendar_datetime()
+    }
+
+    /// Maps a `TimePoint` towards the corresponding Ethiopic date and time-of-day.
+    pub fn into_ethiopic_datetime(self) -> (EthiopicDate, u8, u8, u8) {
+        self.into_calendar_datetime()
+    }
+
+    /// Maps a `TimePoint` towards the corresponding Hebrew date and time-of-day. Note that the
+    /// Hebrew date is identified with the civil day, rather than starting at sunset.
+    pub fn into_hebrew_datetime(self) -> (HebrewDate, u8, u8, u8) {
+        self.into_calendar_datetime()
+    }
+
     /// Maps a `TimePoint` towards the corresponding historic date and time-of-day.
     pub fn into_historic_datetime(self) -> (HistoricDate, u8, u8, u8) {
         let (date, hour, minute, second) = self.into_datetime();
@@ -360,6 +1183,39 @@ where
     }
 }
 
+/// Verifies that the generic calendar conversion may be used with different calendars, and that
+/// it agrees with the calendar-specific conversion functions.
+#[test]
+fn calendar_datetime() {
+    use crate::TaiTime;
+
+    fn check_calendar<C: Calendar<Month = Month>>(
+        time_point: TaiTime,
+        year: i32,
+        month: Month,
+        day: u8,
+    ) {
+        let (date, hour, minute, second) = time_point.into_calendar_datetime::<C>();
+        assert_eq!(date.year(), year);
+        assert_eq!(date.month(), month);
+        assert_eq!(date.day(), day);
+        assert_eq!((hour, minute, second), (12, 34, 56));
+        assert_eq!(C::from_date(date.to_date()).to_date(), date.to_date());
+        assert_eq!(date.name(), C::NAME);
+    }
+
+    let time_point = TaiTime::from_gregorian_datetime(1500, Month::June, 20, 12, 34, 56).unwrap();
+    check_calendar::<GregorianDate>(time_point, 1500, Month::June, 20);
+    check_calendar::<JulianDate>(time_point, 1500, Month::June, 10);
+    check_calendar::<HistoricDate>(time_point, 1500, Month::June, 10);
+    assert_eq!(
+        time_point.into_calendar_datetime::<JulianDate>(),
+        time_point.into_julian_datetime()
+    );
+    let (date, ..) = time_point.into_calendar_datetime::<JulianDate>();
+    assert_eq!(date.name(), "Proleptic Julian");
+}
+
 impl<Scale, Representation, Period> FromFineDateTime<Representation, Period>
     for TimePoint<Scale, Representation, Period>
 where
@@ -503,6 +1359,148 @@ where
     }
 }
 
+impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representation, Period>
+where
+    Self: IntoFineDateTime<Representation, Period> + FromFineDateTime<Representation, Period>,
+    Representation: Zero,
+{
+    /// Returns the start of the civil day on which this time point falls, in its own time scale:
+    /// midnight of its date. Useful for bucketing events by day.
+    pub fn start_of_day(self) -> Self {
+        let (date, _, _, _, _) = self.into_fine_datetime();
+        Self::midnight(date)
+    }
+
+    /// Returns the end of the civil day on which this time point falls: exactly one `Period` (as in
+    /// `Duration::unit`) before midnight of the next day. For integer representations, this is the
+    /// last representable instant of the day; floating point representations could represent
+    /// later instants, but those are not returned. Since this is based on the date-time mapping of
+    /// the time scale itself, leap seconds are accounted for: for UTC, the end of a day with a
+    /// positive leap second is 23:59:60, rather than 23:59:59.
+    pub fn end_of_day(self) -> Self
+    where
+        Self: Sub<Duration<Representation, Period>, Output = Self>,
+        Representation: One,
+    {
+        let (date, _, _, _, _) = self.into_fine_datetime();
+        Self::midnight(date + Days::new(1)) - Duration::unit()
+    }
+
+    /// Returns midnight at the start of the given date, in this time scale.
+    fn midnight(date: Date<i32>) -> Self {
+        Self::from_fine_datetime(date, 0, 0, 0, Duration::new(Representation::zero()))
+            .unwrap_or_else(|_| panic!("midnight must exist on every day"))
+    }
+}
+
+/// Verifies that the start and end of the civil day are found, also on days that contain a leap
+/// second.
+#[test]
+fn day_boundaries() {
+    use crate::{TaiTime, UtcTime, units::Milli};
+    let afternoon = UtcTime::from_gregorian_datetime(2016, Month::December, 31, 15, 30, 0).unwrap();
+    let midnight = UtcTime::from_gregorian_datetime(2016, Month::December, 31, 0, 0, 0).unwrap();
+    let leap_second =
+        UtcTime::from_gregorian_datetime(2016, Month::December, 31, 23, 59, 60).unwrap();
+    assert_eq!(afternoon.start_of_day(), midnight);
+    assert_eq!(afternoon.end_of_day(), leap_second);
+    assert_eq!(leap_second.start_of_day(), midnight);
+    assert_eq!(leap_second.end_of_day(), leap_second);
+    assert_eq!(
+        afternoon.end_of_day() - afternoon.start_of_day(),
+        Duration::new(86_400)
+    );
+
+    let afternoon = TaiTime::<i64, Milli>::from_fine_gregorian_datetime(
+        2016,
+        Month::December,
+        31,
+        15,
+        30,
+        0,
+        Duration::new(250),
+    )
+    .unwrap();
+    let (_, hour, minute, second, subseconds) = afternoon.end_of_day().into_fine_datetime();
+    assert_eq!(
+        (hour, minute, second, subseconds),
+        (23, 59, 59, Duration::new(999))
+    );
+    let (_, hour, minute, second, subseconds) = afternoon.start_of_day().into_fine_datetime();
+    assert_eq!(
+        (hour, minute, second, subseconds),
+        (0, 0, 0, Duration::new(0))
+    );
+}
+
+impl<Scale: ?Sized, Representation, Period: ?Sized> TimePoint<Scale, Representation, Period>
+where
+    Self: IntoFineDateTime<Representation, Period>
+        + FromFineDateTime<Representation, Period>
+        + Add<Duration<Representation, Period>, Output = Self>
+        + PartialOrd,
+    Representation: Copy + Zero,
+{
+    /// Returns the first instant strictly after `self` that falls on the given `weekday`, at the
+    /// given `time_of_day` since midnight. If that moment is still to come today, today is
+    /// returned; otherwise, this steps forward to the next matching day, up to one week ahead.
+    /// Useful as primitive for scheduling recurring events.
+    ///
+    /// The time-of-day is added to midnight as a plain duration: on days containing a leap second,
+    /// the resulting time-of-day may therefore differ by that leap second.
+    pub fn next_weekday_at(
+        self,
+        weekday: WeekDay,
+        time_of_day: Duration<Representation, Period>,
+    ) -> Self {
+        let (date, _, _, _, _) = self.into_fine_datetime();
+        let days_ahead = (weekday as i32 - date.week_day() as i32).rem_euclid(7);
+        let at_day = |days: i32| Self::midnight(date + Days::new(days)) + time_of_day;
+        let candidate = at_day(days_ahead);
+        if candidate > self {
+            candidate
+        } else {
+            at_day(days_ahead + 7)
+        }
+    }
+}
+
+/// Verifies that recurring weekly events are scheduled on the right day, both when the event is
+/// still to come on the same day and when it requires a jump to the next week.
+#[test]
+fn next_weekday_at() {
+    use crate::{TaiTime, duration::Hours, units::Milli};
+    let nine = Hours::new(9i64).into_unit();
+    let wednesday = TaiTime::from_gregorian_datetime(2025, Month::January, 15, 10, 0, 0).unwrap();
+    let monday = TaiTime::from_gregorian_datetime(2025, Month::January, 20, 9, 0, 0).unwrap();
+    let next_monday = TaiTime::from_gregorian_datetime(2025, Month::January, 27, 9, 0, 0).unwrap();
+    assert_eq!(wednesday.next_weekday_at(WeekDay::Monday, nine), monday);
+
+    let early_monday = TaiTime::from_gregorian_datetime(2025, Month::January, 20, 8, 0, 0).unwrap();
+    assert_eq!(early_monday.next_weekday_at(WeekDay::Monday, nine), monday);
+    assert_eq!(monday.next_weekday_at(WeekDay::Monday, nine), next_monday);
+    let late_monday = TaiTime::from_gregorian_datetime(2025, Month::January, 20, 10, 0, 0).unwrap();
+    assert_eq!(
+        late_monday.next_weekday_at(WeekDay::Monday, nine),
+        next_monday
+    );
+
+    let wednesday = TaiTime::<i64, Milli>::from_fine_gregorian_datetime(
+        2025,
+        Month::January,
+        15,
+        10,
+        0,
+        0,
+        Duration::new(250),
+    )
+    .unwrap();
+    assert_eq!(
+        wednesday.next_weekday_at(WeekDay::Wednesday, Duration::new(36_000_250)),
+        wednesday + Days::new(7).into_unit()
+    );
+}
+
 impl<Scale, Representation, Period> Display for TimePoint<Scale, Representation, Period>
 where
     Self: IntoFineDateTime<Representation, Period>,
@@ -512,23 +1510,74 @@ where
     Period: UnitRatio + ?Sized,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        let (historic_date, hour, minute, second, subseconds) = self.into_fine_historic_datetime();
+        let mut buffer = FormatBuffer::<FORMAT_BUFFER_SIZE>::new();
+        self.write_with_radix(&mut buffer, f.precision(), 10, CalendarKind::Historic)?;
+        pad(f, buffer.as_str(), Alignment::Left)
+    }
+}
+
+/// Capacity of the buffer into which time points are rendered before padding: enough for the
+/// widest possible date-time, the maximum number of fractional digits, and a scale abbreviation.
+const FORMAT_BUFFER_SIZE: usize = 192;
+
+impl<Scale, Representation, Period> TimePoint<Scale, Representation, Period>
+where
+    Self: IntoFineDateTime<Representation, Period>,
+    Scale: ?Sized + TimeScale,
+    Duration<Representation, Period>: Zero,
+    Representation: Copy + FractionalDigits,
+    Period: UnitRatio + ?Sized,
+{
+    /// Returns a wrapper that displays this time point like its `Display` implementation does, but
+    /// with the subsecond part printed in hexadecimal rather than decimal. Useful for debugging
+    /// binary fraction periods, such as those used in CUC and NTP timestamps.
+    pub fn display_hex_fraction(&self) -> HexFractionDisplay<'_, Scale, Representation, Period> {
+        HexFractionDisplay { time_point: self }
+    }
+
+    /// Returns a wrapper that displays this time point like its `Display` implementation does, but
+    /// with the number of subsecond digits limited to those that are significant for the
+    /// underlying representation. For floats, this depends on the magnitude of the time since the
+    /// epoch: digits beyond the precision of the float would only print noise. For integers, all
+    /// digits are exact, so the output is the same as that of `Display`. An explicitly given
+    /// precision still takes precedence.
+    pub fn display_auto(&self) -> AutoPrecisionDisplay<'_, Scale, Representation, Period> {
+        AutoPrecisionDisplay { time_point: self }
+    }
+
+    /// Formats this time point like its `Display` implementation does, but with the date expressed
+    /// in the given calendar rather than the historic calendar. This permits rendering the same
+    /// instant as, for example, a proleptic Julian or proleptic Gregorian date-time.
+    #[cfg(feature = "std")]
+    pub fn to_string_with_calendar(&self, calendar: CalendarKind) -> String {
+        let mut string = String::new();
+        self.write_with_radix(&mut string, None, 10, calendar)
+            .unwrap_or_else(|_| unreachable!("Writing into a `String` cannot fail"));
+        string
+    }
+
+    /// Writes this time point as date-time in the given calendar, with the subsecond part
+    /// expressed in the given radix. At most `precision` digits are printed after the radix point.
+    fn write_with_radix(
+        &self,
+        f: &mut impl core::fmt::Write,
+        precision: Option<usize>,
+        base: u8,
+        calendar: CalendarKind,
+    ) -> core::fmt::Result {
+        let (date, hour, minute, second, subseconds) = self.into_fine_datetime();
+        let (year, month, day) = calendar.year_month_day(date);
         write!(
             f,
-            "{:04}-{:02}-{:02}T{hour:02}:{minute:02}:{second:02}",
-            historic_date.year(),
-            historic_date.month() as u8,
-            historic_date.day(),
+            "{}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}",
+            PaddedYear(year),
         )?;
 
         if !subseconds.is_zero() {
             write!(f, ".")?;
 
-            // Set maximum number of digits after the decimal point printed based on precision
-            // argument given to the formatter.
-            let max_digits_printed = f.precision();
-            for digit in subseconds.decimal_digits(max_digits_printed) {
-                write!(f, "{digit}")?;
+            for digit in subseconds.fractional_digits(precision, base) {
+                write!(f, "{digit:x}")?;
             }
         }
 
@@ -536,6 +1585,154 @@ where
     }
 }
 
+/// Wrapper that displays a `TimePoint` with its subsecond part in hexadecimal. Constructed using
+/// `TimePoint::display_hex_fraction`.
+pub struct HexFractionDisplay<'a, Scale: ?Sized, Representation, Period: ?Sized> {
+    time_point: &'a TimePoint<Scale, Representation, Period>,
+}
+
+impl<Scale, Representation, Period> Display
+    for HexFractionDisplay<'_, Scale, Representation, Period>
+where
+    TimePoint<Scale, Representation, Period>: IntoFineDateTime<Representation, Period>,
+    Scale: ?Sized + TimeScale,
+    Duration<Representation, Period>: Zero,
+    Representation: Copy + FractionalDigits,
+    Period: UnitRatio + ?Sized,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut buffer = FormatBuffer::<FORMAT_BUFFER_SIZE>::new();
+        self.time_point
+            .write_with_radix(&mut buffer, f.precision(), 16, CalendarKind::Historic)?;
+        pad(f, buffer.as_str(), Alignment::Left)
+    }
+}
+
+/// Wrapper that displays a `TimePoint` with only the subsecond digits that are significant for its
+/// representation. Constructed using `TimePoint::display_auto`.
+pub struct AutoPrecisionDisplay<'a, Scale: ?Sized, Representation, Period: ?Sized> {
+    time_point: &'a TimePoint<Scale, Representation, Period>,
+}
+
+impl<Scale, Representation, Period> Display
+    for AutoPrecisionDisplay<'_, Scale, Representation, Period>
+where
+    TimePoint<Scale, Representation, Period>: IntoFineDateTime<Representation, Period>,
+    Scale: ?Sized + TimeScale,
+    Duration<Representation, Period>: Zero,
+    Representation: Copy + FractionalDigits,
+    Period: UnitRatio + ?Sized,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().or_else(|| {
+            self.time_point
+                .time_since_epoch
+                .count()
+                .significant_fractional_digits(Period::FRACTION)
+        });
+        let mut buffer = FormatBuffer::<FORMAT_BUFFER_SIZE>::new();
+        self.time_point
+            .write_with_radix(&mut buffer, precision, 10, CalendarKind::Historic)?;
+        let string = buffer.as_str();
+        if f.precision().is_some() {
+            return pad(f, string, Alignment::Left);
+        }
+
+        // Truncation to the significant digits may leave trailing zeros, which carry no
+        // information and are removed, just like `Display` never prints them.
+        let Some((datetime, abbreviation)) = string.rsplit_once(' ') else {
+            return pad(f, string, Alignment::Left);
+        };
+        let datetime = if datetime.contains('.') {
+            datetime.trim_end_matches('0').trim_end_matches('.')
+        } else {
+            datetime
+        };
+        let mut trimmed = FormatBuffer::<FORMAT_BUFFER_SIZE>::new();
+        write!(trimmed, "{datetime} {abbreviation}")?;
+        pad(f, trimmed.as_str(), Alignment::Left)
+    }
+}
+
+/// Verifies that the width, fill, and alignment flags are respected when formatting.
+#[cfg(feature = "std")]
+#[test]
+fn padded_formatting() {
+    use crate::TaiTime;
+    let time = TaiTime::from_historic_datetime(2024, Month::March, 1, 12, 0, 0).unwrap();
+    assert_eq!(format!("{time:>30}"), "       2024-03-01T12:00:00 TAI");
+    assert_eq!(format!("{time:30}|"), "2024-03-01T12:00:00 TAI       |");
+    assert_eq!(format!("{time:*^27}"), "**2024-03-01T12:00:00 TAI**");
+    assert_eq!(format!("{time:>10}"), "2024-03-01T12:00:00 TAI");
+
+    let time = crate::TaiTime::from_fine_historic_datetime(
+        2024,
+        Month::March,
+        1,
+        12,
+        0,
+        0,
+        crate::MilliSeconds::new(250i64),
+    )
+    .unwrap();
+    assert_eq!(format!("{time:>30.3}"), "   2024-03-01T12:00:00.250 TAI");
+}
+
+/// Verifies that subsecond parts are printed correctly in hexadecimal.
+#[cfg(feature = "std")]
+#[test]
+fn hex_fraction_formatting() {
+    use crate::{BinaryFraction4, MilliSeconds, TaiTime};
+    let time = TaiTime::from_fine_historic_datetime(
+        2024,
+        Month::March,
+        1,
+        12,
+        0,
+        0,
+        MilliSeconds::new(500i64),
+    )
+    .unwrap();
+    assert_eq!(
+        time.display_hex_fraction().to_string(),
+        "2024-03-01T12:00:00.8 TAI"
+    );
+
+    let time = TaiTime::from_fine_historic_datetime(
+        2024,
+        Month::March,
+        1,
+        12,
+        0,
+        0,
+        MilliSeconds::new(250i64),
+    )
+    .unwrap();
+    assert_eq!(
+        time.display_hex_fraction().to_string(),
+        "2024-03-01T12:00:00.4 TAI"
+    );
+
+    let time = TaiTime::<i128, BinaryFraction4>::from_fine_historic_datetime(
+        2024,
+        Month::March,
+        1,
+        12,
+        0,
+        0,
+        crate::Duration::new(0x1234_5678),
+    )
+    .unwrap();
+    assert_eq!(
+        time.display_hex_fraction().to_string(),
+        "2024-03-01T12:00:00.12345678 TAI"
+    );
+    assert_eq!(
+        format!("{:.2}", time.display_hex_fraction()),
+        "2024-03-01T12:00:00.12 TAI"
+    );
+}
+
 #[cfg(feature = "std")]
 #[cfg(test)]
 #[allow(clippy::too_many_arguments)]
@@ -822,6 +2019,10 @@ where
     }
 }
 
+/// Shifting a `TimePoint` by a `Duration` overflows as the underlying representation does: for
+/// primitive integers, this means a panic in debug builds and silent wrapping in release builds.
+/// Where overflow may occur, use `checked_add`, `saturating_add`, or `wrapping_add` instead. To
+/// add a `Duration` of a narrower representation, use `add_widened`.
 impl<Scale, Representation, Period> Add<Duration<Representation, Period>>
     for TimePoint<Scale, Representation, Period>
 where
@@ -848,6 +2049,8 @@ where
     }
 }
 
+/// As for addition, overflow is handled as by the underlying representation. Use `checked_sub`,
+/// `saturating_sub`, or `wrapping_sub` to choose the behaviour explicitly.
 impl<Scale, Representation, Period> Sub<Duration<Representation, Period>>
     for TimePoint<Scale, Representation, Period>
 where
@@ -889,6 +2092,19 @@ where
     }
 }
 
+/// The default `TimePoint` is the epoch of its time scale, such that time points may be used in
+/// `#[derive(Default)]` types.
+impl<Scale, Representation, Period> Default for TimePoint<Scale, Representation, Period>
+where
+    Representation: Zero,
+    Scale: ?Sized,
+    Period: ?Sized,
+{
+    fn default() -> Self {
+        Self::from_time_since_epoch(Duration::zero())
+    }
+}
+
 impl<Scale, R1, R2, Period> TryFromExact<TimePoint<Scale, R2, Period>>
     for TimePoint<Scale, R1, Period>
 where
@@ -903,3 +2119,324 @@ where
         Ok(Self::from_time_since_epoch(time_since_epoch))
     }
 }
+
+/// Verifies that the widened difference of narrow time points does not overflow, even for time
+/// points at opposite extremes of the representable range.
+#[test]
+fn wide_difference() {
+    use crate::{Seconds, TaiTime};
+    let min = TaiTime::<i32>::from_time_since_epoch(Seconds::new(i32::MIN));
+    let max = TaiTime::<i32>::from_time_since_epoch(Seconds::new(i32::MAX));
+    assert_eq!(max.wide_sub(min), Seconds::new(u32::MAX as i64));
+    assert_eq!(min.wide_sub(max), Seconds::new(-(u32::MAX as i64)));
+    assert_eq!(max.wide_sub(max), Seconds::new(0i64));
+
+    let unsigned = TaiTime::<u32>::from_time_since_epoch(Seconds::new(3));
+    let later = TaiTime::<u32>::from_time_since_epoch(Seconds::new(5));
+    assert_eq!(unsigned.wide_sub(later), Seconds::new(-2i64));
+}
+
+/// Verifies that differences of unsigned time points may be negative without underflowing.
+#[test]
+fn signed_difference() {
+    use crate::{GpsTime, Seconds, units::Nano};
+    let earlier = GpsTime::<u64, Nano>::from_time_since_epoch(Duration::new(1_000));
+    let later = GpsTime::<u64, Nano>::from_time_since_epoch(Duration::new(4_500));
+    assert_eq!(earlier.signed_sub(later), Duration::new(-3_500));
+    assert_eq!(later.signed_sub(earlier), Duration::new(3_500));
+    assert_eq!(later.signed_sub(later), Duration::new(0));
+
+    let max = GpsTime::<u64>::from_time_since_epoch(Seconds::new(u64::MAX));
+    let zero = GpsTime::<u64>::from_time_since_epoch(Seconds::new(0));
+    assert_eq!(max.checked_signed_sub(zero), None);
+    assert_eq!(zero.checked_signed_sub(max), None);
+    let one = GpsTime::<u64>::from_time_since_epoch(Seconds::new(1));
+    assert_eq!(
+        one.checked_signed_sub(GpsTime::from_time_since_epoch(Seconds::new(
+            i64::MAX as u64 + 1
+        ))),
+        Some(Seconds::new(i64::MIN + 1))
+    );
+    let small = GpsTime::<i32>::from_time_since_epoch(Seconds::new(-5));
+    assert_eq!(
+        small.signed_sub(<GpsTime<i32>>::epoch()),
+        Seconds::new(-5i64)
+    );
+}
+
+/// Verifies that time points are quantized onto a 40 ms grid, also before the grid origin, and
+/// that dequantization inverts this for time points on the grid.
+#[test]
+fn grid_quantization() {
+    use crate::{MilliSeconds, TaiTime, units::Milli};
+    let origin = TaiTime::<i64, Milli>::from_time_since_epoch(MilliSeconds::new(1_000_005));
+    let step = MilliSeconds::new(40);
+    let at = |offset: i64| origin + MilliSeconds::new(offset);
+    assert_eq!(at(0).quantize(origin, step), 0);
+    assert_eq!(at(39).quantize(origin, step), 0);
+    assert_eq!(at(40).quantize(origin, step), 1);
+    assert_eq!(at(4_000).quantize(origin, step), 100);
+    assert_eq!(at(-1).quantize(origin, step), -1);
+    assert_eq!(at(-40).quantize(origin, step), -1);
+    assert_eq!(at(-41).quantize(origin, step), -2);
+
+    for index in [-1_000, -2, -1, 0, 1, 2, 25, 1_000] {
+        let grid_point = TaiTime::dequantize(origin, step, index);
+        assert_eq!(grid_point, at(index * 40));
+        assert_eq!(grid_point.quantize(origin, step), index);
+        assert_eq!(
+            (grid_point + MilliSeconds::new(39)).quantize(origin, step),
+            index
+        );
+    }
+
+    // Unsigned time points before the origin must not underflow, and out-of-range differences or
+    // zero steps are reported rather than overflowing.
+    let origin = TaiTime::<u64, Milli>::from_time_since_epoch(MilliSeconds::new(100));
+    let before = TaiTime::<u64, Milli>::from_time_since_epoch(MilliSeconds::new(59));
+    assert_eq!(before.quantize(origin, MilliSeconds::new(40)), -2);
+    assert_eq!(before.checked_quantize(origin, MilliSeconds::new(0)), None);
+    let far = TaiTime::<u64, Milli>::from_time_since_epoch(MilliSeconds::new(u64::MAX));
+    let zero = TaiTime::<u64, Milli>::from_time_since_epoch(MilliSeconds::new(0));
+    assert_eq!(far.checked_quantize(zero, MilliSeconds::new(1)), None);
+    assert_eq!(
+        far.checked_quantize(zero, MilliSeconds::new(2)),
+        Some(i64::MAX)
+    );
+    let min = TaiTime::<i128, Milli>::from_time_since_epoch(MilliSeconds::new(i128::MIN));
+    let max = TaiTime::<i128, Milli>::from_time_since_epoch(MilliSeconds::new(i128::MAX));
+    assert_eq!(min.checked_quantize(max, MilliSeconds::new(1)), None);
+}
+
+/// Verifies that reinterpreting the time scale of a time point only changes its type, not its
+/// count.
+#[test]
+fn reinterpret_scale() {
+    use crate::{GpsTime, Gpst, MilliSeconds, Seconds, TaiTime, units::Milli};
+    let tai = TaiTime::<i64>::from_time_since_epoch(Seconds::new(1_234));
+    let gps: GpsTime<i64> = tai.reinterpret_scale::<Gpst>();
+    assert_eq!(gps.time_since_epoch(), tai.time_since_epoch());
+
+    let tai = TaiTime::<i64, Milli>::from_time_since_epoch(MilliSeconds::new(-5));
+    assert_eq!(tai.reinterpret_scale::<Gpst>().count(), -5);
+}
+
+/// Verifies that the same instant is rendered differently in the proleptic Julian and Gregorian
+/// calendars, that the historic calendar agrees with the Julian calendar before 1582, and that
+/// dates may also be rendered in the Hebrew calendar.
+#[cfg(feature = "std")]
+#[test]
+fn calendar_rendering() {
+    use crate::{Month, TaiTime};
+    let time_point =
+        TaiTime::<i64>::from_julian_datetime(1500, Month::January, 1, 0, 0, 0).unwrap();
+    assert_eq!(
+        time_point.to_string_with_calendar(CalendarKind::Julian),
+        "1500-01-01T00:00:00 TAI"
+    );
+    assert_eq!(
+        time_point.to_string_with_calendar(CalendarKind::Gregorian),
+        "1500-01-10T00:00:00 TAI"
+    );
+    assert_eq!(
+        time_point.to_string_with_calendar(CalendarKind::Historic),
+        time_point.to_string()
+    );
+    assert_eq!(time_point.to_string(), "1500-01-01T00:00:00 TAI");
+
+    // Rosh Hashanah 5784 fell on 16 September 2023, on the first day of Tishrei (month 7).
+    let time_point =
+        TaiTime::<i64>::from_gregorian_datetime(2023, Month::September, 16, 12, 0, 0).unwrap();
+    assert_eq!(
+        time_point.to_string_with_calendar(CalendarKind::Hebrew),
+        "5784-07-01T12:00:00 TAI"
+    );
+}
+
+/// Verifies that a duration of a narrower representation may be added to or subtracted from a
+/// time point.
+#[test]
+fn mixed_representation_addition() {
+    use crate::{MilliSeconds, Seconds, TaiTime, units::Milli};
+    let time_point = TaiTime::<i64>::from_time_since_epoch(Seconds::new(10_000_000_000));
+    assert_eq!(
+        time_point.add_widened(Seconds::new(5i32)),
+        TaiTime::from_time_since_epoch(Seconds::new(10_000_000_005i64))
+    );
+    assert_eq!(
+        time_point.add_widened(Seconds::new(-5i32)),
+        time_point + Seconds::new(-5i64)
+    );
+    assert_eq!(
+        time_point.sub_widened(Seconds::new(5i32)),
+        time_point - Seconds::new(5i64)
+    );
+    let time_point = TaiTime::<u64, Milli>::from_time_since_epoch(MilliSeconds::new(1));
+    assert_eq!(
+        time_point.add_widened(MilliSeconds::new(2u32)),
+        time_point + MilliSeconds::new(2)
+    );
+}
+
+/// Verifies that time points are clamped to the range of a narrower representation.
+#[test]
+fn saturating_cast() {
+    use crate::{Nano, NanoSeconds, TaiTime};
+    let time_point = TaiTime::<i64, Nano>::from_time_since_epoch(NanoSeconds::new(1_000_000_000));
+    assert_eq!(
+        time_point.saturating_cast::<i16>().time_since_epoch(),
+        NanoSeconds::new(i16::MAX)
+    );
+    assert_eq!(
+        (-time_point.time_since_epoch()).saturating_cast::<i16>(),
+        NanoSeconds::new(i16::MIN)
+    );
+    let small = TaiTime::<i64, Nano>::from_time_since_epoch(NanoSeconds::new(-1234));
+    assert_eq!(
+        small.saturating_cast::<i16>(),
+        TaiTime::from_time_since_epoch(NanoSeconds::new(-1234i16))
+    );
+    assert_eq!(
+        NanoSeconds::new(300u32).saturating_cast::<u8>(),
+        NanoSeconds::new(u8::MAX)
+    );
+}
+
+/// Verifies that non-finite float time points are rejected on construction.
+#[test]
+fn finite_construction() {
+    use crate::{Seconds, TaiTime};
+    assert_eq!(
+        TaiTime::from_time_since_epoch_finite(Seconds::new(f64::NAN)),
+        None
+    );
+    assert_eq!(
+        TaiTime::from_time_since_epoch_finite(Seconds::new(f64::NEG_INFINITY)),
+        None
+    );
+    let time_point = TaiTime::from_time_since_epoch_finite(Seconds::new(0.25f64)).unwrap();
+    assert_eq!(time_point.time_since_epoch(), Seconds::new(0.25));
+    assert!(time_point.is_finite());
+}
+
+/// Verifies that automatic precision does not print more digits than the representation supports.
+#[cfg(feature = "std")]
+#[test]
+fn automatic_display_precision() {
+    use crate::{MilliSeconds, Seconds, TaiTime};
+    let time = TaiTime::from_time_since_epoch(Seconds::new(1.1f32));
+    assert_eq!(
+        time.to_string(),
+        "1958-01-01T00:00:01.10000002384185791015625 TAI"
+    );
+    assert_eq!(time.display_auto().to_string(), "1958-01-01T00:00:01.1 TAI");
+    assert_eq!(
+        format!("{:.3}", time.display_auto()),
+        "1958-01-01T00:00:01.100 TAI"
+    );
+
+    // The larger the time since the epoch, the fewer subsecond digits remain significant.
+    let time = TaiTime::from_time_since_epoch(Seconds::new(1234.5678f32));
+    assert_eq!(
+        time.display_auto().to_string(),
+        "1958-01-01T00:20:34.56 TAI"
+    );
+    let time = TaiTime::from_time_since_epoch(Seconds::new(1_000_000.25f64));
+    assert_eq!(
+        time.display_auto().to_string(),
+        "1958-01-12T13:46:40.25 TAI"
+    );
+
+    // Integer representations are exact, so all digits are printed.
+    let time = TaiTime::from_time_since_epoch(MilliSeconds::new(1_234i64));
+    assert_eq!(time.display_auto().to_string(), time.to_string());
+
+    // Non-finite counts have no significant fractional digits.
+    let unit_ratio = Second::FRACTION;
+    assert_eq!(
+        f64::INFINITY.significant_fractional_digits(unit_ratio),
+        Some(0)
+    );
+    assert_eq!(
+        f32::NEG_INFINITY.significant_fractional_digits(unit_ratio),
+        Some(0)
+    );
+    assert_eq!(f64::NAN.significant_fractional_digits(unit_ratio), Some(0));
+}
+
+/// Verifies that the default time point is the epoch of its time scale.
+#[test]
+fn default_is_epoch() {
+    use crate::{GpsTime, TaiTime};
+    assert_eq!(<TaiTime>::default().count(), 0);
+    assert_eq!(TaiTime::<i64>::default(), TaiTime::epoch());
+    assert_eq!(GpsTime::<u32>::default(), GpsTime::epoch());
+
+    #[derive(Default)]
+    struct Record {
+        time: TaiTime,
+    }
+    assert_eq!(Record::default().time, TaiTime::epoch());
+}
+
+/// Verifies that sub-second time points are converted into seconds under each rounding mode, on
+/// both sides of the epoch.
+#[test]
+fn rounded_unit_conversion() {
+    use crate::{MilliSeconds, Seconds, TaiTime, units::Milli};
+    let time = TaiTime::<i64, Milli>::from_time_since_epoch(MilliSeconds::new(2_700));
+    let expected = [
+        (RoundingMode::Nearest, 3),
+        (RoundingMode::Ceil, 3),
+        (RoundingMode::Floor, 2),
+        (RoundingMode::TowardZero, 2),
+    ];
+    for (mode, seconds) in expected {
+        assert_eq!(
+            time.into_unit_rounded::<Second>(mode),
+            TaiTime::from_time_since_epoch(Seconds::new(seconds))
+        );
+    }
+
+    let time = TaiTime::<i64, Milli>::from_time_since_epoch(MilliSeconds::new(-2_300));
+    let expected = [
+        (RoundingMode::Nearest, -2),
+        (RoundingMode::Ceil, -2),
+        (RoundingMode::Floor, -3),
+        (RoundingMode::TowardZero, -2),
+    ];
+    for (mode, seconds) in expected {
+        assert_eq!(
+            time.into_unit_rounded::<Second>(mode),
+            TaiTime::from_time_since_epoch(Seconds::new(seconds))
+        );
+    }
+
+    let time = TaiTime::<f64, Milli>::from_time_since_epoch(MilliSeconds::new(-2_700.0));
+    assert_eq!(
+        time.into_unit_rounded::<Second>(RoundingMode::TowardZero),
+        TaiTime::from_time_since_epoch(Seconds::new(-2.0))
+    );
+    assert_eq!(RoundingMode::default(), RoundingMode::Nearest);
+}
+
+/// Verifies that round trips through other time scales are quantified, and are lossless for exact
+/// conversions.
+#[test]
+fn round_trip_error() {
+    use crate::{Milli, MilliSeconds, Seconds, Tai, TaiTime, Tcg, Tt, Utc};
+    let time = TaiTime::from_historic_datetime(2024, Month::August, 13, 19, 30, 0)
+        .unwrap()
+        .into_unit::<Milli>();
+    assert_eq!(time.round_trip_error::<Tt>(), MilliSeconds::new(0));
+    assert_eq!(time.round_trip_error::<Tai>(), MilliSeconds::new(0));
+    assert_eq!(time.round_trip_error::<Utc>(), MilliSeconds::new(0));
+    let time = TaiTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 59).unwrap();
+    assert_eq!(time.round_trip_error::<Utc>(), Seconds::new(0));
+
+    // For floating point representations, the residual of a round trip through a scale with a
+    // different rate is small, but not necessarily zero.
+    let time = TaiTime::from_time_since_epoch(Seconds::new(1.5e9));
+    let residual = time.round_trip_error::<Tcg>().count();
+    assert!(residual.abs() < 1e-6);
+}
@@ -0,0 +1,171 @@
+//! Implementation of time points of which the time scale is only known at runtime. This is the
+//! dynamic counterpart of the `Scale` parameter of `TimePoint`: useful when parsing time stamps of
+//! which the time scale is determined by their suffix, for example when ingesting log files.
+
+use core::{ops::Add, str::FromStr};
+
+use crate::{
+    BeiDouTime, ConvertUnit, Fraction, FromTimeScale, GalileoTime, GlonassTime, GpsTime, QzssTime,
+    TaiTime, TcgTime, TryFromExact, TryMul, TtTime, UnitRatio, UtcTime,
+    errors::AnyTimePointParsingError,
+    time_scale::{Bdt, Glonasst, Gpst, Gst, Qzsst, Tcg, TimeScaleId, Tt, Utc},
+    units::Second,
+};
+
+/// Time point in any of the built-in time scales, of which the time scale is only known at
+/// runtime. Each variant carries the correctly-typed `TimePoint` for its scale.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AnyTimePoint<Representation = i64, Period = Second> {
+    Tai(TaiTime<Representation, Period>),
+    Utc(UtcTime<Representation, Period>),
+    Tt(TtTime<Representation, Period>),
+    Tcg(TcgTime<Representation, Period>),
+    Gpst(GpsTime<Representation, Period>),
+    Gst(GalileoTime<Representation, Period>),
+    Bdt(BeiDouTime<Representation, Period>),
+    Qzsst(QzssTime<Representation, Period>),
+    Glonasst(GlonassTime<Representation, Period>),
+}
+
+impl<Representation, Period> AnyTimePoint<Representation, Period> {
+    /// Returns the identifier of the time scale in which this time point is expressed.
+    pub const fn time_scale(&self) -> TimeScaleId {
+        match self {
+            Self::Tai(_) => TimeScaleId::Tai,
+            Self::Utc(_) => TimeScaleId::Utc,
+            Self::Tt(_) => TimeScaleId::Tt,
+            Self::Tcg(_) => TimeScaleId::Tcg,
+            Self::Gpst(_) => TimeScaleId::Gpst,
+            Self::Gst(_) => TimeScaleId::Gst,
+            Self::Bdt(_) => TimeScaleId::Bdt,
+            Self::Qzsst(_) => TimeScaleId::Qzsst,
+            Self::Glonasst(_) => TimeScaleId::Glonasst,
+        }
+    }
+
+    /// Normalizes this time point by converting it into TAI, regardless of its original scale.
+    ///
+    /// Since the offset of TT (and hence TCG) from TAI is 32.184 seconds, this is only available
+    /// for periods of milliseconds or finer, or for float representations: otherwise, TT time
+    /// points cannot be exactly expressed in TAI.
+    pub fn into_tai(self) -> TaiTime<Representation, Period>
+    where
+        TaiTime<Representation, Period>: FromTimeScale<Utc, Representation, Period>
+            + FromTimeScale<Tt, Representation, Period>
+            + FromTimeScale<Tcg, Representation, Period>
+            + FromTimeScale<Gpst, Representation, Period>
+            + FromTimeScale<Gst, Representation, Period>
+            + FromTimeScale<Bdt, Representation, Period>
+            + FromTimeScale<Qzsst, Representation, Period>
+            + FromTimeScale<Glonasst, Representation, Period>,
+    {
+        match self {
+            Self::Tai(time_point) => time_point,
+            Self::Utc(time_point) => TaiTime::from_time_scale(time_point),
+            Self::Tt(time_point) => TaiTime::from_time_scale(time_point),
+            Self::Tcg(time_point) => TaiTime::from_time_scale(time_point),
+            Self::Gpst(time_point) => TaiTime::from_time_scale(time_point),
+            Self::Gst(time_point) => TaiTime::from_time_scale(time_point),
+            Self::Bdt(time_point) => TaiTime::from_time_scale(time_point),
+            Self::Qzsst(time_point) => TaiTime::from_time_scale(time_point),
+            Self::Glonasst(time_point) => TaiTime::from_time_scale(time_point),
+        }
+    }
+}
+
+impl<Representation, Period> FromStr for AnyTimePoint<Representation, Period>
+where
+    Representation: Add<Representation, Output = Representation>
+        + ConvertUnit<Second, Period>
+        + TryFromExact<i64>
+        + TryMul<Fraction, Output = Representation>,
+    Period: UnitRatio,
+{
+    type Err = AnyTimePointParsingError;
+
+    /// Parses a time point in the same format as the `FromStr` implementation of `TimePoint`, but
+    /// determines the time scale from the abbreviation that follows the final space.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let time_scale = TimeScaleId::from_time_point_suffix(string)
+            .ok_or(AnyTimePointParsingError::UnknownTimeScale)?;
+        Ok(match time_scale {
+            TimeScaleId::Tai => Self::Tai(string.parse()?),
+            TimeScaleId::Utc => Self::Utc(
+                string
+                    .parse()
+                    .map_err(AnyTimePointParsingError::UtcParsingError)?,
+            ),
+            TimeScaleId::Tt => Self::Tt(string.parse()?),
+            TimeScaleId::Tcg => Self::Tcg(string.parse()?),
+            TimeScaleId::Gpst => Self::Gpst(string.parse()?),
+            TimeScaleId::Gst => Self::Gst(string.parse()?),
+            TimeScaleId::Bdt => Self::Bdt(string.parse()?),
+            TimeScaleId::Qzsst => Self::Qzsst(string.parse()?),
+            TimeScaleId::Glonasst => Self::Glonasst(
+                string
+                    .parse()
+                    .map_err(AnyTimePointParsingError::GlonassParsingError)?,
+            ),
+        })
+    }
+}
+
+/// Verifies that time points are parsed into the variant indicated by their suffix, and that they
+/// may be normalized into TAI.
+#[test]
+fn parse_any_time_point() {
+    use crate::{MilliSeconds, Month, units::Milli};
+    let tai = TaiTime::from_historic_datetime(2024, Month::January, 15, 0, 0, 0).unwrap();
+
+    let time: AnyTimePoint = "2024-01-15T00:00:00 TAI".parse().unwrap();
+    assert_eq!(time, AnyTimePoint::Tai(tai));
+    assert_eq!(time.time_scale(), TimeScaleId::Tai);
+
+    let time: AnyTimePoint = "2024-01-15T00:00:00 UTC".parse().unwrap();
+    let utc = UtcTime::from_historic_datetime(2024, Month::January, 15, 0, 0, 0).unwrap();
+    assert_eq!(time, AnyTimePoint::Utc(utc));
+    assert_eq!(time.time_scale(), TimeScaleId::Utc);
+
+    let time: AnyTimePoint = "2024-01-15T00:00:00 GPST".parse().unwrap();
+    let gpst = GpsTime::from_historic_datetime(2024, Month::January, 15, 0, 0, 0).unwrap();
+    assert_eq!(time, AnyTimePoint::Gpst(gpst));
+    assert_eq!(time.time_scale(), TimeScaleId::Gpst);
+
+    // Normalization into TAI requires a period in which the TT offset may be expressed.
+    let tai: TaiTime<i64, Milli> = tai.into_unit();
+    let parse = |string: &str| string.parse::<AnyTimePoint<i64, Milli>>().unwrap();
+    assert_eq!(parse("2024-01-15T00:00:00 TAI").into_tai(), tai);
+    assert_eq!(
+        parse("2024-01-15T00:00:00 UTC").into_tai(),
+        tai + MilliSeconds::new(37_000)
+    );
+    assert_eq!(
+        parse("2024-01-15T00:00:00 GPST").into_tai(),
+        tai + MilliSeconds::new(19_000)
+    );
+    assert_eq!(
+        parse("2024-01-15T00:00:00 TT").into_tai(),
+        tai - MilliSeconds::new(32_184)
+    );
+    assert_eq!(
+        parse("2024-01-15T00:00:00.5 TAI").time_scale(),
+        TimeScaleId::Tai
+    );
+
+    assert_eq!(
+        "2024-01-15T00:00:00 UT1".parse::<AnyTimePoint>(),
+        Err(AnyTimePointParsingError::UnknownTimeScale)
+    );
+    assert_eq!(
+        "2024-01-15T00:00:00".parse::<AnyTimePoint>(),
+        Err(AnyTimePointParsingError::UnknownTimeScale)
+    );
+    assert!(matches!(
+        "2024-01-15T00:00:60 UTC".parse::<AnyTimePoint>(),
+        Err(AnyTimePointParsingError::UtcParsingError(_))
+    ));
+    assert!(matches!(
+        "2024-01-15T24:00:00 TAI".parse::<AnyTimePoint>(),
+        Err(AnyTimePointParsingError::TimePointParsingError(_))
+    ));
+}
@@ -1,9 +1,14 @@
 //! Representation of BeiDou Time (BDT), which is broadcast by the BeiDou constellation.
 
+use core::ops::{Add, Sub};
+
+use num_traits::AsPrimitive;
+
 use crate::{
-    Date, Duration, Month, Seconds, TerrestrialTime, TimePoint, UniformDateTimeScale,
+    ConvertUnit, Date, Duration, Fraction, Month, MulFloor, Seconds, TerrestrialTime, TimePoint,
+    UniformDateTimeScale, UnitRatio, Weeks,
     time_scale::{AbsoluteTimeScale, TimeScale},
-    units::Second,
+    units::{Second, SecondsPerWeek},
 };
 
 pub type BeiDouTime<Representation = i64, Period = Second> = TimePoint<Bdt, Representation, Period>;
@@ -21,10 +26,16 @@ impl TimeScale for Bdt {
 }
 
 impl AbsoluteTimeScale for Bdt {
+    /// BDT started at 2006-01-01T00:00:00 UTC, at which point TAI - UTC was 33 seconds. Because
+    /// BDT applies no leap seconds after its epoch, it is a constant 33 seconds behind TAI, or
+    /// equivalently, 14 seconds behind GPS time. BDT week 0 starts at this epoch, which coincides
+    /// with GPS week 1356.
     const EPOCH: Date<i32> = match Date::from_historic_date(2006, Month::January, 1) {
         Ok(epoch) => epoch,
         Err(_) => unreachable!(),
     };
+
+    const IS_UNIFORM_DATE_TIME: bool = true;
 }
 
 impl UniformDateTimeScale for Bdt {}
@@ -35,6 +46,46 @@ impl TerrestrialTime for Bdt {
     const TAI_OFFSET: Duration<Self::Representation, Self::Period> = Seconds::new(-33);
 }
 
+impl<Representation, Period> BeiDouTime<Representation, Period>
+where
+    Period: UnitRatio + ?Sized,
+{
+    /// Splits this BeiDou time into the week number and time-of-week, as commonly used in BeiDou
+    /// navigation messages. Weeks are counted from the BDT epoch, 2006-01-01, without rollover.
+    pub fn to_week_tow(&self) -> (Weeks<Representation>, Duration<Representation, Period>)
+    where
+        Representation: Copy
+            + MulFloor<Fraction, Output = Representation>
+            + Sub<Representation, Output = Representation>
+            + ConvertUnit<SecondsPerWeek, Period>,
+    {
+        self.time_since_epoch().factor_out::<SecondsPerWeek>()
+    }
+
+    /// Constructs a BeiDou time from a week number (counted from the BDT epoch, without rollover)
+    /// and the time-of-week elapsed since the start of that week.
+    pub fn from_week_tow(
+        week: Weeks<Representation>,
+        time_of_week: Duration<Representation, Period>,
+    ) -> Self
+    where
+        Representation:
+            Add<Representation, Output = Representation> + ConvertUnit<SecondsPerWeek, Period>,
+    {
+        Self::from_time_since_epoch(week.into_unit() + time_of_week)
+    }
+
+    /// Returns the total number of seconds elapsed since the BeiDou epoch, 2006-01-01, as a scalar,
+    /// in the same manner as `GpsTime::seconds_since_gps_epoch`.
+    pub fn seconds_since_beidou_epoch(&self) -> f64
+    where
+        Representation: Copy + AsPrimitive<f64>,
+        Period: UnitRatio,
+    {
+        self.time_since_epoch().as_seconds_f64()
+    }
+}
+
 /// Compares with a known timestamp as obtained from the definition of the BeiDou Time: the
 /// epoch itself of the system.
 #[test]
@@ -44,3 +95,33 @@ fn known_timestamps() {
     let bdt = BeiDouTime::from_historic_datetime(2006, Month::January, 1, 0, 0, 0).unwrap();
     assert_eq!(utc, bdt.into_time_scale());
 }
+
+/// Verifies that BDT is exactly 14 seconds behind GPS time, and that BDT week 0 coincides with GPS
+/// week 1356.
+#[test]
+fn gps_offset() {
+    use crate::{GpsTime, IntoTimeScale};
+    let bdt = BeiDouTime::from_week_tow(Weeks::new(100), Seconds::new(1_000));
+    let gpst: GpsTime = bdt.into_time_scale();
+    let (week, time_of_week) = gpst.to_week_tow();
+    assert_eq!(week, Weeks::new(1456));
+    assert_eq!(time_of_week, Seconds::new(1_014));
+
+    let bdt = BeiDouTime::from_historic_datetime(2006, Month::January, 1, 0, 0, 0).unwrap();
+    let gpst = GpsTime::from_historic_datetime(2006, Month::January, 1, 0, 0, 14).unwrap();
+    assert_eq!(gpst, bdt.into_time_scale());
+    assert_eq!(bdt.to_week_tow(), (Weeks::new(0), Seconds::new(0)));
+}
+
+/// Verifies that the elapsed seconds since the BeiDou epoch are consistent with those since the
+/// GPS epoch: BDT week 0 starts at GPS week 1356, and BDT lags GPS by 14 seconds.
+#[test]
+fn seconds_since_epoch() {
+    use crate::GpsTime;
+    let bdt = BeiDouTime::from_historic_datetime(2020, Month::January, 8, 12, 0, 0).unwrap();
+    let gpst = GpsTime::from_historic_datetime(2020, Month::January, 8, 12, 0, 0).unwrap();
+    assert_eq!(
+        bdt.seconds_since_beidou_epoch(),
+        gpst.seconds_since_gps_epoch() - 1356. * 604800.
+    );
+}
@@ -32,3 +32,72 @@ where
         TimePoint::from_time_scale(self)
     }
 }
+
+/// Trait representing the ability to attempt a conversion from one scale into another. This is the
+/// fallible counterpart of `FromTimeScale`, for conversions that are not defined for all instants
+/// or that may overflow the underlying representation.
+///
+/// Unlike `TryFrom`, this trait is not implemented automatically for every `FromTimeScale`
+/// conversion: such a blanket implementation would forbid fallible implementations between any
+/// pair of scales that also converts infallibly. Instead, it is implemented explicitly where a
+/// conversion can fail, as for the checked conversions between terrestrial time scales.
+pub trait TryFromTimeScale<Scale, Representation, Period>: Sized {
+    /// The error returned when the conversion fails.
+    type Error;
+
+    /// Attempts to construct a time point from an instant expressed in another scale.
+    fn try_from_time_scale(
+        time_point: TimePoint<Scale, Representation, Period>,
+    ) -> Result<Self, Self::Error>;
+}
+
+/// Converts a time point from the `Source` into the `Target` time scale. This is the same as
+/// calling `into_time_scale`, but without requiring any traits to be imported. The scales may be
+/// given explicitly, with the representation and period inferred:
+/// `convert_time_scale::<Tai, Tt, _, _>(tai)`.
+pub fn convert_time_scale<Source, Target, Representation, Period>(
+    time_point: TimePoint<Source, Representation, Period>,
+) -> TimePoint<Target, Representation, Period>
+where
+    TimePoint<Target, Representation, Period>: FromTimeScale<Source, Representation, Period>,
+{
+    TimePoint::from_time_scale(time_point)
+}
+
+/// Attempts to convert a time point from the `Source` into the `Target` time scale, returning an
+/// error if the instant cannot be expressed in the `Target` scale.
+pub fn try_convert_time_scale<Source, Target, Representation, Period, Error>(
+    time_point: TimePoint<Source, Representation, Period>,
+) -> Result<TimePoint<Target, Representation, Period>, Error>
+where
+    TimePoint<Target, Representation, Period>:
+        TryFromTimeScale<Source, Representation, Period, Error = Error>,
+{
+    TimePoint::try_from_time_scale(time_point)
+}
+
+/// Verifies that time points may be converted back and forth using the free functions, and that
+/// the fallible conversion reports overflow instead of panicking.
+#[test]
+fn free_function_roundtrip() {
+    use crate::{
+        IntoTimeScale, Milli, MilliSeconds, Month, Tai, TaiTime, Tt, TtTime,
+        errors::TimeScaleConversionOverflow,
+    };
+    let tai = TaiTime::from_historic_datetime(2024, Month::August, 13, 19, 30, 0)
+        .unwrap()
+        .into_unit::<Milli>();
+    let tt = convert_time_scale::<Tai, Tt, _, _>(tai);
+    assert_eq!(tt, tai.into_time_scale());
+    assert_eq!(convert_time_scale::<Tt, Tai, _, _>(tt), tai);
+
+    let tt: TtTime<i64, Milli> = try_convert_time_scale(tai).unwrap();
+    let roundtrip: Result<TaiTime<i64, Milli>, _> = try_convert_time_scale(tt);
+    assert_eq!(roundtrip, Ok(tai));
+
+    let last = TaiTime::<i64, Milli>::from_time_since_epoch(MilliSeconds::new(i64::MAX));
+    assert_eq!(
+        try_convert_time_scale::<Tai, Tt, _, _, _>(last),
+        Err(TimeScaleConversionOverflow)
+    );
+}
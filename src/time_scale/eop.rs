@@ -0,0 +1,153 @@
+//! Earth orientation parameters (EOPs) describe the irregularities in the Earth's rotation. They
+//! are published together in IERS bulletins: leap seconds (Bulletin C), the difference between UT1
+//! and UTC (DUT1), and polar motion (Bulletin A/B). The `EopProvider` trait unifies access to all
+//! of them, such that applications that need more than leap seconds may pass around a single
+//! provider object.
+
+use crate::{Date, Duration, FromDateTime, LeapSecondProvider, Second, Seconds, UtcTime};
+
+/// Umbrella trait for providers of Earth orientation parameters. Simple applications that only
+/// care about leap seconds may keep using a `LeapSecondProvider`: any such provider is also an
+/// `EopProvider` that does not know about DUT1 or polar motion.
+///
+/// Conversely, an `EopProvider` may be used wherever a `LeapSecondProvider` is expected by
+/// wrapping it in an `EopLeapSeconds` adapter.
+pub trait EopProvider {
+    /// Representation of the polar motion parameters returned by this provider. This library does
+    /// not interpret polar motion itself, so it is passed through as-is.
+    type PolarMotion;
+
+    /// Given some UTC time, returns the number of leap seconds that apply, and whether the
+    /// requested date-time is a leap second (exactly). Semantics are the same as for
+    /// `LeapSecondProvider::leap_seconds_at_time`.
+    fn leap_seconds_at(&self, utc_time: UtcTime<i64, Second>) -> (bool, Seconds<u8>);
+
+    /// Returns the difference UT1 - UTC (DUT1) at the given UTC time, if known.
+    fn dut1_at(&self, utc_time: UtcTime<i64, Second>) -> Option<Duration<f64, Second>>;
+
+    /// Returns the polar motion at the given UTC time, if known.
+    fn polar_motion_at(&self, utc_time: UtcTime<i64, Second>) -> Option<Self::PolarMotion>;
+
+    /// Returns the difference UT1 - TAI at the given UTC time, if DUT1 is known. This is the
+    /// offset that must be added to a TAI time to obtain the corresponding UT1 time.
+    fn ut1_minus_tai_at(&self, utc_time: UtcTime<i64, Second>) -> Option<Duration<f64, Second>> {
+        let dut1 = self.dut1_at(utc_time)?;
+        let (_, leap_seconds) = self.leap_seconds_at(utc_time);
+        Some(dut1 - leap_seconds.cast::<f64>())
+    }
+}
+
+/// Any leap second provider may be used as Earth orientation parameter provider that knows
+/// neither DUT1 nor polar motion.
+impl<Provider> EopProvider for Provider
+where
+    Provider: LeapSecondProvider,
+{
+    type PolarMotion = ();
+
+    fn leap_seconds_at(&self, utc_time: UtcTime<i64, Second>) -> (bool, Seconds<u8>) {
+        self.leap_seconds_at_time(utc_time)
+    }
+
+    fn dut1_at(&self, _: UtcTime<i64, Second>) -> Option<Duration<f64, Second>> {
+        None
+    }
+
+    fn polar_motion_at(&self, _: UtcTime<i64, Second>) -> Option<()> {
+        None
+    }
+}
+
+/// Adapter that exposes the leap seconds of an `EopProvider` as `LeapSecondProvider`, such that it
+/// may be used in date-time conversions that only require leap seconds.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EopLeapSeconds<'a, Provider>(pub &'a Provider);
+
+impl<Provider> LeapSecondProvider for EopLeapSeconds<'_, Provider>
+where
+    Provider: EopProvider,
+{
+    fn leap_seconds_on_date(&self, utc_date: Date<i32>) -> (bool, Seconds<u8>) {
+        // Because leap seconds are only ever inserted at 23:59:60, the second after 23:59:59 is a
+        // leap second iff one is inserted on that date.
+        let start_of_day = UtcTime::from_datetime(utc_date, 0, 0, 0)
+            .unwrap_or_else(|_| panic!("Midnight must always be a valid UTC time"));
+        let (_, leap_seconds) = self.0.leap_seconds_at(start_of_day);
+        let end_of_day = start_of_day + Seconds::new(86_400);
+        let (is_leap_second, _) = self.0.leap_seconds_at(end_of_day);
+        (is_leap_second, leap_seconds)
+    }
+
+    fn leap_seconds_at_time(&self, utc_time: UtcTime<i64, Second>) -> (bool, Seconds<u8>) {
+        self.0.leap_seconds_at(utc_time)
+    }
+}
+
+/// Stub provider with a fixed DUT1 that defers to the static leap second table.
+#[cfg(test)]
+struct StubEopProvider;
+
+#[cfg(test)]
+impl EopProvider for StubEopProvider {
+    type PolarMotion = (f64, f64);
+
+    fn leap_seconds_at(&self, utc_time: UtcTime<i64, Second>) -> (bool, Seconds<u8>) {
+        crate::StaticLeapSecondProvider {}.leap_seconds_at_time(utc_time)
+    }
+
+    fn dut1_at(&self, _: UtcTime<i64, Second>) -> Option<Duration<f64, Second>> {
+        Some(Duration::new(-0.25))
+    }
+
+    fn polar_motion_at(&self, _: UtcTime<i64, Second>) -> Option<(f64, f64)> {
+        Some((0.1, 0.3))
+    }
+}
+
+/// Verifies that a custom EOP provider can be used to obtain the UT1 offset, and that it can be
+/// fed into leap second-based date-time conversions.
+#[test]
+fn stub_provider() {
+    use crate::{
+        FromLeapSecondDateTime, GlonassTime, IntoTimeScale, Month, StaticLeapSecondProvider,
+    };
+    let utc = UtcTime::from_historic_datetime(2020, Month::January, 1, 0, 0, 0).unwrap();
+    let provider = StubEopProvider;
+    assert_eq!(provider.dut1_at(utc), Some(Duration::new(-0.25)));
+    assert_eq!(provider.polar_motion_at(utc), Some((0.1, 0.3)));
+
+    // UT1 - TAI = DUT1 - (TAI - UTC), with TAI - UTC = 37 s in 2020.
+    assert_eq!(provider.ut1_minus_tai_at(utc), Some(Duration::new(-37.25)));
+
+    // The leap seconds of the provider may be used in leap second-aware date-time conversions.
+    let provider = EopLeapSeconds(&provider);
+    let utc_leap_second = UtcTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 60);
+    let glonasst_leap_second = <GlonassTime as FromLeapSecondDateTime>::from_datetime(
+        Date::from_historic_date(2017, Month::January, 1).unwrap(),
+        2,
+        59,
+        60,
+        &provider,
+    );
+    assert_eq!(
+        glonasst_leap_second.unwrap().into_time_scale(),
+        utc_leap_second.unwrap()
+    );
+    assert_eq!(
+        provider.leap_seconds_on_date(Date::from_historic_date(2016, Month::December, 31).unwrap()),
+        (true, Seconds::new(36))
+    );
+    assert_eq!(
+        provider.leap_seconds_on_date(Date::from_historic_date(2017, Month::January, 1).unwrap()),
+        (false, Seconds::new(37))
+    );
+
+    // Plain leap second providers do not know about DUT1.
+    let static_provider = StaticLeapSecondProvider {};
+    assert_eq!(static_provider.dut1_at(utc), None);
+    assert_eq!(static_provider.ut1_minus_tai_at(utc), None);
+    assert_eq!(
+        EopProvider::leap_seconds_at(&static_provider, utc),
+        (false, Seconds::new(37))
+    );
+}
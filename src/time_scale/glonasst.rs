@@ -69,7 +69,8 @@ impl FromLeapSecondDateTime for GlonassTime<i64, Second> {
         let utc_date = if hour < 3 { date - Days::new(1) } else { date };
         let (is_leap_second, total_leap_seconds) =
             leap_second_provider.leap_seconds_on_date(utc_date);
-        if second == 60 && !is_leap_second {
+        // Leap seconds are inserted at the end of the UTC day, which is 02:59:60 Moscow time.
+        if second == 60 && !(is_leap_second && hour == 2 && minute == 59) {
             return Err(InvalidGlonassDateTime::NonLeapSecondDateTime {
                 date,
                 hour,
@@ -141,9 +142,13 @@ where
             Glonasst::EPOCH.time_since_epoch() + days_since_scale_epoch;
         let date = Date::from_time_since_epoch(days_since_universal_epoch);
 
+        // Leap seconds are inserted at the end of the UTC day, which coincides with 03:00 Moscow
+        // time. Hence, a leap second shows up as 02:59:60 on the next civil day in GLONASST. The
+        // leap second count is only updated after the leap second itself, so the factored date is
+        // already the Moscow date on which the leap second occurs.
         if is_leap_second {
-            let date = (date - Days::new(1)).try_cast().expect("Call of `datetime_from_time_point` results in date outside of representable range of `i32`");
-            (date, 23, 59, 60)
+            let date = date.try_cast().expect("Call of `datetime_from_time_point` results in date outside of representable range of `i32`");
+            (date, 2, 59, 60)
         } else {
             (
             // We must narrow-cast all results, but only the cast of `date` may fail. The rest will
@@ -204,3 +209,28 @@ fn date_decomposition() {
     date_roundtrip(1643, Month::January, 4, 1, 1, 33);
     date_roundtrip(1996, Month::January, 1, 3, 0, 0);
 }
+
+/// Verifies that the 2016-12-31 UTC leap second is represented as 2017-01-01T02:59:60 in
+/// GLONASST, and that the seconds surrounding it convert correctly in both directions.
+#[test]
+fn leap_second_on_offset_boundary() {
+    use crate::UtcTime;
+    let utc = UtcTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 60).unwrap();
+    let glonasst = GlonassTime::from_historic_datetime(2017, Month::January, 1, 2, 59, 60).unwrap();
+    assert_eq!(utc.into_time_scale(), glonasst);
+    assert_eq!(utc, glonasst.into_time_scale());
+    date_roundtrip(2017, Month::January, 1, 2, 59, 60);
+
+    let before = GlonassTime::from_historic_datetime(2017, Month::January, 1, 2, 59, 59).unwrap();
+    let after = GlonassTime::from_historic_datetime(2017, Month::January, 1, 3, 0, 0).unwrap();
+    assert_eq!(glonasst - before, Seconds::new(1));
+    assert_eq!(after - glonasst, Seconds::new(1));
+    date_roundtrip(2017, Month::January, 1, 2, 59, 59);
+    date_roundtrip(2017, Month::January, 1, 3, 0, 0);
+
+    // A leap second at midnight Moscow time does not exist.
+    assert!(GlonassTime::from_historic_datetime(2016, Month::December, 31, 23, 59, 60).is_err());
+    // Nor does one at any other time of the leap second day.
+    assert!(GlonassTime::from_historic_datetime(2017, Month::January, 1, 2, 58, 60).is_err());
+    assert!(GlonassTime::from_historic_datetime(2017, Month::January, 1, 12, 0, 60).is_err());
+}
@@ -1,9 +1,15 @@
 //! Implementation of the time broadcast by the Global Positioning System (GPS).
 
+use core::ops::{Add, Sub};
+
+use num_traits::AsPrimitive;
+
 use crate::{
-    Date, Duration, Month, Seconds, TerrestrialTime, TimePoint, UniformDateTimeScale,
+    ConvertUnit, Date, Duration, Fraction, IntoTimeScale, LeapSecondProvider, Month, MulFloor,
+    Seconds, StaticLeapSecondProvider, TerrestrialTime, TimePoint, TryIntoExact,
+    UniformDateTimeScale, UnitRatio, UtcTime, Weeks,
     time_scale::{AbsoluteTimeScale, TimeScale},
-    units::Second,
+    units::{Second, SecondsPerWeek},
 };
 
 pub type GpsTime<Representation = i64, Period = Second> = TimePoint<Gpst, Representation, Period>;
@@ -25,6 +31,8 @@ impl AbsoluteTimeScale for Gpst {
         Ok(epoch) => epoch,
         Err(_) => unreachable!(),
     };
+
+    const IS_UNIFORM_DATE_TIME: bool = true;
 }
 
 impl UniformDateTimeScale for Gpst {}
@@ -35,6 +43,67 @@ impl TerrestrialTime for Gpst {
     const TAI_OFFSET: Duration<Self::Representation, Self::Period> = Seconds::new(-19);
 }
 
+impl<Representation, Period> GpsTime<Representation, Period>
+where
+    Period: UnitRatio + ?Sized,
+{
+    /// Splits this GPS time into the week number and time-of-week, as commonly used in GPS
+    /// navigation messages. Weeks are counted from the GPS epoch, 1980-01-06, without rollover.
+    pub fn to_week_tow(&self) -> (Weeks<Representation>, Duration<Representation, Period>)
+    where
+        Representation: Copy
+            + MulFloor<Fraction, Output = Representation>
+            + Sub<Representation, Output = Representation>
+            + ConvertUnit<SecondsPerWeek, Period>,
+    {
+        self.time_since_epoch().factor_out::<SecondsPerWeek>()
+    }
+
+    /// Constructs a GPS time from a week number (counted from the GPS epoch, without rollover) and
+    /// the time-of-week elapsed since the start of that week.
+    pub fn from_week_tow(
+        week: Weeks<Representation>,
+        time_of_week: Duration<Representation, Period>,
+    ) -> Self
+    where
+        Representation:
+            Add<Representation, Output = Representation> + ConvertUnit<SecondsPerWeek, Period>,
+    {
+        Self::from_time_since_epoch(week.into_unit() + time_of_week)
+    }
+
+    /// Returns the total number of seconds elapsed since the GPS epoch, 1980-01-06, as a scalar.
+    /// This is the form in which many GNSS post-processing tools exchange time stamps.
+    pub fn seconds_since_gps_epoch(&self) -> f64
+    where
+        Representation: Copy + AsPrimitive<f64>,
+        Period: UnitRatio,
+    {
+        self.time_since_epoch().as_seconds_f64()
+    }
+}
+
+impl<Representation, Period> GpsTime<Representation, Period>
+where
+    Representation: Copy + MulFloor<Fraction, Output = Representation> + TryIntoExact<i64>,
+    Period: UnitRatio + ?Sized,
+{
+    /// Returns the GPS - UTC offset applicable at this instant, in whole seconds: this is the
+    /// number of leap seconds introduced since the GPS epoch, as broadcast in the GPS navigation
+    /// message. Leap seconds are obtained from the static leap second table. During a leap second
+    /// itself, the offset from before the leap second is returned.
+    pub fn utc_offset_seconds(&self) -> i8 {
+        let gps_time: GpsTime<i64, Second> = self
+            .floor::<Second>()
+            .try_cast()
+            .unwrap_or_else(|_| panic!("GPS time cannot be expressed as `i64` seconds"));
+        let utc_time: UtcTime<i64, Second> = gps_time.into_time_scale();
+        let (_, leap_seconds) = StaticLeapSecondProvider {}.leap_seconds_at_time(utc_time);
+        // TAI - UTC is at most a few dozen seconds, and GPS - UTC is that minus 19 seconds.
+        leap_seconds.count() as i8 + Gpst::TAI_OFFSET.count()
+    }
+}
+
 /// Compares with a known timestamp as obtained from Vallado and McClain's "Fundamentals of
 /// Astrodynamics".
 #[test]
@@ -45,3 +114,48 @@ fn known_timestamps() {
     let gpst = GpsTime::from_historic_datetime(2004, Month::May, 14, 16, 43, 13).unwrap();
     assert_eq!(tai, gpst.into_time_scale());
 }
+
+/// Verifies week number and time-of-week computation against a known GPS week boundary.
+#[test]
+fn week_time_of_week() {
+    let time = GpsTime::from_historic_datetime(2020, Month::January, 8, 12, 0, 0).unwrap();
+    let (week, time_of_week) = time.to_week_tow();
+    assert_eq!(week, Weeks::new(2087));
+    assert_eq!(time_of_week, Seconds::new(3 * 86400 + 12 * 3600));
+    assert_eq!(GpsTime::from_week_tow(week, time_of_week), time);
+}
+
+/// Verifies that the scalar number of seconds since the GPS epoch matches the week number and
+/// time-of-week representation.
+#[test]
+fn seconds_since_epoch() {
+    let gpst = GpsTime::from_historic_datetime(2020, Month::January, 8, 12, 0, 0).unwrap();
+    let (week, time_of_week) = gpst.to_week_tow();
+    assert_eq!(
+        gpst.seconds_since_gps_epoch(),
+        (week.count() * 604800 + time_of_week.count()) as f64
+    );
+    assert_eq!(
+        GpsTime::<i64>::from_week_tow(Weeks::new(0), Seconds::new(0)).seconds_since_gps_epoch(),
+        0.
+    );
+
+    let gpst: GpsTime<i64, crate::units::Milli> = gpst.into_unit();
+    assert_eq!(
+        gpst.seconds_since_gps_epoch(),
+        2087. * 604800. + 3. * 86400. + 12. * 3600.
+    );
+}
+
+/// Verifies that the GPS - UTC offset matches the number of leap seconds since the GPS epoch.
+#[test]
+fn utc_offset() {
+    let gpst = GpsTime::from_historic_datetime(2020, Month::January, 8, 12, 0, 0).unwrap();
+    assert_eq!(gpst.utc_offset_seconds(), 18);
+    let gpst = GpsTime::from_historic_datetime(2016, Month::December, 31, 12, 0, 0).unwrap();
+    assert_eq!(gpst.utc_offset_seconds(), 17);
+    let gpst = GpsTime::<i64>::from_week_tow(Weeks::new(0), Seconds::new(0));
+    assert_eq!(gpst.utc_offset_seconds(), 0);
+    let gpst = GpsTime::<f64, Second>::from_time_since_epoch(Seconds::new(1_262_304_000.5));
+    assert_eq!(gpst.utc_offset_seconds(), 18);
+}
@@ -1,7 +1,9 @@
 //! Representation of Galileo System Time (GST), which is broadcast by the Galileo constellation.
 
+use num_traits::AsPrimitive;
+
 use crate::{
-    Date, Duration, Month, Seconds, TerrestrialTime, TimePoint, UniformDateTimeScale,
+    Date, Duration, Month, Seconds, TerrestrialTime, TimePoint, UniformDateTimeScale, UnitRatio,
     time_scale::{AbsoluteTimeScale, TimeScale},
     units::Second,
 };
@@ -26,6 +28,8 @@ impl AbsoluteTimeScale for Gst {
         Ok(epoch) => epoch,
         Err(_) => unreachable!(),
     };
+
+    const IS_UNIFORM_DATE_TIME: bool = true;
 }
 
 impl UniformDateTimeScale for Gst {}
@@ -36,6 +40,21 @@ impl TerrestrialTime for Gst {
     const TAI_OFFSET: Duration<Self::Representation, Self::Period> = Seconds::new(-19);
 }
 
+impl<Representation, Period> GalileoTime<Representation, Period>
+where
+    Period: UnitRatio + ?Sized,
+{
+    /// Returns the total number of seconds elapsed since the Galileo epoch, 1999-08-22, as a
+    /// scalar, in the same manner as `GpsTime::seconds_since_gps_epoch`.
+    pub fn seconds_since_galileo_epoch(&self) -> f64
+    where
+        Representation: Copy + AsPrimitive<f64>,
+        Period: UnitRatio,
+    {
+        self.time_since_epoch().as_seconds_f64()
+    }
+}
+
 /// Compares with a known timestamp as obtained from Vallado and McClain's "Fundamentals of
 /// Astrodynamics". Note that that timestamp is given for GPS time: Galileo system time is always
 /// aligned with GPS.
@@ -47,3 +66,16 @@ fn known_timestamps() {
     let gst = GalileoTime::from_historic_datetime(2004, Month::May, 14, 16, 43, 13).unwrap();
     assert_eq!(tai, gst.into_time_scale());
 }
+
+/// Verifies that the Galileo epoch coincides with GPS week 1024, such that the elapsed seconds
+/// since the epoch differ by exactly that number of weeks.
+#[test]
+fn seconds_since_epoch() {
+    use crate::GpsTime;
+    let gst = GalileoTime::from_historic_datetime(2020, Month::January, 8, 12, 0, 0).unwrap();
+    let gpst = GpsTime::from_historic_datetime(2020, Month::January, 8, 12, 0, 0).unwrap();
+    assert_eq!(
+        gst.seconds_since_galileo_epoch(),
+        gpst.seconds_since_gps_epoch() - 1024. * 604800.
+    );
+}
@@ -0,0 +1,138 @@
+//! Runtime identification of the time scales supported by this library. Time scales are normally
+//! selected at compile time, through the `Scale` parameter of `TimePoint`. When the time scale is
+//! only known at runtime (for example, because it is read from a file), a `TimeScaleId` may be
+//! used to dispatch on it instead.
+
+use core::{fmt::Display, str::FromStr};
+
+use crate::{
+    errors::UnknownTimeScale,
+    time_scale::{Bdt, Glonasst, Gpst, Gst, Qzsst, Tai, Tcg, TimeScale, Tt, Utc},
+};
+
+/// Runtime tag identifying one of the built-in time scales.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TimeScaleId {
+    Tai,
+    Utc,
+    Tt,
+    Tcg,
+    Gpst,
+    Gst,
+    Bdt,
+    Qzsst,
+    Glonasst,
+}
+
+impl TimeScaleId {
+    /// All built-in time scales, in declaration order.
+    pub const ALL: [Self; 9] = [
+        Self::Tai,
+        Self::Utc,
+        Self::Tt,
+        Self::Tcg,
+        Self::Gpst,
+        Self::Gst,
+        Self::Bdt,
+        Self::Qzsst,
+        Self::Glonasst,
+    ];
+
+    /// Returns the full (English) name of the identified time scale.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Tai => Tai::NAME,
+            Self::Utc => Utc::NAME,
+            Self::Tt => Tt::NAME,
+            Self::Tcg => Tcg::NAME,
+            Self::Gpst => Gpst::NAME,
+            Self::Gst => Gst::NAME,
+            Self::Bdt => Bdt::NAME,
+            Self::Qzsst => Qzsst::NAME,
+            Self::Glonasst => Glonasst::NAME,
+        }
+    }
+
+    /// Returns the abbreviation used to represent the identified time scale.
+    pub const fn abbreviation(&self) -> &'static str {
+        match self {
+            Self::Tai => Tai::ABBREVIATION,
+            Self::Utc => Utc::ABBREVIATION,
+            Self::Tt => Tt::ABBREVIATION,
+            Self::Tcg => Tcg::ABBREVIATION,
+            Self::Gpst => Gpst::ABBREVIATION,
+            Self::Gst => Gst::ABBREVIATION,
+            Self::Bdt => Bdt::ABBREVIATION,
+            Self::Qzsst => Qzsst::ABBREVIATION,
+            Self::Glonasst => Glonasst::ABBREVIATION,
+        }
+    }
+
+    /// Determines the time scale of a formatted time point, based on the time scale abbreviation
+    /// that follows the final space in the string. Returns `None` if there is no such suffix or if
+    /// it does not identify a built-in time scale.
+    pub fn from_time_point_suffix(string: &str) -> Option<Self> {
+        let (_, suffix) = string.rsplit_once(' ')?;
+        suffix.parse().ok()
+    }
+}
+
+impl Display for TimeScaleId {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+impl FromStr for TimeScaleId {
+    type Err = UnknownTimeScale;
+
+    /// Parses a time scale abbreviation. Only exact (case-sensitive) matches are accepted.
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|id| id.abbreviation() == string)
+            .ok_or(UnknownTimeScale)
+    }
+}
+
+impl TryFrom<&str> for TimeScaleId {
+    type Error = UnknownTimeScale;
+
+    fn try_from(string: &str) -> Result<Self, Self::Error> {
+        string.parse()
+    }
+}
+
+/// Verifies that all built-in abbreviations round-trip through their string representation.
+#[cfg(feature = "std")]
+#[test]
+fn abbreviation_roundtrip() {
+    for id in TimeScaleId::ALL {
+        assert_eq!(id.to_string().parse::<TimeScaleId>(), Ok(id));
+        assert_eq!(TimeScaleId::try_from(id.abbreviation()), Ok(id));
+    }
+    assert_eq!("GPST".parse(), Ok(TimeScaleId::Gpst));
+    assert_eq!("gpst".parse::<TimeScaleId>(), Err(UnknownTimeScale));
+    assert_eq!("UT1".parse::<TimeScaleId>(), Err(UnknownTimeScale));
+}
+
+/// Verifies that the time scale of formatted time points can be determined from their suffix.
+#[cfg(feature = "std")]
+#[test]
+fn time_point_suffix() {
+    use crate::{GpsTime, Month, TaiTime};
+    let time = TaiTime::from_historic_datetime(2024, Month::January, 15, 0, 0, 0).unwrap();
+    assert_eq!(
+        TimeScaleId::from_time_point_suffix(&time.to_string()),
+        Some(TimeScaleId::Tai)
+    );
+    let time = GpsTime::from_historic_datetime(2024, Month::January, 15, 0, 0, 0).unwrap();
+    assert_eq!(
+        TimeScaleId::from_time_point_suffix(&time.to_string()),
+        Some(TimeScaleId::Gpst)
+    );
+    assert_eq!(
+        TimeScaleId::from_time_point_suffix("2024-01-15T00:00:00"),
+        None
+    );
+}
@@ -1,7 +1,7 @@
 //! Leap seconds are applied when converting date-time pairs to underlying time scales, to better
 //! align those time scales with the human-centric time based on the Earth's rotation (UT1).
 
-use crate::{Date, FromDateTime, IntoDateTime, Second, Seconds, UtcTime};
+use crate::{Date, FromDateTime, IntoDateTime, Month, Second, Seconds, UtcTime};
 
 /// Since leap seconds are hard to predict in advance (due to irregular variations in the Earth's
 /// rotation), their insertion and deletion is based on short-term predictions. This means that
@@ -23,6 +23,25 @@ pub trait LeapSecondProvider {
     /// Given some UTC time, returns the number of leap seconds that apply, and whether the
     /// requested date-time is a leap second (exactly).
     fn leap_seconds_at_time(&self, utc_time: UtcTime<i64, Second>) -> (bool, Seconds<u8>);
+
+    /// Returns the last UTC date for which this provider is known to be accurate, if any. Beyond
+    /// this date, the leap second count is assumed to remain constant, which may turn out to be
+    /// wrong once new leap seconds are announced. By default, providers are assumed to be valid
+    /// indefinitely, which is indicated by returning `None`.
+    fn valid_until(&self) -> Option<Date<i32>> {
+        None
+    }
+
+    /// Returns the net number of leap seconds inserted in the half-open interval of UTC dates
+    /// `[start, end)`: a leap second inserted at the end of `start` is included, but one inserted
+    /// at the end of `end` is not. If `end` precedes `start`, the interval is traversed backwards
+    /// and the result is negated, such that `leap_seconds_between(a, b)` always equals
+    /// `-leap_seconds_between(b, a)`.
+    fn leap_seconds_between(&self, start: Date<i32>, end: Date<i32>) -> i32 {
+        let (_, leap_seconds_before_start) = self.leap_seconds_on_date(start);
+        let (_, leap_seconds_before_end) = self.leap_seconds_on_date(end);
+        i32::from(leap_seconds_before_end.count()) - i32::from(leap_seconds_before_start.count())
+    }
 }
 
 /// This trait is the leap second equivalent of `FromDateTime`. It permits the creation of time
@@ -103,6 +122,16 @@ pub struct StaticLeapSecondProvider {}
 pub const STATIC_LEAP_SECOND_PROVIDER: StaticLeapSecondProvider = StaticLeapSecondProvider {};
 
 impl LeapSecondProvider for StaticLeapSecondProvider {
+    /// The static table is based on the IERS leap second list that expires on 28 June 2026: up
+    /// to that date, it is known that no further leap seconds have been introduced.
+    fn valid_until(&self) -> Option<Date<i32>> {
+        const VALID_UNTIL: Date<i32> = match Date::from_historic_date(2026, Month::June, 28) {
+            Ok(date) => date,
+            Err(_) => unreachable!(),
+        };
+        Some(VALID_UNTIL)
+    }
+
     /// For the static leap seconds provider, we just use a generated jump table that maps from
     /// days (expressed as `Date<i32>`, i.e., `Days<i32>` since 1970-01-01) to whether that day
     /// contains a leap second and what the total leap second count is. It is sorted in reverse,
@@ -240,3 +269,23 @@ impl LeapSecondProvider for StaticLeapSecondProvider {
         (is_leap_second, Seconds::new(leap_seconds))
     }
 }
+
+/// Verifies that the number of leap seconds inserted between two dates is computed correctly,
+/// including for reversed and empty intervals.
+#[test]
+fn leap_seconds_between() {
+    use crate::{GregorianDate, Month};
+    let date =
+        |year, month, day| -> Date<i32> { GregorianDate::new(year, month, day).unwrap().into() };
+    let provider = StaticLeapSecondProvider {};
+    let start = date(2014, Month::January, 1);
+    let end = date(2017, Month::January, 1);
+    assert_eq!(provider.leap_seconds_between(start, end), 2);
+    assert_eq!(provider.leap_seconds_between(end, start), -2);
+    assert_eq!(provider.leap_seconds_between(start, start), 0);
+
+    // The leap second at the end of 2016-12-31 belongs to that day, not the next.
+    let last_day = date(2016, Month::December, 31);
+    assert_eq!(provider.leap_seconds_between(last_day, end), 1);
+    assert_eq!(provider.leap_seconds_between(start, last_day), 1);
+}
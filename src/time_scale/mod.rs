@@ -1,27 +1,43 @@
 //! Implementation of timekeeping according to different time scales.
 
 mod convert;
-pub use convert::{FromTimeScale, IntoTimeScale};
+pub use convert::{
+    FromTimeScale, IntoTimeScale, TryFromTimeScale, convert_time_scale, try_convert_time_scale,
+};
 mod datetime;
 pub use datetime::{
     FromDateTime, FromFineDateTime, IntoDateTime, IntoFineDateTime, UniformDateTimeScale,
 };
 
+mod any;
+pub use any::AnyTimePoint;
 mod bdt;
 pub use bdt::{Bdt, BeiDouTime};
+mod eop;
+pub use eop::{EopLeapSeconds, EopProvider};
 mod glonasst;
 pub use glonasst::{GlonassTime, Glonasst};
 mod gpst;
 pub use gpst::{GpsTime, Gpst};
 mod gst;
 pub use gst::{GalileoTime, Gst};
+mod id;
+pub use id::TimeScaleId;
 mod leap_seconds;
 pub use leap_seconds::{
     FromLeapSecondDateTime, IntoLeapSecondDateTime, LeapSecondProvider,
     STATIC_LEAP_SECOND_PROVIDER, StaticLeapSecondProvider,
 };
+mod offset;
+pub use offset::OffsetTime;
 mod qzsst;
 pub use qzsst::{QzssTime, Qzsst};
+#[cfg(feature = "std")]
+mod rinex;
+#[cfg(feature = "std")]
+pub use rinex::RinexLeapSecondProvider;
+mod sidereal;
+pub use sidereal::gmst;
 mod tai;
 pub use tai::{Tai, TaiTime};
 mod tcg;
@@ -30,10 +46,14 @@ mod tt;
 pub use tt::{Tt, TtTime};
 mod terrestrial_time;
 pub use terrestrial_time::TerrestrialTime;
+#[cfg(feature = "std")]
+mod tzif;
+#[cfg(feature = "std")]
+pub use tzif::TzifLeapSecondProvider;
 mod utc;
 pub use utc::{Utc, UtcTime};
 
-use crate::Date;
+use crate::{Date, Second, Seconds, TimePoint};
 
 /// A `TimeScale` identifies the relativistic time scale in which some `TimePoint` is expressed.
 pub trait TimeScale {
@@ -42,6 +62,17 @@ pub trait TimeScale {
 
     /// The abbreviated string used to represent this time scale.
     const ABBREVIATION: &'static str;
+
+    /// Returns the zero instant of this time scale (its epoch), expressed in the `Target` time
+    /// scale. For example, `Gpst::epoch_as::<Tai>()` returns the GPS epoch as TAI time point. This
+    /// is the same as converting a zero `TimePoint` of this scale using `into_time_scale`.
+    fn epoch_as<Target>() -> TimePoint<Target, i64, Second>
+    where
+        Self: Sized,
+        TimePoint<Target, i64, Second>: FromTimeScale<Self, i64, Second>,
+    {
+        TimePoint::from_time_scale(TimePoint::from_time_since_epoch(Seconds::new(0)))
+    }
 }
 
 /// `TimeScale` that is fixed in calendrical time by an absolute epoch. Note that this does not yet
@@ -58,4 +89,25 @@ pub trait AbsoluteTimeScale: TimeScale {
     /// UTC, but it would not necessarily be wrong to use a different date here. In practice, of
     /// course, it is more convenient to choose the actual epoch where one is defined.
     const EPOCH: Date<i32>;
+
+    /// Runtime counterpart of the `UniformDateTimeScale` marker trait: must be `true` if and only
+    /// if this scale implements that trait. Permits generic code to check for date-time uniformity
+    /// without requiring it at the type level.
+    const IS_UNIFORM_DATE_TIME: bool = false;
+}
+
+/// Verifies that the epoch of one time scale may be expressed in another.
+#[test]
+fn epoch_as() {
+    use crate::Month;
+    let gps_epoch = Gpst::epoch_as::<Tai>();
+    assert_eq!(
+        gps_epoch,
+        TaiTime::from_historic_datetime(1980, Month::January, 6, 0, 0, 19).unwrap()
+    );
+    assert_eq!(
+        Tai::epoch_as::<Gpst>().time_since_epoch(),
+        -gps_epoch.time_since_epoch()
+    );
+    assert_eq!(Tai::epoch_as::<Tai>().time_since_epoch(), Seconds::new(0));
 }
@@ -0,0 +1,315 @@
+//! Implementation of local times at a fixed offset from UTC. Full time zone support (including
+//! daylight saving time transitions) is out of scope for this library, but fixed-offset local times
+//! suffice for many applications: for example, those that only need to present some instant in the
+//! local time of a user.
+
+use core::fmt::{Alignment, Display, Write};
+
+use num_traits::Zero;
+
+use crate::{
+    Date, DateTimeParts, Days, Duration, FractionalDigits, FromFineDateTime, GregorianDate,
+    IntoFineDateTime, Minutes, Second, UtcTime,
+    errors::{InvalidTimeOfDay, InvalidUtcDateTime, InvalidUtcOffset},
+    format::{FormatBuffer, pad},
+    units::UnitRatio,
+};
+
+/// The number of minutes in a day, used to wrap local times around midnight.
+const MINUTES_PER_DAY: i32 = 1440;
+
+/// A UTC time point, combined with a fixed offset that is to be applied to obtain the local time.
+/// The underlying instant is always stored in UTC, such that leap seconds remain handled by the
+/// UTC time scale: the offset only affects how that instant is presented as a local date-time.
+///
+/// Equality compares both the instant and the offset: the same instant at two different offsets
+/// is not considered equal. Compare `utc()` to determine whether two instants coincide.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OffsetTime<Representation = i64, Period = Second> {
+    utc: UtcTime<Representation, Period>,
+    offset: Minutes<i32>,
+}
+
+impl<Representation, Period> OffsetTime<Representation, Period> {
+    /// Combines a UTC time point with an offset in minutes, which is positive east of Greenwich.
+    /// Returns an error if the offset is not strictly within 24 hours of UTC: actual time zones
+    /// stay well within that range, and it guarantees that applying the offset to a time-of-day
+    /// never shifts the date by more than a single day.
+    pub fn new(
+        utc: UtcTime<Representation, Period>,
+        offset: Minutes<i32>,
+    ) -> Result<Self, InvalidUtcOffset> {
+        check_offset(offset)?;
+        Ok(Self { utc, offset })
+    }
+
+    /// Returns the instant described by this local time, as UTC time point.
+    pub const fn utc(&self) -> UtcTime<Representation, Period>
+    where
+        Representation: Copy,
+    {
+        self.utc
+    }
+
+    /// Returns the offset of this local time with respect to UTC.
+    pub const fn offset(&self) -> Minutes<i32> {
+        self.offset
+    }
+
+    /// Returns the same instant, but expressed at some other offset from UTC. Fails under the same
+    /// conditions as `new`.
+    pub fn with_offset(self, offset: Minutes<i32>) -> Result<Self, InvalidUtcOffset>
+    where
+        Representation: Copy,
+    {
+        Self::new(self.utc, offset)
+    }
+}
+
+impl<Representation, Period> OffsetTime<Representation, Period>
+where
+    UtcTime<Representation, Period>: IntoFineDateTime<Representation, Period>,
+{
+    /// Maps this time point towards the corresponding local date and time-of-day. The offset is
+    /// applied to the hours and minutes only, such that a leap second is still represented as the
+    /// 60th second of its local minute.
+    pub fn into_local_fine_datetime(
+        self,
+    ) -> (Date<i32>, u8, u8, u8, Duration<Representation, Period>) {
+        let (date, hour, minute, second, subseconds) = self.utc.into_fine_datetime();
+        let (date, hour, minute) = shift(date, hour, minute, self.offset.count());
+        (date, hour, minute, second, subseconds)
+    }
+
+    /// Splits this time point into its local date-time components, with the date expressed in the
+    /// proleptic Gregorian calendar.
+    pub fn to_local_parts(self) -> DateTimeParts<Representation, Period> {
+        let (date, hour, minute, second, subseconds) = self.into_local_fine_datetime();
+        let date = GregorianDate::from_date(date);
+        DateTimeParts {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+            hour,
+            minute,
+            second,
+            subseconds,
+        }
+    }
+}
+
+impl<Representation, Period> OffsetTime<Representation, Period>
+where
+    UtcTime<Representation, Period>:
+        FromFineDateTime<Representation, Period, Error = InvalidUtcDateTime>,
+{
+    /// Constructs a time point from a local date and time-of-day, observed at the given offset
+    /// from UTC. The offset is subtracted to obtain the corresponding UTC date-time, which is then
+    /// validated as such: a local time with second 60 is only accepted where UTC has a leap
+    /// second.
+    pub fn from_local_fine_datetime(
+        date: Date<i32>,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        subseconds: Duration<Representation, Period>,
+        offset: Minutes<i32>,
+    ) -> Result<Self, InvalidUtcDateTime> {
+        check_offset(offset)?;
+        if hour >= 24 || minute >= 60 {
+            return Err(InvalidTimeOfDay {
+                hour,
+                minute,
+                second,
+            }
+            .into());
+        }
+        let (date, hour, minute) = shift(date, hour, minute, -offset.count());
+        let utc = UtcTime::from_fine_datetime(date, hour, minute, second, subseconds)?;
+        Ok(Self { utc, offset })
+    }
+}
+
+impl<Representation, Period> UtcTime<Representation, Period> {
+    /// Expresses this instant as local time at the given offset from UTC, which is positive east of
+    /// Greenwich. Fails under the same conditions as `OffsetTime::new`.
+    pub fn to_local(
+        self,
+        offset: Minutes<i32>,
+    ) -> Result<OffsetTime<Representation, Period>, InvalidUtcOffset> {
+        OffsetTime::new(self, offset)
+    }
+}
+
+impl<Representation, Period> From<OffsetTime<Representation, Period>>
+    for UtcTime<Representation, Period>
+{
+    fn from(local: OffsetTime<Representation, Period>) -> Self {
+        local.utc
+    }
+}
+
+impl<Representation, Period> Display for OffsetTime<Representation, Period>
+where
+    UtcTime<Representation, Period>: IntoFineDateTime<Representation, Period>,
+    Duration<Representation, Period>: Zero,
+    Representation: Copy + FractionalDigits,
+    Period: UnitRatio,
+{
+    /// Formats this time point as local date-time, followed by its offset from UTC: for example,
+    /// "2024-03-01T17:30:00+05:30". The precision flag determines the maximum number of subsecond
+    /// digits.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut buffer = FormatBuffer::<160>::new();
+        let parts = Self {
+            utc: self.utc,
+            offset: self.offset,
+        }
+        .to_local_parts();
+        match f.precision() {
+            Some(precision) => write!(buffer, "{parts:.precision$}")?,
+            None => write!(buffer, "{parts}")?,
+        }
+        let offset = self.offset.count();
+        let sign = if offset < 0 { '-' } else { '+' };
+        let offset = offset.unsigned_abs();
+        write!(buffer, "{sign}{:02}:{:02}", offset / 60, offset % 60)?;
+        pad(f, buffer.as_str(), Alignment::Left)
+    }
+}
+
+/// Verifies that an offset lies strictly within a day of UTC.
+fn check_offset(offset: Minutes<i32>) -> Result<(), InvalidUtcOffset> {
+    let minutes = offset.count();
+    if minutes <= -MINUTES_PER_DAY || minutes >= MINUTES_PER_DAY {
+        Err(InvalidUtcOffset { minutes })
+    } else {
+        Ok(())
+    }
+}
+
+/// Shifts a local date, hour, and minute by the given number of minutes, carrying over into the
+/// date where needed.
+fn shift(date: Date<i32>, hour: u8, minute: u8, offset: i32) -> (Date<i32>, u8, u8) {
+    let minutes = i32::from(hour) * 60 + i32::from(minute) + offset;
+    let days = minutes.div_euclid(MINUTES_PER_DAY);
+    let minutes = minutes.rem_euclid(MINUTES_PER_DAY);
+    (
+        date + Days::new(days),
+        (minutes / 60) as u8,
+        (minutes % 60) as u8,
+    )
+}
+
+/// Verifies that a UTC instant may be expressed in a local time at +05:30 and back.
+#[test]
+fn local_roundtrip() {
+    use crate::{Month, Seconds};
+    let utc = UtcTime::from_gregorian_datetime(2024, Month::March, 1, 20, 45, 10).unwrap();
+    let offset = Minutes::new(5 * 60 + 30);
+    let local = utc.to_local(offset).unwrap();
+    let (date, hour, minute, second, _) = local.into_local_fine_datetime();
+    assert_eq!(
+        date,
+        Date::from_gregorian_date(2024, Month::March, 2).unwrap()
+    );
+    assert_eq!((hour, minute, second), (2, 15, 10));
+    assert_eq!(
+        OffsetTime::from_local_fine_datetime(date, hour, minute, second, Seconds::new(0), offset),
+        Ok(local)
+    );
+    assert_eq!(UtcTime::from(local), utc);
+
+    let west = local.with_offset(Minutes::new(-(3 * 60 + 30))).unwrap();
+    let (date, hour, minute, _, _) = west.into_local_fine_datetime();
+    assert_eq!(
+        date,
+        Date::from_gregorian_date(2024, Month::March, 1).unwrap()
+    );
+    assert_eq!((hour, minute), (17, 15));
+    assert_eq!(west.utc(), local.utc());
+    assert_ne!(west, local);
+}
+
+/// Verifies that leap seconds are preserved when shifting into local time, and that local leap
+/// seconds are only accepted where UTC has one.
+#[test]
+fn local_leap_second() {
+    use crate::{Month, Seconds};
+    let utc = UtcTime::from_gregorian_datetime(2016, Month::December, 31, 23, 59, 60).unwrap();
+    let offset = Minutes::new(60);
+    let (date, hour, minute, second, _) = utc.to_local(offset).unwrap().into_local_fine_datetime();
+    assert_eq!(
+        date,
+        Date::from_gregorian_date(2017, Month::January, 1).unwrap()
+    );
+    assert_eq!((hour, minute, second), (0, 59, 60));
+    assert_eq!(
+        OffsetTime::from_local_fine_datetime(date, 0, 59, 60, Seconds::new(0i64), offset)
+            .map(|local| local.utc()),
+        Ok(utc)
+    );
+    assert!(
+        OffsetTime::from_local_fine_datetime(date, 1, 59, 60, Seconds::new(0i64), offset).is_err()
+    );
+    assert!(
+        OffsetTime::from_local_fine_datetime(date, 24, 0, 0, Seconds::new(0i64), offset).is_err()
+    );
+}
+
+/// Verifies that local times are formatted with their offset from UTC.
+#[cfg(feature = "std")]
+#[test]
+fn formatting() {
+    use crate::{MilliSeconds, Month};
+    let utc = UtcTime::from_fine_gregorian_datetime(
+        2024,
+        Month::March,
+        1,
+        12,
+        0,
+        0,
+        MilliSeconds::new(250i64),
+    )
+    .unwrap();
+    let local = utc.to_local(Minutes::new(330)).unwrap();
+    assert_eq!(local.to_string(), "2024-03-01T17:30:00.25+05:30");
+    assert_eq!(format!("{local:.1}"), "2024-03-01T17:30:00.2+05:30");
+    assert_eq!(
+        utc.to_local(Minutes::new(-90)).unwrap().to_string(),
+        "2024-03-01T10:30:00.25-01:30"
+    );
+    assert_eq!(
+        utc.to_local(Minutes::new(0)).unwrap().to_string(),
+        "2024-03-01T12:00:00.25+00:00"
+    );
+}
+
+/// Verifies that offsets of a day or more from UTC are rejected.
+#[test]
+fn offset_range() {
+    use crate::{Month, Seconds};
+    let utc = UtcTime::from_gregorian_datetime(2024, Month::March, 1, 12, 0, 0).unwrap();
+    assert!(utc.to_local(Minutes::new(1439)).is_ok());
+    assert!(utc.to_local(Minutes::new(-1439)).is_ok());
+    assert_eq!(
+        utc.to_local(Minutes::new(1440)),
+        Err(InvalidUtcOffset { minutes: 1440 })
+    );
+    assert_eq!(
+        utc.to_local(Minutes::new(i32::MIN)),
+        Err(InvalidUtcOffset { minutes: i32::MIN })
+    );
+    let date = Date::from_gregorian_date(2024, Month::March, 1).unwrap();
+    assert_eq!(
+        OffsetTime::from_local_fine_datetime(
+            date,
+            12,
+            0,
+            0,
+            Seconds::new(0i64),
+            Minutes::new(i32::MAX)
+        ),
+        Err(InvalidUtcOffset { minutes: i32::MAX }.into())
+    );
+}
@@ -1,8 +1,10 @@
 //! Representation of Quasi-Zenith Satellite System Time (QZSST), which is broadcast by the
 //! Quasi-Zenith Satellite System constellation.
 
+use num_traits::AsPrimitive;
+
 use crate::{
-    Date, Duration, Month, Seconds, TerrestrialTime, TimePoint, UniformDateTimeScale,
+    Date, Duration, Month, Seconds, TerrestrialTime, TimePoint, UniformDateTimeScale, UnitRatio,
     time_scale::{AbsoluteTimeScale, TimeScale},
     units::Second,
 };
@@ -22,10 +24,14 @@ impl TimeScale for Qzsst {
 }
 
 impl AbsoluteTimeScale for Qzsst {
-    const EPOCH: Date<i32> = match Date::from_historic_date(1999, Month::August, 22) {
+    /// QZSST is aligned with GPS time, and its week numbers coincide with those of GPS. Hence,
+    /// both share the same epoch.
+    const EPOCH: Date<i32> = match Date::from_historic_date(1980, Month::January, 6) {
         Ok(epoch) => epoch,
         Err(_) => unreachable!(),
     };
+
+    const IS_UNIFORM_DATE_TIME: bool = true;
 }
 
 impl UniformDateTimeScale for Qzsst {}
@@ -36,6 +42,21 @@ impl TerrestrialTime for Qzsst {
     const TAI_OFFSET: Duration<Self::Representation, Self::Period> = Seconds::new(-19);
 }
 
+impl<Representation, Period> QzssTime<Representation, Period>
+where
+    Period: UnitRatio + ?Sized,
+{
+    /// Returns the total number of seconds elapsed since the QZSS epoch, 1980-01-06 (equal to the
+    /// GPS epoch), as a scalar, in the same manner as `GpsTime::seconds_since_gps_epoch`.
+    pub fn seconds_since_qzss_epoch(&self) -> f64
+    where
+        Representation: Copy + AsPrimitive<f64>,
+        Period: UnitRatio,
+    {
+        self.time_since_epoch().as_seconds_f64()
+    }
+}
+
 /// Compares with a known timestamp as obtained from Vallado and McClain's "Fundamentals of
 /// Astrodynamics". Note that that timestamp is given for GPS time: QZSS time is always aligned
 /// with GPS.
@@ -47,3 +68,17 @@ fn known_timestamps() {
     let qzsst = QzssTime::from_historic_datetime(2004, Month::May, 14, 16, 43, 13).unwrap();
     assert_eq!(tai, qzsst.into_time_scale());
 }
+
+/// Verifies that QZSST shares its epoch with GPS time, such that the elapsed seconds since the
+/// epoch coincide for equal time stamps.
+#[test]
+fn gps_epoch() {
+    use crate::GpsTime;
+    let qzsst = QzssTime::from_historic_datetime(2020, Month::January, 8, 12, 0, 0).unwrap();
+    let gpst = GpsTime::from_historic_datetime(2020, Month::January, 8, 12, 0, 0).unwrap();
+    assert_eq!(
+        qzsst.seconds_since_qzss_epoch(),
+        gpst.seconds_since_gps_epoch()
+    );
+    assert_eq!(qzsst.time_since_epoch(), gpst.time_since_epoch());
+}
@@ -0,0 +1,262 @@
+//! Leap second provider based on the `LEAP SECONDS` header record of RINEX navigation files. GNSS
+//! processing chains usually already ingest such files, so their leap second information may be
+//! reused directly instead of maintaining a separate leap second table.
+
+use crate::{
+    Bdt, Date, Days, Gpst, LeapSecondProvider, Second, Seconds, TerrestrialTime, Utc, UtcTime,
+    errors::RinexLeapSecondParsingError, time_scale::AbsoluteTimeScale,
+};
+
+/// The label that identifies a leap second record in a RINEX header, located in columns 61-80.
+const LEAP_SECONDS_LABEL: &str = "LEAP SECONDS";
+
+/// Width of each of the integer fields of the leap second record.
+const FIELD_WIDTH: usize = 6;
+
+/// Leap second provider that is constructed from the `LEAP SECONDS` record found in the header of
+/// RINEX (navigation) files. Such a record states the current number of leap seconds and,
+/// optionally, the number of leap seconds after the next scheduled change, together with the week
+/// and day at whose end that change takes place.
+///
+/// A single record only describes the leap seconds around the epoch of the file it was taken
+/// from: dates before a scheduled change are all assumed to have the current number of leap
+/// seconds, and dates after it the future number. This makes the provider suitable for processing
+/// the data contained in that file, but not for arbitrary historic dates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct RinexLeapSecondProvider {
+    current: Seconds<u8>,
+    future: Option<(Date<i32>, Seconds<u8>)>,
+}
+
+impl RinexLeapSecondProvider {
+    /// Parses a RINEX `LEAP SECONDS` header line, which consists of the current number of leap
+    /// seconds, the future number of leap seconds, the week number and day number of the change,
+    /// and the time system identifier, each in their own fixed-width column. Only the current
+    /// number of leap seconds is mandatory, as in RINEX 2.
+    ///
+    /// The week and day are interpreted according to the time system identifier: GPS weeks with
+    /// days numbered 1-7 when it is absent or `GPS`, and BeiDou weeks with days numbered 0-6 when
+    /// it is `BDS`. Leap second counts are given with respect to that time system in the header,
+    /// but are stored as the total offset TAI - UTC.
+    pub fn from_header_line(line: &str) -> Result<Self, RinexLeapSecondParsingError> {
+        let line = line.trim_end_matches(['\r', '\n']);
+        let data = match (line.get(..60), line.get(60..)) {
+            (Some(data), Some(label)) if label.trim_end() == LEAP_SECONDS_LABEL => data,
+            _ => return Err(RinexLeapSecondParsingError::MissingLabel),
+        };
+
+        let field = |index: usize| -> Option<&str> {
+            let field = data
+                .get(index * FIELD_WIDTH..(index + 1) * FIELD_WIDTH)?
+                .trim();
+            (!field.is_empty()).then_some(field)
+        };
+        let system = data
+            .get(4 * FIELD_WIDTH..4 * FIELD_WIDTH + 3)
+            .map(str::trim)
+            .unwrap_or_default();
+        let (epoch, tai_offset, first_day) = match system {
+            "" | "GPS" => (Gpst::EPOCH, Gpst::TAI_OFFSET.count(), 1),
+            "BDS" => (Bdt::EPOCH, Bdt::TAI_OFFSET.count(), 0),
+            _ => return Err(RinexLeapSecondParsingError::UnsupportedTimeSystem),
+        };
+        let leap_seconds = |count: &str| -> Result<Seconds<u8>, RinexLeapSecondParsingError> {
+            let count: i16 = lexical_core::parse(count.as_bytes())?;
+            count
+                .checked_sub(i16::from(tai_offset))
+                .and_then(|leap_seconds| u8::try_from(leap_seconds).ok())
+                .map(Seconds::new)
+                .ok_or(RinexLeapSecondParsingError::LeapSecondsOutOfRange { count })
+        };
+
+        let current = match field(0) {
+            Some(current) => leap_seconds(current)?,
+            None => return Err(RinexLeapSecondParsingError::MissingCurrentLeapSeconds),
+        };
+        let future = match (field(1), field(2), field(3)) {
+            (None, None, None) => None,
+            (Some(future), Some(week), Some(day)) => {
+                let future = leap_seconds(future)?;
+                let week: i32 = lexical_core::parse(week.as_bytes())?;
+                let day: u8 = lexical_core::parse(day.as_bytes())?;
+                if !(first_day..first_day + 7).contains(&day) {
+                    return Err(RinexLeapSecondParsingError::InvalidDayNumber { day });
+                }
+                let days = Days::new(week * 7 + i32::from(day - first_day));
+                Some((epoch + days, future))
+            }
+            _ => return Err(RinexLeapSecondParsingError::IncompleteFutureLeapSeconds),
+        };
+        Ok(Self { current, future })
+    }
+
+    /// Returns the number of leap seconds (TAI - UTC) that applies up to the scheduled change, if
+    /// any.
+    pub const fn current_leap_seconds(&self) -> Seconds<u8> {
+        self.current
+    }
+
+    /// Returns the UTC date at whose end the number of leap seconds changes, together with the
+    /// number of leap seconds (TAI - UTC) after that change. Returns `None` if no such change was
+    /// given in the header record.
+    pub const fn future_leap_seconds(&self) -> Option<(Date<i32>, Seconds<u8>)> {
+        self.future
+    }
+}
+
+impl LeapSecondProvider for RinexLeapSecondProvider {
+    fn leap_seconds_on_date(&self, utc_date: Date<i32>) -> (bool, Seconds<u8>) {
+        match self.future {
+            Some((date, future)) if utc_date > date => (false, future),
+            Some((date, future)) if utc_date == date => (future > self.current, self.current),
+            _ => (false, self.current),
+        }
+    }
+
+    /// The new leap second count applies from midnight after the day of the change onwards. Since
+    /// UTC time points count leap seconds, that midnight lies at a whole number of days after the
+    /// UTC epoch, plus the new count of leap seconds.
+    fn leap_seconds_at_time(&self, utc_time: UtcTime<i64, Second>) -> (bool, Seconds<u8>) {
+        let Some((date, future)) = self.future else {
+            return (false, self.current);
+        };
+        let days_since_utc_epoch =
+            i64::from((date.time_since_epoch() - Utc::EPOCH.time_since_epoch()).count()) + 1;
+        let change = days_since_utc_epoch * 86_400 + i64::from(future.count());
+        let seconds_since_utc_epoch = utc_time.time_since_epoch().count();
+        if seconds_since_utc_epoch >= change {
+            (false, future)
+        } else {
+            let is_leap_second = future > self.current && seconds_since_utc_epoch == change - 1;
+            (is_leap_second, self.current)
+        }
+    }
+}
+
+/// Verifies that a leap second record from a RINEX 3 navigation file (announcing the leap second
+/// of 2016-12-31) is parsed into a provider that agrees with the static leap second table around
+/// that leap second.
+#[test]
+fn leap_second_record() {
+    use crate::{
+        FromDateTime, FromLeapSecondDateTime, GlonassTime, IntoTimeScale, Month,
+        StaticLeapSecondProvider,
+    };
+    let line = "    17    18  1929     7                                    LEAP SECONDS        ";
+    let provider = RinexLeapSecondProvider::from_header_line(line).unwrap();
+    let leap_date = Date::from_historic_date(2016, Month::December, 31).unwrap();
+    assert_eq!(provider.current_leap_seconds(), Seconds::new(36));
+    assert_eq!(
+        provider.future_leap_seconds(),
+        Some((leap_date, Seconds::new(37)))
+    );
+
+    let static_provider = StaticLeapSecondProvider {};
+    for date in [
+        leap_date - Days::new(1),
+        leap_date,
+        leap_date + Days::new(1),
+    ] {
+        assert_eq!(
+            provider.leap_seconds_on_date(date),
+            static_provider.leap_seconds_on_date(date)
+        );
+        for (hour, minute, second) in [(0, 0, 0), (23, 59, 59), (23, 59, 60)] {
+            let Ok(utc) = UtcTime::from_datetime(date, hour, minute, second) else {
+                continue;
+            };
+            assert_eq!(
+                provider.leap_seconds_at_time(utc),
+                static_provider.leap_seconds_at_time(utc)
+            );
+        }
+    }
+
+    // The provider may be used in leap second-aware date-time conversions.
+    let glonasst = <GlonassTime as FromLeapSecondDateTime>::from_datetime(
+        leap_date + Days::new(1),
+        2,
+        59,
+        60,
+        &provider,
+    )
+    .unwrap();
+    assert_eq!(
+        glonasst.into_time_scale(),
+        UtcTime::from_datetime(leap_date, 23, 59, 60).unwrap()
+    );
+}
+
+/// Verifies that RINEX 2 and BeiDou leap second records are supported, and that malformed records
+/// are rejected.
+#[test]
+fn malformed_records() {
+    use crate::Month;
+    let provider = RinexLeapSecondProvider::from_header_line(
+        "    18                                                      LEAP SECONDS",
+    )
+    .unwrap();
+    assert_eq!(provider.current_leap_seconds(), Seconds::new(37));
+    assert_eq!(provider.future_leap_seconds(), None);
+
+    let provider = RinexLeapSecondProvider::from_header_line(
+        "     3     4   573     6BDS                                 LEAP SECONDS\n",
+    )
+    .unwrap();
+    assert_eq!(
+        provider.future_leap_seconds(),
+        Some((
+            Date::from_historic_date(2016, Month::December, 31).unwrap(),
+            Seconds::new(37)
+        ))
+    );
+
+    assert_eq!(
+        RinexLeapSecondProvider::from_header_line(
+            "    18                                                      ION ALPHA"
+        ),
+        Err(RinexLeapSecondParsingError::MissingLabel)
+    );
+    assert_eq!(
+        RinexLeapSecondProvider::from_header_line(
+            "                                                            LEAP SECONDS"
+        ),
+        Err(RinexLeapSecondParsingError::MissingCurrentLeapSeconds)
+    );
+    assert_eq!(
+        RinexLeapSecondProvider::from_header_line(
+            "    18    18  1929                                          LEAP SECONDS"
+        ),
+        Err(RinexLeapSecondParsingError::IncompleteFutureLeapSeconds)
+    );
+    assert_eq!(
+        RinexLeapSecondProvider::from_header_line(
+            "    18    18  1929     8                                    LEAP SECONDS"
+        ),
+        Err(RinexLeapSecondParsingError::InvalidDayNumber { day: 8 })
+    );
+    assert_eq!(
+        RinexLeapSecondProvider::from_header_line(
+            "    18    18  1929     7GAL                                 LEAP SECONDS"
+        ),
+        Err(RinexLeapSecondParsingError::UnsupportedTimeSystem)
+    );
+    assert!(
+        RinexLeapSecondProvider::from_header_line(
+            "    1x                                                      LEAP SECONDS"
+        )
+        .is_err()
+    );
+    assert_eq!(
+        RinexLeapSecondProvider::from_header_line(
+            "   -20                                                      LEAP SECONDS"
+        ),
+        Err(RinexLeapSecondParsingError::LeapSecondsOutOfRange { count: -20 })
+    );
+    assert_eq!(
+        RinexLeapSecondProvider::from_header_line(
+            " 32767                                                      LEAP SECONDS"
+        ),
+        Err(RinexLeapSecondParsingError::LeapSecondsOutOfRange { count: 32767 })
+    );
+}
@@ -0,0 +1,86 @@
+//! Computation of Greenwich mean sidereal time (GMST), which relates the orientation of the Earth
+//! to the celestial reference frame. It is needed for, e.g., antenna pointing and the conversion
+//! between Earth-fixed and inertial coordinates.
+
+use core::f64::consts::TAU;
+
+use num_traits::Float;
+
+use crate::{Hours, JulianDay, units::SecondsPerDay};
+
+/// Julian date of the J2000 epoch, 2000-01-01T12:00:00.
+const J2000: f64 = 2_451_545.0;
+
+/// Number of days in a Julian century.
+const DAYS_PER_JULIAN_CENTURY: f64 = 36_525.0;
+
+/// Number of radians in an arcsecond.
+const RADIANS_PER_ARCSECOND: f64 = TAU / 1_296_000.0;
+
+/// Computes Greenwich mean sidereal time (GMST) following the IAU 2006 model of Capitaine et al.
+/// (2003), as also implemented by `iauGmst06` in SOFA. The result is expressed as an angle in
+/// hours, normalized to the range [0, 24).
+///
+/// GMST depends on both the rotation of the Earth and on precession, so it requires the same
+/// instant expressed as two Julian dates:
+/// - `ut1`, the Julian date in UT1, which determines the Earth rotation angle. UT1 is not a time
+///   scale in this library: it may be obtained by applying DUT1 (e.g., from an `EopProvider`) to
+///   a UTC time. For accuracies of about a second, UTC may be used instead.
+/// - `tt`, the Julian date in TT, which determines the accumulated precession. Because precession
+///   is slow, using UT1 here instead introduces an error of only about 3 microarcseconds.
+///
+/// Because a single `f64` Julian date resolves only about 40 microseconds, this is also the
+/// limiting accuracy of the result.
+pub fn gmst(ut1: JulianDay<f64, SecondsPerDay>, tt: JulianDay<f64, SecondsPerDay>) -> Hours<f64> {
+    let ut1 = ut1.time_since_epoch().count();
+    let tt = tt.time_since_epoch().count();
+
+    // Earth rotation angle, in turns. The fraction of the Julian date is added separately to
+    // retain precision in the product with the (large) number of days since J2000.
+    let days_since_j2000 = ut1 - J2000;
+    let earth_rotation_turns =
+        Float::fract(ut1) + 0.779_057_273_264 + 0.002_737_811_911_354_48 * days_since_j2000;
+    let earth_rotation_angle = Float::fract(earth_rotation_turns) * TAU;
+
+    // Accumulated precession in right ascension, as polynomial in the Julian centuries of TT
+    // since J2000, in arcseconds.
+    let t = (tt - J2000) / DAYS_PER_JULIAN_CENTURY;
+    let precession = 0.014_506
+        + (4_612.156_534
+            + (1.391_581_7 + (-0.000_000_44 + (-0.000_029_956 + -0.000_000_036_8 * t) * t) * t)
+                * t)
+            * t;
+
+    let gmst = earth_rotation_angle + precession * RADIANS_PER_ARCSECOND;
+    let gmst = gmst - Float::floor(gmst / TAU) * TAU;
+    Hours::new(gmst * 24.0 / TAU)
+}
+
+/// Verifies the computed GMST against the SOFA reference implementation and the published value at
+/// J2000.
+#[test]
+fn greenwich_mean_sidereal_time() {
+    // Test case of `iauGmst06` in the SOFA test suite, at 2006-01-01T00:00:00.
+    let date = JulianDay::new(2_400_000.5 + 53_736.0);
+    let radians = gmst(date, date).count() * TAU / 24.0;
+    assert!((radians - 1.754_174_971_870_091_2).abs() < 1e-12);
+
+    // At J2000, GMST is 18h41m50.54841s according to the IAU 1982 model. The IAU 2006 model
+    // differs from it by about a millisecond.
+    let j2000 = JulianDay::new(J2000);
+    let hours = gmst(j2000, j2000).count();
+    let published = 18.0 + 41.0 / 60.0 + 50.548_41 / 3_600.0;
+    assert!((hours - published).abs() * 3_600.0 < 2e-3);
+
+    // One sidereal day later (in UT1), GMST has made a full turn.
+    let later = JulianDay::new(J2000 + 0.997_269_566_3);
+    let later_hours = gmst(later, later).count();
+    assert!((later_hours - hours).abs() * 3_600.0 < 1e-2);
+
+    // Results are always normalized to [0, 24) hours.
+    for offset in [-0.3, 0.1, 0.25, 0.8, 1_000.0, -36_525.5] {
+        let date = JulianDay::new(J2000 + offset);
+        let hours = gmst(date, date).count();
+        assert!((0.0..24.0).contains(&hours));
+    }
+}
@@ -25,6 +25,8 @@ impl AbsoluteTimeScale for Tai {
         Ok(epoch) => epoch,
         Err(_) => unreachable!(),
     };
+
+    const IS_UNIFORM_DATE_TIME: bool = true;
 }
 
 impl UniformDateTimeScale for Tai {}
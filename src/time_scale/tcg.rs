@@ -31,6 +31,8 @@ impl AbsoluteTimeScale for Tcg {
         Ok(epoch) => epoch,
         Err(_) => unreachable!(),
     };
+
+    const IS_UNIFORM_DATE_TIME: bool = true;
 }
 
 impl UniformDateTimeScale for Tcg {}
@@ -6,9 +6,13 @@ use core::{
     ops::{Add, Sub},
 };
 
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, Zero};
+
 use crate::{
-    ConvertUnit, Duration, FromTimeScale, TimePoint, TryFromExact, time_scale::AbsoluteTimeScale,
-    units::SecondsPerDay,
+    ConvertUnit, Duration, FromTimeScale, TimePoint, TryFromExact, TryFromTimeScale, UnitRatio,
+    errors::TimeScaleConversionOverflow,
+    time_scale::{AbsoluteTimeScale, UniformDateTimeScale},
+    units::{Milli, SecondsPerDay},
 };
 
 /// In general, "terrestrial time" refers not just to the specific realization TT, but to an
@@ -26,6 +30,21 @@ pub trait TerrestrialTime: AbsoluteTimeScale {
     type Period;
 
     const TAI_OFFSET: Duration<Self::Representation, Self::Period>;
+
+    /// Returns the constant offset of this time scale with respect to TAI, expressed uniformly
+    /// in milliseconds. This permits generic code to reason about the offset of any terrestrial
+    /// time scale without having to deal with its native representation and period.
+    ///
+    /// Only available for time scales without leap seconds: for UTC and GLONASST, `TAI_OFFSET`
+    /// does not describe their actual offset from TAI, which changes with every leap second.
+    fn tai_offset() -> Duration<i64, Milli>
+    where
+        Self: UniformDateTimeScale,
+        Self::Representation: Into<i64>,
+        i64: ConvertUnit<Self::Period, Milli>,
+    {
+        Self::TAI_OFFSET.cast().into_unit()
+    }
 }
 
 impl<ScaleFrom, ScaleInto, Representation, Period> FromTimeScale<ScaleFrom, Representation, Period>
@@ -67,3 +86,75 @@ where
         Self::from_time_since_epoch(time_since_epoch)
     }
 }
+
+/// Checked counterpart of the `FromTimeScale` conversion between terrestrial time scales: fails
+/// instead of panicking when the epoch or scale offsets, or the shifted time point, cannot be
+/// expressed in the underlying representation.
+impl<ScaleFrom, ScaleInto, Representation, Period>
+    TryFromTimeScale<ScaleFrom, Representation, Period>
+    for TimePoint<ScaleInto, Representation, Period>
+where
+    ScaleFrom: TerrestrialTime,
+    ScaleInto: TerrestrialTime,
+    ScaleFrom::Period: UnitRatio,
+    ScaleInto::Period: UnitRatio,
+    Representation: Copy
+        + CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + Zero
+        + From<ScaleFrom::Representation>
+        + From<ScaleInto::Representation>
+        + TryFromExact<i32>
+        + TryFromExact<u128>
+        + ConvertUnit<ScaleFrom::Period, Period>
+        + ConvertUnit<ScaleInto::Period, Period>
+        + ConvertUnit<SecondsPerDay, Period>
+        + PartialOrd,
+    Period: UnitRatio,
+{
+    type Error = TimeScaleConversionOverflow;
+
+    fn try_from_time_scale(
+        time_point: TimePoint<ScaleFrom, Representation, Period>,
+    ) -> Result<Self, Self::Error> {
+        let epoch_offset: Duration<Representation, Period> = ScaleFrom::EPOCH
+            .elapsed_calendar_days_since(ScaleInto::EPOCH)
+            .try_cast()
+            .map_err(|_| TimeScaleConversionOverflow)?
+            .checked_into_unit()
+            .ok_or(TimeScaleConversionOverflow)?;
+        let from_offset: Duration<Representation, Period> = ScaleFrom::TAI_OFFSET
+            .cast()
+            .checked_into_unit()
+            .ok_or(TimeScaleConversionOverflow)?;
+        let into_offset: Duration<Representation, Period> = ScaleInto::TAI_OFFSET
+            .cast()
+            .checked_into_unit()
+            .ok_or(TimeScaleConversionOverflow)?;
+        // As for the infallible conversion, the subtraction order is flipped based on the sign.
+        let time_since_epoch = if from_offset >= into_offset {
+            let scale_offset = from_offset.checked_sub(into_offset);
+            scale_offset.and_then(|offset| time_point.time_since_epoch().checked_sub(offset))
+        } else {
+            let scale_offset = into_offset.checked_sub(from_offset);
+            scale_offset.and_then(|offset| time_point.time_since_epoch().checked_add(offset))
+        };
+        time_since_epoch
+            .and_then(|time_since_epoch| time_since_epoch.checked_add(epoch_offset))
+            .map(Self::from_time_since_epoch)
+            .ok_or(TimeScaleConversionOverflow)
+    }
+}
+
+/// Verifies that the uniform TAI offset matches the known offsets of some time scales.
+#[test]
+fn tai_offsets() {
+    use crate::{Bdt, Gpst, Gst, MilliSeconds, Qzsst, Tai, Tt};
+    assert_eq!(Tt::tai_offset(), MilliSeconds::new(32_184));
+    assert_eq!(Gpst::tai_offset(), MilliSeconds::new(-19_000));
+    assert_eq!(Bdt::tai_offset(), MilliSeconds::new(-33_000));
+    assert_eq!(Gst::tai_offset(), MilliSeconds::new(-19_000));
+    assert_eq!(Qzsst::tai_offset(), MilliSeconds::new(-19_000));
+    assert_eq!(Tai::tai_offset(), MilliSeconds::new(0));
+}
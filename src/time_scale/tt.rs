@@ -14,6 +14,14 @@ pub type TtTime<Representation = i64, Period = Second> = TimePoint<Tt, Represent
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Tt;
 
+impl Tt {
+    /// The exact offset of TT with respect to TAI: TT = TAI + 32.184 s. Unlike the
+    /// `TerrestrialTime::TAI_OFFSET` constant, which is stored in the narrowest representation
+    /// that fits, this offset is given as `i64` milliseconds, such that it may be used in
+    /// arithmetic directly and without importing any traits.
+    pub const TAI_OFFSET: MilliSeconds<i64> = MilliSeconds::new(32_184);
+}
+
 impl TimeScale for Tt {
     const NAME: &'static str = "Terrestrial Time";
 
@@ -25,6 +33,8 @@ impl AbsoluteTimeScale for Tt {
         Ok(epoch) => epoch,
         Err(_) => unreachable!(),
     };
+
+    const IS_UNIFORM_DATE_TIME: bool = true;
 }
 
 impl UniformDateTimeScale for Tt {}
@@ -32,7 +42,8 @@ impl UniformDateTimeScale for Tt {}
 impl TerrestrialTime for Tt {
     type Representation = u16;
     type Period = Milli;
-    const TAI_OFFSET: Duration<Self::Representation, Self::Period> = MilliSeconds::new(32_184);
+    const TAI_OFFSET: Duration<Self::Representation, Self::Period> =
+        MilliSeconds::new(Tt::TAI_OFFSET.count() as u16);
 }
 
 /// Compares with a known timestamp as obtained from Vallado and McClain's "Fundamentals of
@@ -54,6 +65,25 @@ fn known_timestamps() {
     assert_eq!(tai.into_unit(), tt.into_time_scale());
 }
 
+/// Verifies that TT is exactly 32.184 seconds ahead of TAI, without any loss of precision in the
+/// stored offset.
+#[test]
+fn tai_offset() {
+    use crate::{FromDateTime, IntoTimeScale, TaiTime};
+    assert_eq!(Tt::TAI_OFFSET, MilliSeconds::new(32_184));
+    assert_eq!(<Tt as TerrestrialTime>::tai_offset(), Tt::TAI_OFFSET);
+
+    let date = Date::from_historic_date(2024, Month::August, 13).unwrap();
+    let tai = TaiTime::from_datetime(date, 19, 30, 0).unwrap();
+    let tai = tai.into_unit::<Milli>();
+    let tt: TtTime<i64, Milli> = tai.into_time_scale();
+    // The TAI instant is labelled 32.184 seconds later in TT.
+    let label: TtTime<i64, Milli> = TtTime::from_datetime(date, 19, 30, 0).unwrap().into_unit();
+    let offset = tt - label;
+    assert_eq!(offset, Tt::TAI_OFFSET);
+    assert_eq!(tt.into_time_scale(), tai);
+}
+
 #[test]
 fn date_decomposition() {
     let time =
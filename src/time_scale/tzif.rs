@@ -0,0 +1,312 @@
+//! Leap second provider based on the leap second records of TZif files, as found in the `right/`
+//! zones of the time zone database on most Unix-like systems. Those files are maintained by the
+//! operating system, so reusing them avoids relying on a bundled leap second table that may go
+//! stale.
+
+use crate::{
+    Date, Days, LeapSecondProvider, Month, Second, Seconds, Utc, UtcTime, errors::TzifParsingError,
+    time_scale::AbsoluteTimeScale,
+};
+
+/// Length of the fixed-size header that precedes each data block of a TZif file.
+const HEADER_LENGTH: usize = 44;
+
+/// Number of seconds in a (non-leap second) day.
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// TAI - UTC before the start of 1972, when UTC was still a rubber time scale. As in the static
+/// leap second table, the step towards an offset of 10 s at the start of 1972 is modelled as if a
+/// leap second was inserted at the end of 1971-12-31.
+const INITIAL_LEAP_SECONDS: u8 = 9;
+
+/// TAI - UTC at the start of 1972. Leap second corrections in TZif files are relative to this
+/// offset.
+const TZIF_BASE_LEAP_SECONDS: i64 = 10;
+
+/// Leap second provider that is constructed from the leap second records of a TZif (version 2 or
+/// 3) file, such as `/usr/share/zoneinfo/right/UTC`. Only the leap second records are used:
+/// the time zone transitions in the file are ignored.
+///
+/// Reading the file is left to the caller, such that the provider may also be constructed from
+/// embedded or downloaded data:
+///
+/// ```no_run
+/// # use finetime::TzifLeapSecondProvider;
+/// let bytes = std::fs::read("/usr/share/zoneinfo/right/UTC").unwrap();
+/// let provider = TzifLeapSecondProvider::from_bytes(&bytes).unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TzifLeapSecondProvider {
+    /// Chronologically ordered list of the UTC dates at whose end the number of leap seconds
+    /// changes, together with the number of leap seconds (TAI - UTC) after that change.
+    changes: Vec<(Date<i32>, Seconds<u8>)>,
+}
+
+impl TzifLeapSecondProvider {
+    /// Parses the leap second records of a TZif file. Only version 2 and 3 files are supported:
+    /// earlier versions do not store 64-bit leap second occurrences, and version 4 relaxes the
+    /// requirement that each correction differs by exactly one second from the previous one (its
+    /// leap second table may be truncated at the start, or end in an expiration marker).
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TzifParsingError> {
+        // The version 1 data block only contains 32-bit times, so it is skipped in favour of the
+        // version 2+ data block that follows it.
+        let version_1 = Header::parse(bytes)?;
+        if !matches!(version_1.version, b'2' | b'3') {
+            return Err(TzifParsingError::UnsupportedVersion {
+                version: version_1.version,
+            });
+        }
+        let bytes = bytes
+            .get(HEADER_LENGTH + version_1.data_length(4)..)
+            .ok_or(TzifParsingError::UnexpectedEndOfData)?;
+        let header = Header::parse(bytes)?;
+        let leap_records = header.timecnt * 9 + header.typecnt * 6 + header.charcnt;
+        let leap_records = bytes
+            .get(HEADER_LENGTH + leap_records..)
+            .and_then(|bytes| bytes.get(..header.leapcnt * 12))
+            .ok_or(TzifParsingError::UnexpectedEndOfData)?;
+
+        let first_change = Date::from_historic_date(1971, Month::December, 31).unwrap();
+        let mut changes = vec![(first_change, Seconds::new(INITIAL_LEAP_SECONDS + 1))];
+        let mut previous_correction = 0;
+        for record in leap_records.chunks_exact(12) {
+            let occurrence = i64::from_be_bytes(record[..8].try_into().unwrap());
+            let correction = i32::from_be_bytes(record[8..].try_into().unwrap());
+            if (i64::from(correction) - previous_correction).abs() != 1 {
+                return Err(TzifParsingError::InvalidCorrection { correction });
+            }
+
+            // The occurrence is the Unix time (counting leap seconds) of the midnight following
+            // the leap second, so the leap seconds before it must be removed to find its date.
+            let midnight = occurrence - previous_correction;
+            if midnight.rem_euclid(SECONDS_PER_DAY) != 0 {
+                return Err(TzifParsingError::MisalignedOccurrence { occurrence });
+            }
+            let days = i32::try_from(midnight.div_euclid(SECONDS_PER_DAY) - 1)
+                .map_err(|_| TzifParsingError::MisalignedOccurrence { occurrence })?;
+            let leap_seconds = u8::try_from(TZIF_BASE_LEAP_SECONDS + i64::from(correction))
+                .map_err(|_| TzifParsingError::InvalidCorrection { correction })?;
+            let date = Date::from_time_since_epoch(Days::new(days));
+            if changes.last().is_some_and(|&(last, _)| last >= date) {
+                return Err(TzifParsingError::MisalignedOccurrence { occurrence });
+            }
+            changes.push((date, Seconds::new(leap_seconds)));
+            previous_correction = i64::from(correction);
+        }
+        Ok(Self { changes })
+    }
+
+    /// Returns the UTC dates at whose end the number of leap seconds changes, together with the
+    /// number of leap seconds (TAI - UTC) after that change, in chronological order.
+    pub fn leap_second_changes(&self) -> &[(Date<i32>, Seconds<u8>)] {
+        &self.changes
+    }
+}
+
+impl LeapSecondProvider for TzifLeapSecondProvider {
+    fn leap_seconds_on_date(&self, utc_date: Date<i32>) -> (bool, Seconds<u8>) {
+        let changes_before = self.changes.partition_point(|&(date, _)| date < utc_date);
+        let current = match changes_before {
+            0 => Seconds::new(INITIAL_LEAP_SECONDS),
+            index => self.changes[index - 1].1,
+        };
+        match self.changes.get(changes_before) {
+            Some(&(date, future)) if date == utc_date => (future > current, current),
+            _ => (false, current),
+        }
+    }
+
+    /// Each new leap second count applies from midnight after the day of the change onwards.
+    /// Since UTC time points count leap seconds, that midnight lies at a whole number of days
+    /// after the UTC epoch, plus the new count of leap seconds.
+    fn leap_seconds_at_time(&self, utc_time: UtcTime<i64, Second>) -> (bool, Seconds<u8>) {
+        let seconds_since_utc_epoch = utc_time.time_since_epoch().count();
+        let change_time = |&(date, future): &(Date<i32>, Seconds<u8>)| {
+            let days_since_utc_epoch =
+                i64::from((date.time_since_epoch() - Utc::EPOCH.time_since_epoch()).count()) + 1;
+            days_since_utc_epoch * SECONDS_PER_DAY + i64::from(future.count())
+        };
+        let changes_before = self
+            .changes
+            .partition_point(|change| change_time(change) <= seconds_since_utc_epoch);
+        let current = match changes_before {
+            0 => Seconds::new(INITIAL_LEAP_SECONDS),
+            index => self.changes[index - 1].1,
+        };
+        match self.changes.get(changes_before) {
+            Some(change) => {
+                let is_leap_second =
+                    change.1 > current && seconds_since_utc_epoch == change_time(change) - 1;
+                (is_leap_second, current)
+            }
+            None => (false, current),
+        }
+    }
+}
+
+/// The counts stored in the header of a TZif data block, as far as they are needed to find the
+/// leap second records.
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    /// Parses the header at the start of the given bytes.
+    fn parse(bytes: &[u8]) -> Result<Self, TzifParsingError> {
+        let header = bytes
+            .get(..HEADER_LENGTH)
+            .ok_or(TzifParsingError::UnexpectedEndOfData)?;
+        if &header[..4] != b"TZif" {
+            return Err(TzifParsingError::InvalidMagic);
+        }
+        let count = |index: usize| {
+            let offset = 20 + 4 * index;
+            u32::from_be_bytes(header[offset..offset + 4].try_into().unwrap()) as usize
+        };
+        Ok(Self {
+            version: header[4],
+            isutcnt: count(0),
+            isstdcnt: count(1),
+            leapcnt: count(2),
+            timecnt: count(3),
+            typecnt: count(4),
+            charcnt: count(5),
+        })
+    }
+
+    /// Returns the length of the data block following this header, for the given size of time
+    /// values in bytes.
+    fn data_length(&self, time_size: usize) -> usize {
+        self.timecnt * (time_size + 1)
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+/// Builds a minimal TZif file of the given version for the UTC zone, with the given leap second
+/// records in both data blocks.
+#[cfg(test)]
+fn tzif_file(version: u8, leap_seconds: &[(i64, i32)]) -> Vec<u8> {
+    let header = |leapcnt: usize| {
+        let mut header = b"TZif".to_vec();
+        header.push(version);
+        header.extend([0; 15]);
+        for count in [0, 0, leapcnt as u32, 0, 1, 4] {
+            header.extend(count.to_be_bytes());
+        }
+        header
+    };
+    let local_time_type = [0, 0, 0, 0, 0, 0];
+    let mut file = header(leap_seconds.len());
+    file.extend(local_time_type);
+    file.extend(b"UTC\0");
+    for &(occurrence, correction) in leap_seconds {
+        file.extend((occurrence as i32).to_be_bytes());
+        file.extend(correction.to_be_bytes());
+    }
+    file.extend(header(leap_seconds.len()));
+    file.extend(local_time_type);
+    file.extend(b"UTC\0");
+    for &(occurrence, correction) in leap_seconds {
+        file.extend(occurrence.to_be_bytes());
+        file.extend(correction.to_be_bytes());
+    }
+    file.extend(b"\nUTC0\n");
+    file
+}
+
+/// Verifies that the leap second records of a TZif file are parsed into a provider that agrees
+/// with the static leap second table around those leap seconds.
+#[test]
+fn leap_second_records() {
+    use crate::{FromDateTime, StaticLeapSecondProvider};
+    // The leap seconds at the end of 1972-06-30 and 1972-12-31, as stored in `right/UTC`.
+    let file = tzif_file(b'2', &[(78_796_800, 1), (94_694_401, 2)]);
+    let provider = TzifLeapSecondProvider::from_bytes(&file).unwrap();
+    let first = Date::from_historic_date(1972, Month::June, 30).unwrap();
+    let second = Date::from_historic_date(1972, Month::December, 31).unwrap();
+    assert_eq!(
+        &provider.leap_second_changes()[1..],
+        [(first, Seconds::new(11)), (second, Seconds::new(12))]
+    );
+
+    let static_provider = StaticLeapSecondProvider {};
+    let start = Date::from_historic_date(1971, Month::December, 30).unwrap();
+    let end = Date::from_historic_date(1973, Month::January, 2).unwrap();
+    let mut date = start;
+    while date <= end {
+        assert_eq!(
+            provider.leap_seconds_on_date(date),
+            static_provider.leap_seconds_on_date(date)
+        );
+        for (hour, minute, second) in [(0, 0, 0), (23, 59, 59), (23, 59, 60)] {
+            let Ok(utc) = UtcTime::from_datetime(date, hour, minute, second) else {
+                continue;
+            };
+            assert_eq!(
+                provider.leap_seconds_at_time(utc),
+                static_provider.leap_seconds_at_time(utc)
+            );
+        }
+        date = date.succ();
+    }
+
+    // Beyond the last record, the leap second count remains constant.
+    let later = Date::from_historic_date(1974, Month::December, 31).unwrap();
+    assert_eq!(
+        provider.leap_seconds_on_date(later),
+        (false, Seconds::new(12))
+    );
+    assert!(TzifLeapSecondProvider::from_bytes(&tzif_file(b'3', &[])).is_ok());
+}
+
+/// Verifies that unsupported and malformed TZif files are rejected.
+#[test]
+fn malformed_files() {
+    assert_eq!(
+        TzifLeapSecondProvider::from_bytes(&tzif_file(0, &[(78_796_800, 1)])),
+        Err(TzifParsingError::UnsupportedVersion { version: 0 })
+    );
+    assert_eq!(
+        TzifLeapSecondProvider::from_bytes(&tzif_file(b'4', &[])),
+        Err(TzifParsingError::UnsupportedVersion { version: b'4' })
+    );
+    assert_eq!(
+        TzifLeapSecondProvider::from_bytes(&tzif_file(b'5', &[])),
+        Err(TzifParsingError::UnsupportedVersion { version: b'5' })
+    );
+    assert_eq!(
+        TzifLeapSecondProvider::from_bytes(b"TZig"),
+        Err(TzifParsingError::UnexpectedEndOfData)
+    );
+    let mut file = tzif_file(b'2', &[]);
+    file[0] = b'X';
+    assert_eq!(
+        TzifLeapSecondProvider::from_bytes(&file),
+        Err(TzifParsingError::InvalidMagic)
+    );
+    let file = tzif_file(b'2', &[(78_796_800, 1)]);
+    assert_eq!(
+        TzifLeapSecondProvider::from_bytes(&file[..file.len() - 12]),
+        Err(TzifParsingError::UnexpectedEndOfData)
+    );
+    assert_eq!(
+        TzifLeapSecondProvider::from_bytes(&tzif_file(b'2', &[(78_796_801, 1)])),
+        Err(TzifParsingError::MisalignedOccurrence {
+            occurrence: 78_796_801
+        })
+    );
+    assert_eq!(
+        TzifLeapSecondProvider::from_bytes(&tzif_file(b'2', &[(78_796_800, 2)])),
+        Err(TzifParsingError::InvalidCorrection { correction: 2 })
+    );
+}
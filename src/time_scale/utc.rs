@@ -2,13 +2,20 @@
 
 use core::ops::Sub;
 
+use num_traits::AsPrimitive;
+
 use crate::{
-    ConvertUnit, Date, Days, Fraction, FromDateTime, Hours, IntoDateTime, LeapSecondProvider,
-    Minutes, Month, MulFloor, Second, Seconds, StaticLeapSecondProvider, TerrestrialTime,
-    TimePoint, TryFromExact, TryIntoExact, Years,
-    errors::{InvalidTimeOfDay, InvalidUtcDateTime},
+    BinaryFraction4, ConvertUnit, Date, Days, Duration, Fraction, FromDateTime, Hours,
+    IntoDateTime, IntoFineDateTime, LeapSecondProvider, Minutes, Month, MulFloor, Second, Seconds,
+    StaticLeapSecondProvider, TerrestrialTime, TimePoint, TryFromExact, TryIntoExact, Years,
+    errors::{InvalidNtpTimestamp, InvalidTimeOfDay, InvalidUtcDateTime},
     time_scale::{AbsoluteTimeScale, TimeScale},
-    units::{SecondsPerDay, SecondsPerHour, SecondsPerMinute, SecondsPerYear},
+    units::{SecondsPerDay, SecondsPerHour, SecondsPerMinute, SecondsPerYear, UnitRatio},
+};
+#[cfg(feature = "std")]
+use crate::{
+    Nano, NanoSeconds,
+    errors::{LeapTableStale, SystemTimeOutOfRange},
 };
 
 pub type UtcTime<Representation = i64, Period = Second> = TimePoint<Utc, Representation, Period>;
@@ -78,7 +85,8 @@ impl FromDateTime for UtcTime<i64, Second> {
 
         let (is_leap_second, total_leap_seconds) =
             StaticLeapSecondProvider {}.leap_seconds_on_date(date);
-        if second == 60 && !is_leap_second {
+        // Leap seconds are only ever inserted as the last second of the UTC day.
+        if second == 60 && !(is_leap_second && hour == 23 && minute == 59) {
             return Err(InvalidUtcDateTime::NonLeapSecondDateTime {
                 date,
                 hour,
@@ -161,6 +169,220 @@ where
     }
 }
 
+#[cfg(feature = "std")]
+impl UtcTime<i64, Nano> {
+    /// Returns the current UTC time, as reported by the system clock. Leap seconds are obtained
+    /// from the static leap second table. Beyond the validity window of that table, the leap
+    /// second count is assumed to remain constant: use `UtcTime::now_checked` to detect this.
+    ///
+    /// Since the system clock follows Unix time, it cannot represent leap seconds themselves: during
+    /// a leap second, the returned time point will lag behind by up to one second.
+    pub fn now() -> Self {
+        Self::from_system_time(std::time::SystemTime::now())
+            .unwrap_or_else(|error| panic!("Current system time is invalid: {error}"))
+    }
+
+    /// Converts the given system time into a UTC time point, with leap seconds obtained from the
+    /// static leap second table. This is the conversion used by `UtcTime::now`, exposed such that
+    /// stored `SystemTime` values may be converted, and such that fixed times may be injected in
+    /// tests. Returns an error if the system time is so far from 1972 that it cannot be represented
+    /// as `i64` nanoseconds (roughly 292 years).
+    pub fn from_system_time(
+        system_time: std::time::SystemTime,
+    ) -> Result<Self, SystemTimeOutOfRange> {
+        Self::from_system_time_and_date(system_time, &StaticLeapSecondProvider {})
+            .map(|(time_point, _)| time_point)
+    }
+
+    /// Returns the current UTC time like `UtcTime::now`, but based on the given leap second
+    /// provider. Returns an error if the current date lies beyond the validity window of that
+    /// provider, because the leap second count may then be inaccurate.
+    pub fn now_checked(provider: &impl LeapSecondProvider) -> Result<Self, LeapTableStale> {
+        let (time_point, date) =
+            Self::from_system_time_and_date(std::time::SystemTime::now(), provider)
+                .unwrap_or_else(|error| panic!("Current system time is invalid: {error}"));
+        match provider.valid_until() {
+            Some(valid_until) if date > valid_until => Err(LeapTableStale { valid_until, date }),
+            _ => Ok(time_point),
+        }
+    }
+
+    /// Converts a system time into a UTC time point, applying leap seconds from the given
+    /// provider. Also returns the UTC date on which the system time falls.
+    fn from_system_time_and_date(
+        system_time: std::time::SystemTime,
+        provider: &impl LeapSecondProvider,
+    ) -> Result<(Self, Date<i32>), SystemTimeOutOfRange> {
+        let nanoseconds_since_1970 = match system_time.duration_since(std::time::UNIX_EPOCH) {
+            Ok(duration) => duration.as_nanos() as i128,
+            Err(error) => -(error.duration().as_nanos() as i128),
+        };
+        let seconds_since_1970 = nanoseconds_since_1970.div_euclid(1_000_000_000);
+        let subsecond_nanoseconds = nanoseconds_since_1970.rem_euclid(1_000_000_000);
+        let days_since_1970 = seconds_since_1970.div_euclid(86400);
+        let seconds_in_day = seconds_since_1970.rem_euclid(86400);
+        let date = Date::from_time_since_epoch(Days::new(
+            i32::try_from(days_since_1970).map_err(|_| SystemTimeOutOfRange)?,
+        ));
+
+        let (_, leap_seconds) = provider.leap_seconds_on_date(date);
+        let days_since_scale_epoch =
+            days_since_1970 - i128::from(Utc::EPOCH.time_since_epoch().count());
+        let seconds_since_scale_epoch =
+            days_since_scale_epoch * 86400 + seconds_in_day + i128::from(leap_seconds.count());
+        let nanoseconds_since_scale_epoch =
+            seconds_since_scale_epoch * 1_000_000_000 + subsecond_nanoseconds;
+        let time_point = Self::from_time_since_epoch(NanoSeconds::new(
+            i64::try_from(nanoseconds_since_scale_epoch).map_err(|_| SystemTimeOutOfRange)?,
+        ));
+        Ok((time_point, date))
+    }
+}
+
+impl<Representation, Period> UtcTime<Representation, Period>
+where
+    Self: IntoFineDateTime<Representation, Period>,
+    Representation: Copy + AsPrimitive<f64>,
+    Period: UnitRatio + ?Sized,
+{
+    /// Returns the modified Julian date of this instant, expressed in UTC. In general, Julian
+    /// dates are not supported for UTC, because the length of a UTC day is not constant. This
+    /// function resolves that ambiguity using the convention of the IAU SOFA library: the fraction
+    /// of the day is determined by dividing the elapsed time by the actual length of that day.
+    /// Hence, on days that end with a leap second, all 86401 seconds are compressed into a single
+    /// MJD day, and the leap second itself occupies the final 1/86401 of that day.
+    ///
+    /// As a result, the MJD increases monotonically, but is not uniform in time: it must not be
+    /// used to compute durations across leap seconds.
+    pub fn to_mjd_utc(&self) -> f64 {
+        const MODIFIED_JULIAN_EPOCH: Date<i32> =
+            match Date::from_historic_date(1858, Month::November, 17) {
+                Ok(epoch) => epoch,
+                Err(_) => unreachable!(),
+            };
+        let (date, hour, minute, second, subseconds) = self.into_fine_datetime();
+        let (is_leap_second_day, _) = StaticLeapSecondProvider {}.leap_seconds_on_date(date);
+        let day_length = if is_leap_second_day { 86401. } else { 86400. };
+        let seconds_in_day = f64::from(hour) * 3600.
+            + f64::from(minute) * 60.
+            + f64::from(second)
+            + subseconds.as_seconds_f64();
+        date.signed_days_since(MODIFIED_JULIAN_EPOCH) as f64 + seconds_in_day / day_length
+    }
+}
+
+/// Epoch of the Network Time Protocol (NTP) prime era: 1900-01-01T00:00:00 UTC.
+const NTP_EPOCH: Date<i32> = match Date::from_historic_date(1900, Month::January, 1) {
+    Ok(epoch) => epoch,
+    Err(_) => unreachable!(),
+};
+
+/// Number of seconds in a single NTP era: the range of the 32-bit seconds field of an NTP
+/// timestamp.
+const SECONDS_PER_NTP_ERA: i64 = 1 << 32;
+
+impl UtcTime<i128, BinaryFraction4> {
+    /// Constructs a UTC time point from a 64-bit NTP timestamp, consisting of 32 bits of seconds
+    /// since 1900-01-01 and 32 bits of binary fraction. The timestamp is assumed to lie in NTP era
+    /// 0, which ends at 2036-02-07T06:28:16 UTC: for later timestamps, use
+    /// `from_ntp_timestamp_in_era`.
+    ///
+    /// Note that NTP timestamps do not count leap seconds: during a leap second, NTP repeats a
+    /// second. Hence, the resulting time point is never a leap second.
+    pub fn from_ntp_timestamp(timestamp: u64) -> Result<Self, InvalidNtpTimestamp> {
+        Self::from_ntp_timestamp_in_era(timestamp, 0)
+    }
+
+    /// Constructs a UTC time point from a 64-bit NTP timestamp in the given NTP era. Era 0 starts
+    /// at 1900-01-01, era 1 at 2036-02-07T06:28:16 UTC, and so on; negative eras lie before 1900.
+    pub fn from_ntp_timestamp_in_era(
+        timestamp: u64,
+        era: i32,
+    ) -> Result<Self, InvalidNtpTimestamp> {
+        let seconds_since_ntp_epoch = era as i64 * SECONDS_PER_NTP_ERA + (timestamp >> 32) as i64;
+        let days_since_ntp_epoch = seconds_since_ntp_epoch.div_euclid(86_400);
+        let second_of_day = seconds_since_ntp_epoch.rem_euclid(86_400) as u32;
+        let date = i32::try_from(days_since_ntp_epoch)
+            .ok()
+            .and_then(|days| NTP_EPOCH.checked_add_days(Days::new(days)))
+            .ok_or(InvalidNtpTimestamp { timestamp, era })?;
+        let hour = (second_of_day / 3600) as u8;
+        let minute = (second_of_day % 3600 / 60) as u8;
+        let second = (second_of_day % 60) as u8;
+        let coarse_time_point = UtcTime::<i64, Second>::from_datetime(date, hour, minute, second)
+            .unwrap_or_else(|_| unreachable!());
+        let subseconds = Duration::<i128, BinaryFraction4>::new((timestamp & 0xFFFF_FFFF) as i128);
+        Ok(coarse_time_point.cast().into_unit() + subseconds)
+    }
+
+    /// Converts this UTC time point into a 64-bit NTP timestamp. Any information on the NTP era
+    /// is discarded: timestamps wrap around at 2036-02-07T06:28:16 UTC. If the era is required,
+    /// use `to_ntp_timestamp_and_era`.
+    ///
+    /// Since NTP does not count leap seconds, a leap second is mapped to the same NTP timestamp as
+    /// the first second of the following day.
+    pub fn to_ntp_timestamp(&self) -> u64 {
+        self.to_ntp_timestamp_and_era().0
+    }
+
+    /// Converts this UTC time point into a 64-bit NTP timestamp, together with the NTP era that it
+    /// falls in.
+    pub fn to_ntp_timestamp_and_era(&self) -> (u64, i32) {
+        let (date, hour, minute, second, subseconds) = self.into_fine_datetime();
+        let seconds_since_ntp_epoch = date.signed_days_since(NTP_EPOCH) * 86_400
+            + hour as i64 * 3600
+            + minute as i64 * 60
+            + second as i64;
+        let era = seconds_since_ntp_epoch.div_euclid(SECONDS_PER_NTP_ERA);
+        let seconds_in_era = seconds_since_ntp_epoch.rem_euclid(SECONDS_PER_NTP_ERA) as u64;
+        let fraction = subseconds.count() as u64;
+        let era = i32::try_from(era).unwrap_or_else(|_| panic!("NTP era does not fit in `i32`"));
+        ((seconds_in_era << 32) | fraction, era)
+    }
+}
+
+/// Verifies conversion from and to NTP timestamps using some known values.
+#[test]
+fn ntp_timestamps() {
+    let timestamp = (3_913_056_000u64 << 32) | 0x8000_0000;
+    let utc = UtcTime::from_ntp_timestamp(timestamp).unwrap();
+    let expected = UtcTime::<i64, Second>::from_historic_datetime(2024, Month::January, 1, 0, 0, 0)
+        .unwrap()
+        .cast::<i128>()
+        .into_unit()
+        + Duration::new(0x8000_0000);
+    assert_eq!(utc, expected);
+    assert_eq!(utc.to_ntp_timestamp(), timestamp);
+    assert_eq!(utc.to_ntp_timestamp_and_era(), (timestamp, 0));
+
+    // The NTP epoch itself, in UTC "proleptic" time, before the start of the UTC epoch.
+    let epoch = UtcTime::from_ntp_timestamp(0).unwrap();
+    let (date, hour, minute, second, subseconds) = epoch.into_fine_historic_datetime();
+    assert_eq!(
+        date,
+        crate::HistoricDate::new(1900, Month::January, 1).unwrap()
+    );
+    assert_eq!((hour, minute, second, subseconds.count()), (0, 0, 0, 0));
+    assert_eq!(epoch.to_ntp_timestamp(), 0);
+
+    // Rollover of era 0 into era 1.
+    let rollover = UtcTime::from_ntp_timestamp_in_era(0, 1).unwrap();
+    let expected =
+        UtcTime::<i64, Second>::from_historic_datetime(2036, Month::February, 7, 6, 28, 16)
+            .unwrap()
+            .cast::<i128>()
+            .into_unit();
+    assert_eq!(rollover, expected);
+    assert_eq!(rollover.to_ntp_timestamp_and_era(), (0, 1));
+    assert_eq!(
+        UtcTime::from_ntp_timestamp_in_era(0, i32::MAX),
+        Err(InvalidNtpTimestamp {
+            timestamp: 0,
+            era: i32::MAX
+        })
+    );
+}
+
 /// Tests the creation of UTC time points from calendar dates for some known values. We explicitly
 /// try out times near leap second insertions to see if those are handled properly, including:
 /// - Durations should be handled correctly before, during, and after a leap second.
@@ -210,6 +432,24 @@ fn calendar_dates_near_insertion() {
     );
 }
 
+/// Verifies that second 60 is rejected at any time other than 23:59, even on a leap second day.
+#[test]
+fn leap_second_only_at_end_of_day() {
+    let date = Date::from_historic_date(2016, Month::December, 31).unwrap();
+    assert!(UtcTime::<i64, Second>::from_datetime(date, 23, 59, 60).is_ok());
+    assert_eq!(
+        UtcTime::<i64, Second>::from_datetime(date, 12, 0, 60),
+        Err(InvalidUtcDateTime::NonLeapSecondDateTime {
+            date,
+            hour: 12,
+            minute: 0,
+            second: 60
+        })
+    );
+    assert!(UtcTime::<i64, Second>::from_datetime(date, 23, 58, 60).is_err());
+    assert!(UtcTime::<i64, Second>::from_datetime(date, 22, 59, 60).is_err());
+}
+
 #[test]
 fn trivial_times() {
     let epoch = UtcTime::from_historic_datetime(1972, Month::January, 1, 0, 0, 0).unwrap();
@@ -280,3 +520,112 @@ fn datetime_roundtrip_near_leap_seconds() {
         }
     }
 }
+
+/// Verifies that the current time may be checked against the validity window of a leap second
+/// provider.
+#[cfg(feature = "std")]
+#[test]
+fn now_checked() {
+    /// Leap second provider that wraps the static table, but with a configurable validity window.
+    struct WindowedProvider(Option<Date<i32>>);
+
+    impl LeapSecondProvider for WindowedProvider {
+        fn leap_seconds_on_date(&self, utc_date: Date<i32>) -> (bool, Seconds<u8>) {
+            StaticLeapSecondProvider {}.leap_seconds_on_date(utc_date)
+        }
+
+        fn leap_seconds_at_time(&self, utc_time: UtcTime<i64, Second>) -> (bool, Seconds<u8>) {
+            StaticLeapSecondProvider {}.leap_seconds_at_time(utc_time)
+        }
+
+        fn valid_until(&self) -> Option<Date<i32>> {
+            self.0
+        }
+    }
+
+    let valid_until = Date::from_historic_date(2000, Month::January, 1).unwrap();
+    let error = UtcTime::now_checked(&WindowedProvider(Some(valid_until))).unwrap_err();
+    assert_eq!(error.valid_until, valid_until);
+    assert!(error.date > valid_until);
+
+    let before = UtcTime::now();
+    let now = UtcTime::now_checked(&WindowedProvider(None)).unwrap();
+    let after = UtcTime::now();
+    assert!(before <= now && now <= after);
+}
+
+/// Verifies that fixed system times are converted into the expected UTC time points.
+#[cfg(feature = "std")]
+#[test]
+fn from_system_time() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    // The Unix epoch lies two years before the UTC epoch, and precedes all leap seconds in the
+    // static table: only its initial offset of 9 seconds applies.
+    let unix_epoch = UtcTime::from_system_time(UNIX_EPOCH).unwrap();
+    assert_eq!(
+        unix_epoch.time_since_epoch(),
+        NanoSeconds::new((-730 * 86400 + 9) * 1_000_000_000)
+    );
+    assert_eq!(
+        unix_epoch,
+        UtcTime::from_historic_datetime(1970, Month::January, 1, 0, 0, 0)
+            .unwrap()
+            .into_unit()
+    );
+
+    let later = UtcTime::from_system_time(UNIX_EPOCH + Duration::from_millis(1500)).unwrap();
+    assert_eq!(later - unix_epoch, NanoSeconds::new(1_500_000_000));
+
+    let leap_second = UtcTime::from_gregorian_datetime(2016, Month::December, 31, 23, 59, 60)
+        .unwrap()
+        .into_unit();
+    let after_leap_second =
+        UtcTime::from_system_time(UNIX_EPOCH + Duration::from_secs(1_483_228_800)).unwrap();
+    assert_eq!(
+        after_leap_second - leap_second,
+        NanoSeconds::new(1_000_000_000)
+    );
+
+    assert_eq!(
+        UtcTime::from_system_time(UNIX_EPOCH + Duration::from_secs(400 * 366 * 86400)),
+        Err(SystemTimeOutOfRange)
+    );
+}
+
+/// Verifies the modified Julian date of UTC time points, including on leap second days.
+#[test]
+fn mjd_utc() {
+    let mjd = |year, month, day, hour, minute, second| {
+        UtcTime::from_historic_datetime(year, month, day, hour, minute, second)
+            .unwrap()
+            .to_mjd_utc()
+    };
+    assert_eq!(mjd(1858, Month::November, 17, 0, 0, 0), 0.);
+    assert_eq!(mjd(2024, Month::January, 15, 12, 0, 0), 60324.5);
+    assert_eq!(mjd(2024, Month::January, 15, 0, 0, 0), 60324.);
+
+    // 2016-12-31 (MJD 57753) ends with a leap second, so it lasts 86401 seconds.
+    assert_eq!(mjd(2016, Month::December, 31, 0, 0, 0), 57753.);
+    assert_eq!(
+        mjd(2016, Month::December, 31, 23, 59, 59),
+        57753. + 86399. / 86401.
+    );
+    assert_eq!(
+        mjd(2016, Month::December, 31, 23, 59, 60),
+        57753. + 86400. / 86401.
+    );
+    assert_eq!(mjd(2017, Month::January, 1, 0, 0, 0), 57754.);
+
+    let time = UtcTime::from_fine_historic_datetime(
+        2016,
+        Month::December,
+        31,
+        23,
+        59,
+        60,
+        crate::MilliSeconds::new(500i64),
+    )
+    .unwrap();
+    assert_eq!(time.to_mjd_utc(), 57753. + 86400.5 / 86401.);
+}
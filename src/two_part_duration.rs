@@ -0,0 +1,285 @@
+//! Implementation of a `Duration`-like type that is stored as a pair of a coarse and a fine count.
+//! This is the classic two-part Julian date trick, applied to durations: a single integer cannot
+//! combine a wide range with a fine resolution, but a pair of integers can.
+
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+use crate::{AttoSeconds, Duration, Seconds, TimePoint, units::Atto};
+
+/// The number of attoseconds in a second.
+const ATTOSECONDS_PER_SECOND: i64 = 1_000_000_000_000_000_000;
+
+/// A duration stored as a whole number of seconds plus a fine number of attoseconds. This gives
+/// attosecond resolution over the full range of `Seconds<i64>`: nearly 300 billion years in either
+/// direction, which a single 64-bit attosecond count cannot come close to.
+///
+/// The attosecond part is always normalized into the range `0..10^18`, such that each duration
+/// has a unique representation and may be compared component-wise. Negative durations are
+/// therefore stored as a negative number of seconds plus a non-negative fraction of a second.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TwoPartDuration {
+    seconds: i64,
+    attoseconds: i64,
+}
+
+impl TwoPartDuration {
+    /// A duration of zero length.
+    pub const ZERO: Self = Self {
+        seconds: 0,
+        attoseconds: 0,
+    };
+
+    /// Constructs a new `TwoPartDuration` from a coarse number of seconds and a fine number of
+    /// attoseconds. The attoseconds need not be normalized: any whole seconds contained in them
+    /// are carried over into the coarse part.
+    ///
+    /// Panics if the resulting number of seconds cannot be expressed as `i64`.
+    pub const fn new(seconds: Seconds<i64>, attoseconds: AttoSeconds<i64>) -> Self {
+        let attoseconds = attoseconds.count();
+        let carry = attoseconds.div_euclid(ATTOSECONDS_PER_SECOND);
+        let seconds = match seconds.count().checked_add(carry) {
+            Some(seconds) => seconds,
+            None => panic!("Seconds of two-part duration cannot be expressed as `i64`"),
+        };
+        Self {
+            seconds,
+            attoseconds: attoseconds.rem_euclid(ATTOSECONDS_PER_SECOND),
+        }
+    }
+
+    /// Returns the coarse part of this duration: the whole number of seconds, rounded towards
+    /// negative infinity.
+    pub const fn seconds(&self) -> Seconds<i64> {
+        Seconds::new(self.seconds)
+    }
+
+    /// Returns the fine part of this duration: the normalized number of attoseconds remaining
+    /// after the whole seconds, which always lies in `0..10^18`.
+    pub const fn attoseconds(&self) -> AttoSeconds<i64> {
+        AttoSeconds::new(self.attoseconds)
+    }
+
+    /// Converts this duration into a single attosecond count. This is always exact, since `i128`
+    /// is wide enough to hold any `TwoPartDuration`.
+    pub const fn into_attoseconds(self) -> AttoSeconds<i128> {
+        AttoSeconds::new(
+            self.seconds as i128 * ATTOSECONDS_PER_SECOND as i128 + self.attoseconds as i128,
+        )
+    }
+
+    /// Converts a single attosecond count into a `TwoPartDuration`. Returns `None` if the number
+    /// of whole seconds cannot be expressed as `i64`.
+    pub const fn try_from_attoseconds(attoseconds: AttoSeconds<i128>) -> Option<Self> {
+        let attoseconds = attoseconds.count();
+        let seconds = attoseconds.div_euclid(ATTOSECONDS_PER_SECOND as i128);
+        if seconds < i64::MIN as i128 || seconds > i64::MAX as i128 {
+            return None;
+        }
+        Some(Self {
+            seconds: seconds as i64,
+            attoseconds: attoseconds.rem_euclid(ATTOSECONDS_PER_SECOND as i128) as i64,
+        })
+    }
+
+    /// Adds two two-part durations. Returns `None` if the resulting number of whole seconds cannot
+    /// be expressed as `i64`.
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        Self::combine(
+            self.seconds as i128 + rhs.seconds as i128,
+            self.attoseconds + rhs.attoseconds,
+        )
+    }
+
+    /// Subtracts two two-part durations. Returns `None` if the resulting number of whole seconds
+    /// cannot be expressed as `i64`.
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Self::combine(
+            self.seconds as i128 - rhs.seconds as i128,
+            self.attoseconds - rhs.attoseconds,
+        )
+    }
+
+    /// Normalizes a sum or difference of both parts, whose fine part lies within `-10^18..2*10^18`.
+    /// The coarse part is widened, such that intermediate results outside the range of `i64` are
+    /// only rejected if the carry does not bring them back into range.
+    const fn combine(seconds: i128, attoseconds: i64) -> Option<Self> {
+        let seconds = seconds + attoseconds.div_euclid(ATTOSECONDS_PER_SECOND) as i128;
+        if seconds < i64::MIN as i128 || seconds > i64::MAX as i128 {
+            return None;
+        }
+        Some(Self {
+            seconds: seconds as i64,
+            attoseconds: attoseconds.rem_euclid(ATTOSECONDS_PER_SECOND),
+        })
+    }
+}
+
+impl From<Seconds<i64>> for TwoPartDuration {
+    fn from(seconds: Seconds<i64>) -> Self {
+        Self::new(seconds, AttoSeconds::new(0))
+    }
+}
+
+impl From<AttoSeconds<i64>> for TwoPartDuration {
+    fn from(attoseconds: AttoSeconds<i64>) -> Self {
+        Self::new(Seconds::new(0), attoseconds)
+    }
+}
+
+impl From<TwoPartDuration> for Duration<i128, Atto> {
+    fn from(duration: TwoPartDuration) -> Self {
+        duration.into_attoseconds()
+    }
+}
+
+/// Addition of two-part durations is exact: the fine parts are summed separately, with any carry
+/// propagated into the coarse part. Overflow of the coarse part results in a panic; use
+/// `checked_add` where it may occur.
+impl Add for TwoPartDuration {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .unwrap_or_else(|| panic!("Seconds of two-part duration cannot be expressed as `i64`"))
+    }
+}
+
+impl AddAssign for TwoPartDuration {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// Subtraction of two-part durations is exact, with any borrow taken from the coarse part.
+/// Overflow of the coarse part results in a panic; use `checked_sub` where it may occur.
+impl Sub for TwoPartDuration {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .unwrap_or_else(|| panic!("Seconds of two-part duration cannot be expressed as `i64`"))
+    }
+}
+
+impl SubAssign for TwoPartDuration {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for TwoPartDuration {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::ZERO - self
+    }
+}
+
+impl<Scale: ?Sized> TimePoint<Scale, i128, Atto> {
+    /// Constructs a `TimePoint` from a two-part duration elapsed since the epoch of its time
+    /// scale. This is always exact.
+    pub const fn from_two_part_duration(time_since_epoch: TwoPartDuration) -> Self {
+        Self::from_time_since_epoch(time_since_epoch.into_attoseconds())
+    }
+
+    /// Returns the time elapsed since the epoch of this time point's time scale as a two-part
+    /// duration. Returns `None` if the whole number of seconds cannot be expressed as `i64`.
+    pub const fn to_two_part_duration(&self) -> Option<TwoPartDuration> {
+        TwoPartDuration::try_from_attoseconds(self.time_since_epoch())
+    }
+}
+
+/// Verifies that the fine part of a two-part duration is always normalized, also for negative
+/// inputs, and that conversion to and from a single attosecond count is exact.
+#[test]
+fn normalization() {
+    let duration =
+        TwoPartDuration::new(Seconds::new(1), AttoSeconds::new(2_500_000_000_000_000_000));
+    assert_eq!(duration.seconds(), Seconds::new(3));
+    assert_eq!(
+        duration.attoseconds(),
+        AttoSeconds::new(500_000_000_000_000_000)
+    );
+
+    let duration = TwoPartDuration::new(Seconds::new(0), AttoSeconds::new(-1));
+    assert_eq!(duration.seconds(), Seconds::new(-1));
+    assert_eq!(
+        duration.attoseconds(),
+        AttoSeconds::new(999_999_999_999_999_999)
+    );
+    assert_eq!(duration.into_attoseconds(), AttoSeconds::new(-1));
+    assert_eq!(-duration, TwoPartDuration::from(AttoSeconds::new(1)));
+    assert!(duration < TwoPartDuration::ZERO);
+
+    let max = TwoPartDuration::new(
+        Seconds::new(i64::MAX),
+        AttoSeconds::new(ATTOSECONDS_PER_SECOND - 1),
+    );
+    assert_eq!(
+        TwoPartDuration::try_from_attoseconds(max.into_attoseconds()),
+        Some(max)
+    );
+    assert_eq!(
+        TwoPartDuration::try_from_attoseconds(max.into_attoseconds() + AttoSeconds::new(1)),
+        None
+    );
+
+    // Results are normalized before their range is checked, so a carry or borrow may bring an
+    // intermediate sum of the coarse parts back into range.
+    let min = TwoPartDuration::new(Seconds::new(i64::MIN), AttoSeconds::new(0));
+    let almost_min = TwoPartDuration::new(
+        Seconds::new(i64::MIN),
+        AttoSeconds::new(ATTOSECONDS_PER_SECOND / 2),
+    );
+    let minus_half = TwoPartDuration::new(
+        Seconds::new(-1),
+        AttoSeconds::new(ATTOSECONDS_PER_SECOND / 2),
+    );
+    assert_eq!(almost_min.checked_add(minus_half), Some(min));
+    assert_eq!(min.checked_add(minus_half), None);
+    assert_eq!(max.checked_sub(-minus_half), max.checked_add(minus_half));
+    assert_eq!(
+        max.checked_add(TwoPartDuration::from(AttoSeconds::new(1))),
+        None
+    );
+    assert_eq!(min.checked_sub(max), None);
+    assert_eq!(max.checked_sub(max), Some(TwoPartDuration::ZERO));
+}
+
+/// Verifies that repeatedly adding a single attosecond is exact: after 10^18 additions, exactly
+/// one second has passed. Rather than looping 10^18 times, the additions are grouped using
+/// repeated doubling, which relies only on the associativity of exact addition.
+#[test]
+fn exact_accumulation() {
+    let mut step = TwoPartDuration::from(AttoSeconds::new(1));
+    let mut total = TwoPartDuration::ZERO;
+    let mut remaining: u64 = 1_000_000_000_000_000_000;
+    while remaining != 0 {
+        if remaining & 1 == 1 {
+            total += step;
+        }
+        step += step;
+        remaining >>= 1;
+    }
+    assert_eq!(total, TwoPartDuration::from(Seconds::new(1)));
+    let almost = total - TwoPartDuration::from(AttoSeconds::new(1));
+    assert_eq!(almost.seconds(), Seconds::new(0));
+    assert_eq!(
+        almost.attoseconds(),
+        AttoSeconds::new(ATTOSECONDS_PER_SECOND - 1)
+    );
+}
+
+/// Verifies that time points may be constructed from and converted into two-part durations.
+#[test]
+fn time_point_conversion() {
+    use crate::TaiTime;
+    let duration = TwoPartDuration::new(Seconds::new(-42), AttoSeconds::new(123));
+    let time_point = TaiTime::<i128, Atto>::from_two_part_duration(duration);
+    assert_eq!(
+        time_point.count(),
+        -42 * ATTOSECONDS_PER_SECOND as i128 + 123
+    );
+    assert_eq!(time_point.to_two_part_duration(), Some(duration));
+    assert_eq!(TaiTime::<i128, Atto>::MAX.to_two_part_duration(), None);
+}
@@ -100,6 +100,14 @@ pub trait UnitRatio {
     const FRACTION: Fraction;
 }
 
+/// Returns the ratio of the unit `U` to seconds as a normalized `(numerator, denominator)` pair.
+/// Unlike `UnitRatio::FRACTION`, this may be called as a plain function, which is convenient when
+/// building unit-aware displays. Components are `u128`, like those of `Fraction`, since some
+/// units (such as `BinaryFraction8` and smaller) have a denominator that exceeds `u64`.
+pub const fn unit_ratio<U: UnitRatio + ?Sized>() -> (u128, u128) {
+    (U::FRACTION.numerator(), U::FRACTION.denominator())
+}
+
 /// Unit that is described as an exact ratio with respect to unity.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum LiteralRatio<const NUMERATOR: u128, const DENOMINATOR: u128 = 1> {}
@@ -110,11 +118,34 @@ impl<const NUMERATOR: u128, const DENOMINATOR: u128> UnitRatio
     const FRACTION: Fraction = Fraction::new(NUMERATOR, DENOMINATOR);
 }
 
+/// Trait that determines the common unit of two units between which an exact integer conversion
+/// exists: this is always the finer of both, such that values expressed in either unit may be
+/// converted into it without loss. Used to combine durations of different periods.
+pub trait CommonUnit<Other: ?Sized> {
+    /// The finer of `Self` and `Other`.
+    type Output: ?Sized;
+}
+
+impl<T> CommonUnit<T> for T
+where
+    T: UnitRatio + ?Sized,
+{
+    type Output = T;
+}
+
 macro_rules! valid_integer_conversions {
     (
         $from:ty => $( $to:ty ),+ $(,)?
     ) => {
         $(
+            impl CommonUnit<$to> for $from {
+                type Output = $to;
+            }
+
+            impl CommonUnit<$from> for $to {
+                type Output = $to;
+            }
+
             valid_integer_conversion!(u8, $from, $to);
             valid_integer_conversion!(u16, $from, $to);
             valid_integer_conversion!(u32, $from, $to);